@@ -0,0 +1,75 @@
+/**********************************************************************/
+/* Batch: Play guesses read from a file or a pipe, answering in JSON. */
+/**********************************************************************/
+
+// Import libraries/modules
+use crate::expr::ExprError;
+use crate::game::{Game, GuessOutcome};
+use crate::json::Value;
+use crate::session::{self, LineOutcome, SessionEnd};
+use std::io;
+use std::io::{BufRead, Write};
+
+/// Play a game with the guesses read from `input`, one per line.
+///
+/// Every line is answered with one JSON object on `out`, such as
+/// `{"guess":50,"outcome":"too_small"}`, and the game ends with an object
+/// holding its result. Nothing else is written, so that the output can be
+/// parsed line by line. `quit` and `hint` work as they do on the terminal.
+pub fn play_batch<R, W>(game: &mut Game, input: R, out: &mut W) -> io::Result<SessionEnd>
+where
+    R: BufRead,
+    W: Write + ?Sized,
+{
+    let mut lines = input.lines();
+    let end: SessionEnd = loop {
+        if game.is_over() {
+            break if game.is_won() { SessionEnd::Won } else { SessionEnd::Lost };
+        }
+        let line: String = match lines.next() {
+            Some(line) => line?,
+            None => break SessionEnd::EndOfInput,
+        };
+
+        // Commands are answered with what they did
+        if session::is_quit_command(&line) {
+            break SessionEnd::Quit;
+        }
+        if session::is_hint_command(&line) {
+            let (outcome, message): (LineOutcome, String) = session::ask_hint(game);
+            let key: &str = if outcome == LineOutcome::Hint { "hint" } else { "message" };
+            let answer: Value = Value::object([
+                ("input", Value::from(line.trim())),
+                ("outcome", outcome.name().into()),
+                (key, message.into()),
+            ]);
+            writeln!(out, "{answer}")?;
+            continue;
+        }
+
+        // Guesses are answered with their value, or with why they are invalid
+        let value: Result<i64, ExprError> = game.evaluate(&line);
+        let outcome: GuessOutcome = game.submit(&line);
+        let answer: Value = match value {
+            Ok(value) => Value::object([("guess", Value::from(value)), ("outcome", outcome.name().into())]),
+            Err(error) => Value::object([
+                ("input", Value::from(line.trim())),
+                ("outcome", outcome.name().into()),
+                ("error", error.to_string().into()),
+            ]),
+        };
+        writeln!(out, "{answer}")?;
+    };
+
+    // The last line sums the game up
+    let summary: Value = Value::object([
+        ("result", end.name().into()),
+        ("attempts", Value::from(game.attempts())),
+        ("secret", Value::from(game.secret())),
+        ("score", Value::from(game.score())),
+    ]);
+    writeln!(out, "{summary}")?;
+    out.flush()?;
+
+    Ok(end)
+}
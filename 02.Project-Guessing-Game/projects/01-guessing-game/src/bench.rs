@@ -0,0 +1,75 @@
+/*****************************************************************/
+/* Bench: Play thousands of seeded games and measure a strategy. */
+/*****************************************************************/
+
+// Import libraries/modules
+use crate::game::{seeded_rng, Game, SeededRng};
+use crate::histogram;
+use crate::messages::{self, Message};
+use crate::settings::Settings;
+use crate::strategy::{self, StrategyKind};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// How many attempts a strategy needed over many games.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    /// The strategy that played.
+    pub strategy: StrategyKind,
+    /// The number of games played.
+    pub games: usize,
+    /// The mean number of attempts per game.
+    pub mean: f64,
+    /// The most attempts needed by a single game.
+    pub worst: u32,
+    /// The number of games won with each number of attempts.
+    pub histogram: BTreeMap<u32, usize>,
+}
+
+/// Let a strategy play `games` games, without any attempt limit.
+///
+/// The secret numbers come from `seed`, so every strategy benched with the
+/// same seed faces the exact same games.
+pub fn bench(kind: StrategyKind, settings: Settings, games: usize, seed: u64) -> BenchReport {
+    // Without a limit, every game is won and the attempts can be compared,
+    // and the strategies trust every answer, so no game lies
+    let settings: Settings = settings
+        .with_lies(0)
+        .with_max_attempts(None)
+        .expect("removing the attempt limit is always valid");
+    let mut secrets: SeededRng = seeded_rng(seed);
+    let mut player = kind.build(seed);
+
+    let mut histogram: BTreeMap<u32, usize> = BTreeMap::new();
+    let mut total: u64 = 0;
+    for _ in 0..games {
+        let mut game: Game = Game::from_rng(settings, &mut secrets);
+        strategy::solve(&mut game, player.as_mut(), |_, _| {});
+        *histogram.entry(game.attempts()).or_default() += 1;
+        total += u64::from(game.attempts());
+    }
+
+    BenchReport {
+        strategy: kind,
+        games,
+        mean: if games == 0 { 0.0 } else { total as f64 / games as f64 },
+        worst: histogram.keys().next_back().copied().unwrap_or(0),
+        histogram,
+    }
+}
+
+/// Render a report as plain text, with a histogram of the attempts.
+pub fn render(report: &BenchReport) -> String {
+    let mut text: String = String::new();
+    let games: u64 = report.games as u64;
+    let _ = writeln!(text, "{}", messages::plural(Message::BenchTitle, games, &[("strategy", &report.strategy)]));
+
+    // The labels are padded to the longest one, so the values line up
+    let (mean, worst): (&str, &str) = (messages::text(Message::BenchMean), messages::text(Message::BenchWorst));
+    let width: usize = mean.chars().count().max(worst.chars().count());
+    let _ = writeln!(text, "{mean:<width$} {:.2}", report.mean);
+    let _ = writeln!(text, "{worst:<width$} {}", report.worst);
+    text.push_str(&histogram::render(&report.histogram));
+
+    text
+}
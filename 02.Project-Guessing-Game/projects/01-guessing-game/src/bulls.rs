@@ -0,0 +1,381 @@
+/**************************************************************/
+/* Bulls: Bulls and cows, guessing a code of distinct digits. */
+/**************************************************************/
+
+// Import libraries/modules
+use crate::game::GameStatus;
+use crate::messages;
+use crate::score;
+use rand::{Rng, RngExt};
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// The fewest digits a code can have.
+pub const MIN_DIGITS: usize = 2;
+
+/// The most digits a code can have, so that the solver stays fast.
+pub const MAX_DIGITS: usize = 5;
+
+/// The number of digits of a code, unless `--digits` is given.
+pub const DEFAULT_DIGITS: usize = 4;
+
+/// Above this many guesses times candidates, the solver only considers the
+/// candidates as its next guess, instead of every possible code.
+const SEARCH_LIMIT: usize = 10_000_000;
+
+/// The number of possible marks, indexed by bulls and cows.
+const GROUPS: usize = (MAX_DIGITS + 1) * (MAX_DIGITS + 1);
+
+/// A code of distinct decimal digits, such as `0372`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Code {
+    digits: Vec<u8>,
+    /// Bit `d` is set when the code holds the digit `d`.
+    mask: u16,
+    /// Bit `10 * i + d` is set when the digit `d` is at position `i`.
+    placed: u64,
+}
+
+impl Code {
+    /// Build a code from its digits, which must be distinct and below 10.
+    fn from_digits(digits: Vec<u8>) -> Self {
+        let mask: u16 = digits.iter().fold(0, |mask, digit| mask | 1 << digit);
+        let placed: u64 = digits
+            .iter()
+            .enumerate()
+            .fold(0, |placed, (index, digit)| placed | 1 << (10 * index + usize::from(*digit)));
+        Self { digits, mask, placed }
+    }
+
+    /// Parse a code of `length` distinct digits, ignoring surrounding whitespace.
+    pub fn parse(text: &str, length: usize) -> Result<Self, CodeError> {
+        let text: &str = text.trim();
+        let mut digits: Vec<u8> = Vec::with_capacity(length);
+        for c in text.chars() {
+            let digit: u8 = match c.to_digit(10) {
+                Some(digit) => digit as u8,
+                None => return Err(CodeError::NotADigit(c)),
+            };
+            if digits.contains(&digit) {
+                return Err(CodeError::Repeated(c));
+            }
+            digits.push(digit);
+        }
+        if digits.len() != length {
+            return Err(CodeError::Length {
+                expected: length,
+                found: text.chars().count(),
+            });
+        }
+        Ok(Self::from_digits(digits))
+    }
+
+    /// Draw a code of `length` distinct digits at random.
+    pub fn random<R: Rng + ?Sized>(length: usize, rng: &mut R) -> Self {
+        // Shuffle the first `length` digits into place
+        let mut digits: Vec<u8> = (0..10).collect();
+        for index in 0..length {
+            let pick: usize = rng.random_range(index..digits.len());
+            digits.swap(index, pick);
+        }
+        digits.truncate(length);
+        Self::from_digits(digits)
+    }
+
+    /// Every code of `length` distinct digits, in increasing order.
+    pub fn all(length: usize) -> Vec<Self> {
+        let mut codes: Vec<Self> = Vec::new();
+        let mut digits: Vec<u8> = Vec::with_capacity(length);
+        fill(length, &mut digits, &mut codes);
+        codes
+    }
+
+    /// The number of codes of `length` distinct digits: `10! / (10 - length)!`.
+    pub fn count(length: usize) -> u64 {
+        (0..length as u64).map(|index| 10 - index).product()
+    }
+
+    /// The number of digits of the code.
+    pub fn len(&self) -> usize {
+        self.digits.len()
+    }
+
+    /// Whether the code has no digits, which parsed codes never are.
+    pub fn is_empty(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Score `guess` against this code.
+    pub fn marks(&self, guess: &Code) -> Marks {
+        let bulls: u32 = (self.placed & guess.placed).count_ones();
+        let common: u32 = (self.mask & guess.mask).count_ones();
+        Marks {
+            bulls,
+            cows: common - bulls,
+        }
+    }
+}
+
+/// Extend `digits` with every unused digit, collecting the complete codes.
+fn fill(length: usize, digits: &mut Vec<u8>, codes: &mut Vec<Code>) {
+    if digits.len() == length {
+        codes.push(Code::from_digits(digits.clone()));
+        return;
+    }
+    for digit in 0..10 {
+        if !digits.contains(&digit) {
+            digits.push(digit);
+            fill(length, digits, codes);
+            digits.pop();
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.digits.iter().try_for_each(|digit| write!(f, "{digit}"))
+    }
+}
+
+/// The feedback on a guessed code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Marks {
+    /// Right digits in the right place.
+    pub bulls: u32,
+    /// Right digits in the wrong place.
+    pub cows: u32,
+}
+
+/// The reasons why a guessed code can be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeError {
+    /// The guess is not as long as the code.
+    Length { expected: usize, found: usize },
+    /// The guess holds something other than a digit.
+    NotADigit(char),
+    /// The guess holds the same digit twice.
+    Repeated(char),
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::code_error(self))
+    }
+}
+
+impl Error for CodeError {}
+
+/// The outcome of one guessed code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeOutcome {
+    /// The guess was scored, and counts as an attempt.
+    Marked(Marks),
+    /// The guess is not a valid code, and does not count.
+    Invalid(CodeError),
+    /// The game is already over.
+    GameOver,
+}
+
+/// A game of bulls and cows: find the secret code of distinct digits.
+#[derive(Debug, Clone)]
+pub struct CodeGame {
+    secret: Code,
+    max_attempts: Option<u32>,
+    guesses: Vec<(Code, Marks)>,
+    status: GameStatus,
+    started: Instant,
+    finished: Option<Instant>,
+}
+
+impl CodeGame {
+    /// Start a game with a code of `length` digits drawn from `rng`.
+    pub fn from_rng<R: Rng + ?Sized>(length: usize, max_attempts: Option<u32>, rng: &mut R) -> Self {
+        Self::with_secret(Code::random(length, rng), max_attempts)
+    }
+
+    /// Start a game with a known secret code.
+    pub fn with_secret(secret: Code, max_attempts: Option<u32>) -> Self {
+        Self {
+            secret,
+            max_attempts,
+            guesses: Vec::new(),
+            status: GameStatus::InProgress,
+            started: Instant::now(),
+            finished: None,
+        }
+    }
+
+    /// Submit the raw text typed by the player and return its outcome.
+    pub fn submit(&mut self, guess: &str) -> CodeOutcome {
+        if self.is_over() {
+            return CodeOutcome::GameOver;
+        }
+        let guess: Code = match Code::parse(guess, self.secret.len()) {
+            Ok(code) => code,
+            Err(error) => return CodeOutcome::Invalid(error),
+        };
+
+        // Settle the game on a win, or when no attempt is left
+        let marks: Marks = self.secret.marks(&guess);
+        self.guesses.push((guess, marks));
+        if marks.bulls as usize == self.secret.len() {
+            self.finish(GameStatus::Won);
+        } else if self.attempts_left() == Some(0) {
+            self.finish(GameStatus::Lost);
+        }
+
+        CodeOutcome::Marked(marks)
+    }
+
+    /// Record the end of the game.
+    fn finish(&mut self, status: GameStatus) {
+        self.status = status;
+        self.finished = Some(Instant::now());
+    }
+
+    /// The number of digits of the code.
+    pub fn length(&self) -> usize {
+        self.secret.len()
+    }
+
+    /// The secret code the player is trying to find.
+    pub fn secret(&self) -> &Code {
+        &self.secret
+    }
+
+    /// Every valid guess so far, with its marks, oldest first.
+    pub fn guesses(&self) -> &[(Code, Marks)] {
+        &self.guesses
+    }
+
+    /// The number of valid guesses so far.
+    pub fn attempts(&self) -> u32 {
+        self.guesses.len() as u32
+    }
+
+    /// The number of attempts left, or `None` if they are unlimited.
+    pub fn attempts_left(&self) -> Option<u32> {
+        self.max_attempts
+            .map(|max_attempts| max_attempts.saturating_sub(self.attempts()))
+    }
+
+    /// Where the game stands.
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    /// Whether the code was found.
+    pub fn is_won(&self) -> bool {
+        self.status == GameStatus::Won
+    }
+
+    /// Whether the game is won or lost.
+    pub fn is_over(&self) -> bool {
+        self.status != GameStatus::InProgress
+    }
+
+    /// The time spent playing, up to the end of the game.
+    pub fn elapsed(&self) -> Duration {
+        self.finished.unwrap_or_else(Instant::now) - self.started
+    }
+
+    /// The score of the game, as for numbers, with every code as a candidate.
+    pub fn score(&self) -> u32 {
+        match self.status {
+            GameStatus::Won => score::score(self.attempts(), Code::count(self.length()), self.elapsed(), 0),
+            GameStatus::InProgress | GameStatus::Lost => 0,
+        }
+    }
+}
+
+/// Finds a code by keeping the candidates consistent with every mark.
+///
+/// Each guess minimises the largest group of candidates that could be left
+/// afterwards, as in Knuth's Mastermind algorithm. For 4 digits, this finds
+/// any of the 5040 codes within 7 guesses, which is the optimal bound.
+#[derive(Debug, Clone)]
+pub struct CodeSolver {
+    codes: Vec<Code>,
+    candidates: Vec<Code>,
+}
+
+impl CodeSolver {
+    /// Start with every code of `length` digits as a candidate.
+    pub fn new(length: usize) -> Self {
+        let codes: Vec<Code> = Code::all(length);
+        Self {
+            candidates: codes.clone(),
+            codes,
+        }
+    }
+
+    /// The codes that are still consistent with every mark.
+    pub fn candidates(&self) -> &[Code] {
+        &self.candidates
+    }
+
+    /// Pick the next guess, or `None` if the marks contradict each other.
+    pub fn next_guess(&self) -> Option<Code> {
+        // Every first guess is as good as any other
+        if self.candidates.len() <= 2 || self.candidates.len() == self.codes.len() {
+            return self.candidates.first().cloned();
+        }
+
+        // Prefer guesses that may win on the spot, then the smallest code
+        let pool: &[Code] = match self.codes.len() * self.candidates.len() {
+            work if work <= SEARCH_LIMIT => &self.codes,
+            _ => &self.candidates,
+        };
+        let length: usize = self.candidates[0].len();
+        pool.iter()
+            .min_by_key(|guess| {
+                // Count the candidates left by each possible mark
+                let mut groups: [u32; GROUPS] = [0; GROUPS];
+                for candidate in &self.candidates {
+                    let marks: Marks = candidate.marks(guess);
+                    groups[marks.bulls as usize * (MAX_DIGITS + 1) + marks.cows as usize] += 1;
+                }
+                let worst: u32 = groups.iter().copied().max().unwrap_or(0);
+                let winning: bool = groups[length * (MAX_DIGITS + 1)] > 0;
+                (worst, !winning)
+            })
+            .cloned()
+    }
+
+    /// Keep the candidates that would have given `marks` to `guess`.
+    pub fn record(&mut self, guess: &Code, marks: Marks) {
+        self.candidates.retain(|candidate| candidate.marks(guess) == marks);
+    }
+}
+
+/// Let the solver play `game` until it is over, reporting every step.
+pub fn solve<F>(game: &mut CodeGame, mut on_step: F) -> GameStatus
+where
+    F: FnMut(&Code, Marks),
+{
+    let mut solver: CodeSolver = CodeSolver::new(game.length());
+    while !game.is_over() {
+        let guess: Code = solver.next_guess().expect("the marks of a real code are consistent");
+
+        // Feed the guess through the same path as typed input
+        if let CodeOutcome::Marked(marks) = game.submit(&guess.to_string()) {
+            on_step(&guess, marks);
+            solver.record(&guess, marks);
+        }
+    }
+
+    game.status()
+}
+
+/// The default attempt limit for codes of `length` digits: the most guesses
+/// that [`CodeSolver`] needs for any code, found by playing every code.
+///
+/// For 4 digits, this is 7, the optimal bound: no strategy can do better.
+pub fn attempt_bound(length: usize) -> u32 {
+    match length {
+        ..=3 => 6,
+        4 => 7,
+        _ => 9,
+    }
+}
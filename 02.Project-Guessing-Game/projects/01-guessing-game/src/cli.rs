@@ -0,0 +1,634 @@
+/**************************************************/
+/* CLI: Turn command-line arguments into options. */
+/**************************************************/
+
+// Import libraries/modules
+use crate::bulls;
+use crate::domain::NumberType;
+use crate::lies;
+use crate::messages::{self, Language, Message};
+use crate::multiplayer;
+use crate::net;
+use crate::settings::{Difficulty, Feedback, Settings, SettingsError};
+use crate::strategy::StrategyKind;
+use crate::words;
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// The number of games shown per difficulty by `scores`, unless `--top` is given.
+pub const DEFAULT_TOP: usize = 10;
+
+/// The number of games played per strategy by `bench`, unless `--games` is given.
+pub const DEFAULT_GAMES: usize = 1_000;
+
+/// The help text printed by `--help`, in the language of the messages.
+pub fn usage() -> &'static str {
+    messages::text(Message::Usage)
+}
+
+/// The language asked for with `--lang`, if any, looked up without parsing
+/// the rest of the command line, so that its errors are in that language too.
+pub fn language_of(args: &[String]) -> Option<Language> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value: &str = match arg.split_once('=') {
+            Some(("--lang", value)) => value,
+            _ if arg == "--lang" => args.next()?,
+            _ => continue,
+        };
+        return value.parse().ok();
+    }
+    None
+}
+
+/// What the program should do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Command {
+    /// Play a game on the terminal.
+    #[default]
+    Play,
+    /// Print the leaderboard.
+    Scores,
+    /// Print the statistics of a player.
+    Stats,
+    /// Play bulls and cows.
+    Bulls,
+    /// Play the word mode.
+    Words,
+    /// Let the computer guess the player's number.
+    Reverse,
+    /// Compare the strategies over many games.
+    Bench,
+    /// Host games over TCP.
+    Serve,
+    /// Play on a TCP server.
+    Connect,
+    /// Replay a transcript.
+    Replay,
+    /// Continue the saved game.
+    Load,
+    /// Play the number of the day.
+    Daily,
+}
+
+/// What the player asked for on the command line.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Options {
+    /// What the program should do.
+    pub command: Command,
+    /// The validated game settings.
+    pub settings: Settings,
+    /// The type of the secret number picked with `--type`, if any.
+    pub number_type: Option<NumberType>,
+    /// The smallest secret number as given, to be parsed as the `--type`.
+    pub min: Option<String>,
+    /// The largest secret number as given, to be parsed as the `--type`.
+    pub max: Option<String>,
+    /// The tolerance as given, to be parsed as the `--type`.
+    pub tolerance: Option<String>,
+    /// The number of digits of the code of bulls and cows.
+    pub digits: usize,
+    /// The number of letters of the secret word.
+    pub letters: usize,
+    /// The word list of the word mode, instead of the embedded one.
+    pub word_list: Option<PathBuf>,
+    /// The attempt limit given with `--attempts`, if any, for modes whose
+    /// default limit does not come from the range.
+    pub attempts: Option<Option<u32>>,
+    /// The seed of the secret number, if the game must be reproducible.
+    pub seed: Option<u64>,
+    /// Where to record the transcript of the game, if anywhere.
+    pub record: Option<PathBuf>,
+    /// Whether to continue the saved game instead of starting a new one.
+    pub resume: bool,
+    /// The file argument of the command, e.g. the transcript of `replay`.
+    pub file: Option<PathBuf>,
+    /// The name of the player, if given.
+    pub name: Option<String>,
+    /// The players of a hot-seat game, if any.
+    pub players: Option<Vec<String>>,
+    /// The number of games per difficulty shown by the leaderboard.
+    pub top: usize,
+    /// The language picked with `--lang`, if any.
+    pub language: Option<Language>,
+    /// Whether to play full-screen, when stdout is a terminal.
+    pub tui: bool,
+    /// Whether the computer plays instead of the player.
+    pub auto: bool,
+    /// Whether guesses are read from stdin and answered in JSON.
+    pub batch: bool,
+    /// The strategy picked with `--strategy`, if any.
+    pub strategy: Option<StrategyKind>,
+    /// The number of games played per strategy by `bench`.
+    pub games: usize,
+    /// The address of the server.
+    pub addr: String,
+    /// Whether to print the help text instead of playing.
+    pub help: bool,
+}
+
+/// The reasons why the command line can be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// An option that the game does not know about.
+    UnknownOption(String),
+    /// A command that the game does not know about.
+    UnknownCommand(String),
+    /// An argument that no command or option expects.
+    UnexpectedArgument(String),
+    /// A command was given without its file argument.
+    MissingArgument(&'static str),
+    /// An option that needs a value was given none.
+    MissingValue(String),
+    /// An option value that could not be parsed.
+    InvalidValue { option: String, value: String },
+    /// The options describe invalid settings.
+    Settings(SettingsError),
+    /// Two options that cannot be used together.
+    Conflict(&'static str, &'static str),
+    /// An option that only makes sense along with another one.
+    Requires(&'static str, &'static str),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::cli_error(self))
+    }
+}
+
+impl Error for CliError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CliError::Settings(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<SettingsError> for CliError {
+    fn from(err: SettingsError) -> Self {
+        CliError::Settings(err)
+    }
+}
+
+/// Parse the command-line arguments, without the program name.
+///
+/// The command, if any, comes first. Both `--option value` and
+/// `--option=value` are accepted.
+pub fn parse_args<I>(args: I) -> Result<Options, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options: Options = Options {
+        digits: bulls::DEFAULT_DIGITS,
+        letters: words::DEFAULT_LETTERS,
+        top: DEFAULT_TOP,
+        games: DEFAULT_GAMES,
+        addr: net::DEFAULT_ADDR.to_string(),
+        ..Options::default()
+    };
+    let mut difficulty: Difficulty = Difficulty::default();
+    let mut min: Option<String> = None;
+    let mut max: Option<String> = None;
+    let mut attempts: Option<Option<u32>> = None;
+    let mut hints_after: Option<Option<u32>> = None;
+    let mut feedback: Feedback = Feedback::default();
+    let mut time_limit: Option<Duration> = None;
+    let mut lies: u32 = 0;
+
+    let mut args = args.into_iter().peekable();
+
+    // The command is the first argument, unless it is an option
+    if let Some(command) = args.next_if(|arg| !arg.starts_with('-')) {
+        options.command = match command.as_str() {
+            "play" => Command::Play,
+            "scores" => Command::Scores,
+            "stats" => Command::Stats,
+            "bulls" => Command::Bulls,
+            "words" => Command::Words,
+            "reverse" => Command::Reverse,
+            "bench" => Command::Bench,
+            "serve" => Command::Serve,
+            "connect" => Command::Connect,
+            "replay" => Command::Replay,
+            "load" => Command::Load,
+            "daily" => Command::Daily,
+            _ => return Err(CliError::UnknownCommand(command)),
+        };
+    }
+
+    while let Some(arg) = args.next() {
+        // Split `--option=value` into its two halves
+        let (name, inline_value): (String, Option<String>) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name.to_string(), Some(value.to_string())),
+            _ => (arg, None),
+        };
+
+        match name.as_str() {
+            "-h" | "--help" => options.help = true,
+            "-d" | "--difficulty" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                difficulty = value.parse()?;
+            }
+            "--min" => min = Some(value_of(&name, inline_value, &mut args)?),
+            "--max" => max = Some(value_of(&name, inline_value, &mut args)?),
+            "--type" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                options.number_type = match value.parse() {
+                    Ok(number_type) => Some(number_type),
+                    Err(_) => return Err(CliError::InvalidValue { option: name, value }),
+                };
+            }
+            "--tolerance" => options.tolerance = Some(value_of(&name, inline_value, &mut args)?),
+            "--attempts" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                attempts = Some(parse_limit(&name, value, "unlimited")?);
+            }
+            "--digits" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                options.digits = match value.trim().parse() {
+                    Ok(digits) if (bulls::MIN_DIGITS..=bulls::MAX_DIGITS).contains(&digits) => digits,
+                    _ => return Err(CliError::InvalidValue { option: name, value }),
+                };
+            }
+            "--letters" => options.letters = parse_value(&name, inline_value, &mut args)?,
+            "--word-list" => options.word_list = Some(value_of(&name, inline_value, &mut args)?.into()),
+            "--time" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                time_limit = match value.trim().parse() {
+                    Ok(seconds) if seconds > 0 => Some(Duration::from_secs(seconds)),
+                    _ => return Err(CliError::InvalidValue { option: name, value }),
+                };
+            }
+            "--lies" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                lies = match value.trim().parse() {
+                    Ok(lies) if lies <= lies::MAX_LIES => lies,
+                    _ => return Err(CliError::InvalidValue { option: name, value }),
+                };
+            }
+            "--hot-cold" => feedback = Feedback::HotCold,
+            "--hints-after" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                hints_after = Some(parse_limit(&name, value, "never")?);
+            }
+            "--seed" => options.seed = Some(parse_value(&name, inline_value, &mut args)?),
+            "--name" => options.name = Some(value_of(&name, inline_value, &mut args)?),
+            "--players" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                let names: Vec<String> = value.split(',').map(str::to_string).collect();
+                options.players = Some(multiplayer::validate_players(names)?);
+            }
+            "--top" => options.top = parse_value(&name, inline_value, &mut args)?,
+            "--lang" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                options.language = match value.parse() {
+                    Ok(language) => Some(language),
+                    Err(_) => return Err(CliError::InvalidValue { option: name, value }),
+                };
+            }
+            "--tui" => options.tui = true,
+            "--auto" => options.auto = true,
+            "--batch" => options.batch = true,
+            "--strategy" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                options.strategy = Some(value.parse()?);
+            }
+            "--games" => {
+                let value: String = value_of(&name, inline_value, &mut args)?;
+                options.games = match value.trim().parse() {
+                    Ok(games) if games > 0 => games,
+                    _ => return Err(CliError::InvalidValue { option: name, value }),
+                };
+            }
+            "--addr" => options.addr = value_of(&name, inline_value, &mut args)?,
+            "--record" => options.record = Some(value_of(&name, inline_value, &mut args)?.into()),
+            "--resume" => options.resume = true,
+            _ if !name.starts_with('-') && options.command == Command::Replay && options.file.is_none() => {
+                options.file = Some(name.into());
+            }
+            _ if !name.starts_with('-') => return Err(CliError::UnexpectedArgument(name)),
+            _ => return Err(CliError::UnknownOption(name)),
+        }
+    }
+
+    if options.command == Command::Replay && options.file.is_none() {
+        return Err(CliError::MissingArgument("replay"));
+    }
+
+    // A resumed game has no seed to draw from nor to record, and is only played on the terminal
+    options.resume |= options.command == Command::Load;
+    if options.resume {
+        reject(
+            "--resume",
+            &[
+                ("--seed", options.seed.is_some()),
+                ("--record", options.record.is_some()),
+                ("--auto", options.auto),
+                ("--batch", options.batch),
+                ("--players", options.players.is_some()),
+            ],
+        )?;
+    }
+
+    // The number of the day is the same normal game for everyone, played by a person
+    if options.command == Command::Daily {
+        reject(
+            "daily",
+            &[
+                ("--difficulty", difficulty != Difficulty::default()),
+                ("--min", min.is_some()),
+                ("--max", max.is_some()),
+                ("--attempts", attempts.is_some()),
+                ("--hints-after", hints_after.is_some()),
+                ("--lies", lies > 0),
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--time", time_limit.is_some()),
+                ("--type", options.number_type.is_some()),
+                ("--seed", options.seed.is_some()),
+                ("--record", options.record.is_some()),
+                ("--resume", options.resume),
+                ("--players", options.players.is_some()),
+                ("--auto", options.auto),
+                ("--batch", options.batch),
+            ],
+        )?;
+    }
+
+    // Only the classic game has hints, hot/cold, saves, transcripts, and the lie solver
+    if options.number_type.is_some() {
+        reject(
+            "--type",
+            &[
+                ("--hints-after", hints_after.is_some()),
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--record", options.record.is_some()),
+                ("--batch", options.batch),
+                ("--players", options.players.is_some()),
+                ("--resume", options.resume),
+                ("--tui", options.tui),
+            ],
+        )?;
+        if options.auto && lies > 0 {
+            return Err(CliError::Conflict("--auto", "--lies"));
+        }
+    } else if options.tolerance.is_some() {
+        return Err(CliError::Requires("--tolerance", "--type"));
+    }
+
+    // The computer guesses the player's number, so nothing else of a number game applies
+    if options.command == Command::Reverse {
+        reject(
+            "reverse",
+            &[
+                ("--type", options.number_type.is_some()),
+                ("--hints-after", hints_after.is_some()),
+                ("--lies", lies > 0),
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--time", time_limit.is_some()),
+                ("--record", options.record.is_some()),
+                ("--resume", options.resume),
+                ("--players", options.players.is_some()),
+                ("--auto", options.auto),
+                ("--batch", options.batch),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // A code of digits has no range, and none of the features built on numbers
+    if options.command == Command::Bulls {
+        reject(
+            "bulls",
+            &[
+                ("--difficulty", difficulty != Difficulty::default()),
+                ("--min", min.is_some()),
+                ("--max", max.is_some()),
+                ("--type", options.number_type.is_some()),
+                ("--hints-after", hints_after.is_some()),
+                ("--lies", lies > 0),
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--time", time_limit.is_some()),
+                ("--record", options.record.is_some()),
+                ("--resume", options.resume),
+                ("--players", options.players.is_some()),
+                ("--batch", options.batch),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // Neither does a word, and only people guess words
+    if options.command == Command::Words {
+        reject(
+            "words",
+            &[
+                ("--difficulty", difficulty != Difficulty::default()),
+                ("--min", min.is_some()),
+                ("--max", max.is_some()),
+                ("--type", options.number_type.is_some()),
+                ("--hints-after", hints_after.is_some()),
+                ("--lies", lies > 0),
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--time", time_limit.is_some()),
+                ("--record", options.record.is_some()),
+                ("--resume", options.resume),
+                ("--players", options.players.is_some()),
+                ("--auto", options.auto),
+                ("--batch", options.batch),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // Hosted games are played by people, line by line, on the settings of the server
+    if options.command == Command::Serve {
+        reject(
+            "serve",
+            &[
+                ("--type", options.number_type.is_some()),
+                ("--record", options.record.is_some()),
+                ("--resume", options.resume),
+                ("--players", options.players.is_some()),
+                ("--auto", options.auto),
+                ("--batch", options.batch),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // Strategies are measured on plain directions, without any limit, over fresh games
+    if options.command == Command::Bench {
+        reject(
+            "bench",
+            &[
+                ("--type", options.number_type.is_some()),
+                ("--attempts", attempts.is_some()),
+                ("--hints-after", hints_after.is_some()),
+                ("--lies", lies > 0),
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--time", time_limit.is_some()),
+                ("--record", options.record.is_some()),
+                ("--resume", options.resume),
+                ("--players", options.players.is_some()),
+                ("--auto", options.auto),
+                ("--batch", options.batch),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // A client plays the game of its server, and the other commands only read past games
+    let command: Option<&'static str> = match options.command {
+        Command::Connect => Some("connect"),
+        Command::Scores => Some("scores"),
+        Command::Stats => Some("stats"),
+        Command::Replay => Some("replay"),
+        _ => None,
+    };
+    if let Some(command) = command {
+        reject(
+            command,
+            &[
+                ("--difficulty", difficulty != Difficulty::default()),
+                ("--min", min.is_some()),
+                ("--max", max.is_some()),
+                ("--type", options.number_type.is_some()),
+                ("--attempts", attempts.is_some()),
+                ("--hints-after", hints_after.is_some()),
+                ("--lies", lies > 0),
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--time", time_limit.is_some()),
+                ("--seed", options.seed.is_some()),
+                ("--record", options.record.is_some()),
+                ("--resume", options.resume),
+                ("--players", options.players.is_some()),
+                ("--auto", options.auto),
+                ("--batch", options.batch),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // How close a guess is cannot be lied about without giving the lie away
+    if lies > 0 && feedback != Feedback::Classic {
+        return Err(CliError::Conflict("--lies", "--hot-cold"));
+    }
+
+    // Hot-seat players take turns on the plain terminal, and none of them is the computer
+    if options.players.is_some() {
+        reject(
+            "--players",
+            &[
+                ("--record", options.record.is_some()),
+                ("--auto", options.auto),
+                ("--batch", options.batch),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // Batch games answer in JSON with the outcome of every guess, and nothing else
+    if options.batch {
+        reject(
+            "--batch",
+            &[
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--time", time_limit.is_some()),
+                ("--record", options.record.is_some()),
+                ("--auto", options.auto),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // The computer plays at once, on the plain terminal, and reads the direction of every guess
+    if options.auto {
+        reject(
+            "--auto",
+            &[
+                ("--hot-cold", feedback != Feedback::Classic),
+                ("--time", time_limit.is_some()),
+                ("--record", options.record.is_some()),
+                ("--tui", options.tui),
+            ],
+        )?;
+    }
+
+    // Explicit bounds win over the difficulty preset; other types parse them later
+    let (preset_min, preset_max): (u32, u32) = difficulty.range();
+    let bound = |option: &str, value: &Option<String>, preset: u32| -> Result<u32, CliError> {
+        match (value, options.number_type) {
+            (Some(value), None) => value.trim().parse().map_err(|_| CliError::InvalidValue {
+                option: option.to_string(),
+                value: value.clone(),
+            }),
+            _ => Ok(preset),
+        }
+    };
+    options.settings = Settings::new(bound("--min", &min, preset_min)?, bound("--max", &max, preset_max)?)?;
+    (options.min, options.max) = (min, max);
+
+    // The attempt limit defaults to the one of the range and feedback
+    options.settings = options
+        .settings
+        .with_feedback(feedback)
+        .with_lies(lies)
+        .with_time_limit(time_limit);
+    if let Some(attempts) = attempts {
+        options.settings = options.settings.with_max_attempts(attempts)?;
+    }
+    options.attempts = attempts;
+    if let Some(hints_after) = hints_after {
+        options.settings = options.settings.with_hints_after(hints_after);
+    }
+
+    Ok(options)
+}
+
+/// Fail on the first of the `conflicts` that was given along with `option`.
+fn reject(option: &'static str, conflicts: &[(&'static str, bool)]) -> Result<(), CliError> {
+    match conflicts.iter().find(|(_, given)| *given) {
+        Some((conflict, _)) => Err(CliError::Conflict(option, conflict)),
+        None => Ok(()),
+    }
+}
+
+/// Take the value of an option, either inline or from the next argument.
+fn value_of<I>(name: &str, inline_value: Option<String>, args: &mut I) -> Result<String, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    inline_value
+        .or_else(|| args.next())
+        .ok_or_else(|| CliError::MissingValue(name.to_string()))
+}
+
+/// Take the value of an option and parse it.
+fn parse_value<T, I>(name: &str, inline_value: Option<String>, args: &mut I) -> Result<T, CliError>
+where
+    T: std::str::FromStr,
+    I: Iterator<Item = String>,
+{
+    let value: String = value_of(name, inline_value, args)?;
+    value.trim().parse().map_err(|_| CliError::InvalidValue {
+        option: name.to_string(),
+        value,
+    })
+}
+
+/// Parse a count, where the `none` keyword (e.g. `unlimited`) means no count at all.
+fn parse_limit(name: &str, value: String, none: &str) -> Result<Option<u32>, CliError> {
+    if value.trim().eq_ignore_ascii_case(none) {
+        return Ok(None);
+    }
+    match value.trim().parse() {
+        Ok(attempts) => Ok(Some(attempts)),
+        Err(_) => Err(CliError::InvalidValue {
+            option: name.to_string(),
+            value,
+        }),
+    }
+}
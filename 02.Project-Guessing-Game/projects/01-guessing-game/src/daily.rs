@@ -0,0 +1,255 @@
+/****************************************************************/
+/* Daily: One number a day, the same for everyone, scored once. */
+/****************************************************************/
+
+//! The daily challenge draws its secret number from the UTC date, so that
+//! everyone playing the same build gets the same puzzle on the same day,
+//! wherever they are. It is always a normal game (1-100, 7 attempts).
+//!
+//! The days already played are kept in `daily.tsv`, next to the history,
+//! one tab-separated line per save, so that only the first game of a day
+//! is scored:
+//!
+//! ```text
+//! v1    2026-10-17    7    too_big,too_small,too_big,correct
+//! ```
+
+// Import libraries/modules
+use crate::date::Date;
+use crate::game::{seeded_rng, Game, GuessOutcome};
+use crate::history;
+use crate::session::{LineOutcome, Observer, SessionEnd};
+use crate::settings::Settings;
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// The name of the file of the days played, inside the data directory.
+const DAILY_FILE: &str = "daily.tsv";
+
+/// The first field of every line, to tell formats apart after upgrades.
+const FORMAT_VERSION: &str = "v1";
+
+/// Mixed into the seed of the day, so that `--seed` games do not replay it.
+const DAILY_SALT: u64 = 0xDA11_C4A1_1E46_E500;
+
+/// The seed of the secret number of a day.
+pub fn seed(date: Date) -> u64 {
+    DAILY_SALT ^ date.to_days() as u64
+}
+
+/// The game of a day, the same on every machine.
+pub fn game(date: Date) -> Game {
+    Game::from_rng(Settings::default(), &mut seeded_rng(seed(date)))
+}
+
+/// How a daily game went, as stored and shared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailyResult {
+    /// The day of the game.
+    pub date: Date,
+    /// The attempts allowed.
+    pub max_attempts: u32,
+    /// The outcome of every guess, in order.
+    pub outcomes: Vec<GuessOutcome>,
+}
+
+impl DailyResult {
+    /// The result of a daily game, finished or not.
+    pub fn from_game(date: Date, game: &Game) -> Self {
+        Self {
+            date,
+            max_attempts: game.settings().max_attempts().unwrap_or(game.attempts()),
+            outcomes: game.guesses().iter().map(|guess| guess.outcome).collect(),
+        }
+    }
+
+    /// Whether the number of the day was found.
+    pub fn is_won(&self) -> bool {
+        self.outcomes.last() == Some(&GuessOutcome::Correct)
+    }
+
+    /// The result as a spoiler-free text to paste to others: the attempts,
+    /// or `X` when the number was not found, and one emoji per guess.
+    ///
+    /// It stays in English whatever the language of the game, so that
+    /// results can be compared side by side.
+    ///
+    /// ```text
+    /// Guessing Game daily 2026-10-17: 4/7
+    /// 🔽🔼🔽🎯
+    /// ```
+    pub fn share(&self) -> String {
+        let attempts: String = match self.is_won() {
+            true => self.outcomes.len().to_string(),
+            false => "X".to_string(),
+        };
+        let trail: String = self
+            .outcomes
+            .iter()
+            .map(|outcome| match outcome {
+                GuessOutcome::TooSmall => "🔼",
+                GuessOutcome::TooBig => "🔽",
+                _ => "🎯",
+            })
+            .collect();
+        format!("Guessing Game daily {}: {attempts}/{}\n{trail}", self.date, self.max_attempts)
+    }
+
+    /// Encode the result as one tab-separated line, without the line ending.
+    pub fn to_line(&self) -> String {
+        let outcomes: Vec<&str> = self.outcomes.iter().map(|outcome| outcome.name()).collect();
+        format!("{FORMAT_VERSION}\t{}\t{}\t{}", self.date, self.max_attempts, outcomes.join(","))
+    }
+
+    /// Decode a line written by [`DailyResult::to_line`], or `None` if it is corrupted.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.trim_end_matches(['\r', '\n']).split('\t').collect();
+        let [version, date, max_attempts, outcomes] = fields[..] else {
+            return None;
+        };
+        if version != FORMAT_VERSION {
+            return None;
+        }
+
+        Some(DailyResult {
+            date: date.parse().ok()?,
+            max_attempts: max_attempts.parse().ok()?,
+            outcomes: outcomes
+                .split(',')
+                .filter(|name| !name.is_empty())
+                .map(GuessOutcome::from_name)
+                .collect::<Option<Vec<GuessOutcome>>>()?,
+        })
+    }
+}
+
+/// The file of the days played, one [`DailyResult`] per line; the last
+/// line of a day is its result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailyLog {
+    path: PathBuf,
+}
+
+impl DailyLog {
+    /// Use the file at the given path.
+    pub fn at<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Use the file inside the user's data directory.
+    pub fn open_default() -> io::Result<Self> {
+        Ok(Self::at(history::data_dir()?.join(DAILY_FILE)))
+    }
+
+    /// The path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The last result of a day, if it was played; a missing file holds no days.
+    ///
+    /// Corrupted lines are skipped, as in the history.
+    pub fn find(&self, date: Date) -> io::Result<Option<DailyResult>> {
+        let file: File = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        file.lock_shared()?;
+
+        let mut reader: BufReader<File> = BufReader::new(file);
+        let mut bytes: Vec<u8> = Vec::new();
+        let mut found: Option<DailyResult> = None;
+        while reader.read_until(b'\n', &mut bytes)? > 0 {
+            let decoded: Option<DailyResult> = std::str::from_utf8(&bytes).ok().and_then(DailyResult::from_line);
+            if let Some(result) = decoded.filter(|result| result.date == date) {
+                found = Some(result);
+            }
+            bytes.clear();
+        }
+        Ok(found)
+    }
+
+    /// Record the result of a day, creating the file if needed.
+    pub fn append(&self, result: &DailyResult) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file: File = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&self.path)?;
+        file.lock()?;
+
+        // Terminate a line left unfinished by a crash, so that it does not swallow ours
+        let mut line: String = String::new();
+        if file.metadata()?.len() > 0 {
+            let mut last: [u8; 1] = [0];
+            file.seek(SeekFrom::End(-1))?;
+            file.read_exact(&mut last)?;
+            if last[0] != b'\n' {
+                line.push('\n');
+            }
+        }
+        line.push_str(&result.to_line());
+        line.push('\n');
+
+        // Write the whole line at once
+        file.write_all(line.as_bytes())?;
+        file.flush()
+    }
+}
+
+/// Records a daily game in the log: once its first guess is made, so that
+/// leaving the game halfway, even by killing it, does not allow playing it
+/// again for score, and again when the session ends, with every guess,
+/// even none: the summary gives the number away.
+///
+/// Failing to record never stops the game: the last error is kept for the
+/// caller to report instead.
+#[derive(Debug)]
+pub struct DailyRecorder {
+    log: DailyLog,
+    date: Date,
+    error: Option<io::Error>,
+}
+
+impl DailyRecorder {
+    /// Record the game of `date` to `log`.
+    pub fn new(log: DailyLog, date: Date) -> Self {
+        Self { log, date, error: None }
+    }
+
+    /// The last error met while recording, if any.
+    pub fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+
+    /// Append the game as it stands.
+    fn record(&mut self, game: &Game) {
+        if let Err(err) = self.log.append(&DailyResult::from_game(self.date, game)) {
+            self.error = Some(err);
+        }
+    }
+}
+
+impl Observer for DailyRecorder {
+    fn on_line(&mut self, _line: &str, outcome: LineOutcome, game: &Game) -> io::Result<()> {
+        let judged: bool = matches!(
+            outcome,
+            LineOutcome::Guess(GuessOutcome::TooSmall | GuessOutcome::TooBig | GuessOutcome::Correct)
+        );
+        if judged && game.attempts() == 1 {
+            self.record(game);
+        }
+        Ok(())
+    }
+
+    fn on_end(&mut self, _end: SessionEnd, game: &Game) -> io::Result<()> {
+        self.record(game);
+        Ok(())
+    }
+}
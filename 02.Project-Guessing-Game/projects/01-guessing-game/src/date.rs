@@ -0,0 +1,105 @@
+/*******************************************************/
+/* Date: Calendar dates in UTC, without any time zone. */
+/*******************************************************/
+
+// Import libraries/modules
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// A calendar date in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year, e.g. `2024`.
+    pub year: i32,
+    /// The month, from `1` to `12`.
+    pub month: u32,
+    /// The day of the month, from `1` to `31`.
+    pub day: u32,
+}
+
+impl Date {
+    /// The date of a Unix timestamp, in seconds.
+    pub fn from_timestamp(timestamp: u64) -> Self {
+        Self::from_days((timestamp / SECONDS_PER_DAY) as i64)
+    }
+
+    /// Today's date in UTC.
+    pub fn today() -> Self {
+        Self::from_timestamp(unix_timestamp())
+    }
+
+    /// The date a number of days after 1970-01-01.
+    ///
+    /// This is the `civil_from_days` algorithm of Howard Hinnant, which
+    /// works on 400-year eras of the proleptic Gregorian calendar.
+    pub fn from_days(days: i64) -> Self {
+        let z: i64 = days + 719_468;
+        let era: i64 = z.div_euclid(146_097);
+        let day_of_era: i64 = z.rem_euclid(146_097);
+        let year_of_era: i64 =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march: i64 = (5 * day_of_year + 2) / 153;
+        let day: i64 = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month: i64 = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+        let year: i64 = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    /// The number of days since 1970-01-01, the inverse of [`Date::from_days`].
+    pub fn to_days(self) -> i64 {
+        let year: i64 = i64::from(self.year) - i64::from(self.month <= 2);
+        let era: i64 = year.div_euclid(400);
+        let year_of_era: i64 = year.rem_euclid(400);
+        let month: i64 = i64::from(self.month);
+        let month_from_march: i64 = if month > 2 { month - 3 } else { month + 9 };
+        let day_of_year: i64 = (153 * month_from_march + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ();
+
+    /// Parse a date written as `YYYY-MM-DD`, which must exist in the calendar.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.trim().splitn(3, '-');
+        let mut field = || fields.next().ok_or(());
+        let date: Date = Date {
+            year: field()?.parse().map_err(|_| ())?,
+            month: field()?.parse().map_err(|_| ())?,
+            day: field()?.parse().map_err(|_| ())?,
+        };
+
+        // A day past the end of its month would land on another date
+        match (1..=12).contains(&date.month) && Date::from_days(date.to_days()) == date {
+            true => Ok(date),
+            false => Err(()),
+        }
+    }
+}
+
+/// The number of seconds since 1970-01-01 00:00:00 UTC.
+pub fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,349 @@
+/*****************************************************************************/
+/* Domain: Secret numbers of any integer type, or floats within a tolerance. */
+/*****************************************************************************/
+
+//! The classic game draws a `u32`. The same [`Game`] plays over every
+//! integer type of `02-data-types`, from `i8` to `u128`, and over `f32` and
+//! `f64`, where a guess wins once it is within a tolerance of the secret
+//! number. This module holds what the game needs to know about each type.
+
+// Import libraries/modules
+use crate::game::{Game, GameStatus, GuessOutcome};
+use crate::messages;
+use rand::{Rng, RngExt};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The tolerance of a float game, unless `--tolerance` is given.
+pub const DEFAULT_FLOAT_TOLERANCE: f64 = 0.01;
+
+/// A type that secret numbers can be drawn from.
+///
+/// Integers compare exactly unless given a tolerance, and floats always
+/// need one, since a player cannot type every digit of a random float.
+pub trait Number: Copy + PartialOrd + fmt::Display + fmt::Debug {
+    /// The name of the type, as typed after `--type`.
+    const NAME: &'static str;
+
+    /// Zero, the tolerance of an exact match.
+    const ZERO: Self;
+
+    /// The tolerance unless `--tolerance` is given.
+    const DEFAULT_TOLERANCE: Self;
+
+    /// Parse a number as typed by the player; NaN and infinities are not numbers.
+    fn parse(text: &str) -> Option<Self>;
+
+    /// The number equal to the value of an arithmetic guess, if the type holds it.
+    fn from_i64(value: i64) -> Option<Self>;
+
+    /// The value of the number in arithmetic guesses, if it is a whole `i64`.
+    fn to_i64(self) -> Option<i64>;
+
+    /// Whether the number can be used as a tolerance.
+    fn is_valid_tolerance(self) -> bool;
+
+    /// Whether `low..=high` is a range that numbers can be drawn from.
+    fn is_valid_range(low: Self, high: Self) -> bool;
+
+    /// Whether two numbers are at most `tolerance` apart.
+    fn is_within(self, other: Self, tolerance: Self) -> bool;
+
+    /// The number halfway between `low` and `high`, rounded down, without overflow.
+    fn midpoint(low: Self, high: Self) -> Self;
+
+    /// The smallest number more than `tolerance` above this one, if any.
+    fn above(self, tolerance: Self) -> Option<Self>;
+
+    /// The largest number more than `tolerance` below this one, if any.
+    fn below(self, tolerance: Self) -> Option<Self>;
+
+    /// The number of guesses a perfect bisection needs, in the worst case,
+    /// to come within `tolerance` of any number of `low..=high`.
+    fn attempt_bound(low: Self, high: Self, tolerance: Self) -> u32;
+
+    /// The number of guesses that it takes to cover `low..=high` when each
+    /// covers the numbers within `tolerance` of it, saturating at `u128::MAX`.
+    fn count(low: Self, high: Self, tolerance: Self) -> u128;
+
+    /// Draw a number of `low..=high` at random.
+    fn random<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self;
+}
+
+/// Implement [`Number`] for integer types, through their two's complement
+/// as `u128`, where every difference fits.
+macro_rules! impl_integer {
+    ($($int:ty),*) => {$(
+        impl Number for $int {
+            const NAME: &'static str = stringify!($int);
+            const ZERO: Self = 0;
+            const DEFAULT_TOLERANCE: Self = 0;
+
+            fn parse(text: &str) -> Option<Self> {
+                text.trim().parse().ok()
+            }
+
+            fn from_i64(value: i64) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+
+            fn to_i64(self) -> Option<i64> {
+                i64::try_from(self).ok()
+            }
+
+            // Unsigned tolerances are never negative
+            #[allow(unused_comparisons)]
+            fn is_valid_tolerance(self) -> bool {
+                self >= 0
+            }
+
+            fn is_valid_range(low: Self, high: Self) -> bool {
+                low < high
+            }
+
+            fn is_within(self, other: Self, tolerance: Self) -> bool {
+                let (low, high): (Self, Self) = if self < other { (self, other) } else { (other, self) };
+                span(low as u128, high as u128) <= tolerance as u128
+            }
+
+            fn midpoint(low: Self, high: Self) -> Self {
+                (low as u128).wrapping_add(span(low as u128, high as u128) / 2) as Self
+            }
+
+            fn above(self, tolerance: Self) -> Option<Self> {
+                self.checked_add(tolerance)?.checked_add(1)
+            }
+
+            fn below(self, tolerance: Self) -> Option<Self> {
+                self.checked_sub(tolerance)?.checked_sub(1)
+            }
+
+            fn attempt_bound(low: Self, high: Self, tolerance: Self) -> u32 {
+                // Each guess covers the numbers within the tolerance of it
+                let width: u128 = (tolerance as u128).saturating_mul(2).saturating_add(1);
+                let cells: u128 = span(low as u128, high as u128) / width;
+                match cells.checked_add(1) {
+                    Some(count) => u128::BITS - count.leading_zeros(),
+                    None => u128::BITS + 1,
+                }
+            }
+
+            fn count(low: Self, high: Self, tolerance: Self) -> u128 {
+                let width: u128 = (tolerance as u128).saturating_mul(2).saturating_add(1);
+                (span(low as u128, high as u128) / width).saturating_add(1)
+            }
+
+            fn random<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+                rng.random_range(low..=high)
+            }
+        }
+    )*};
+}
+
+/// Implement [`Number`] for float types.
+macro_rules! impl_float {
+    ($($float:ident),*) => {$(
+        impl Number for $float {
+            const NAME: &'static str = stringify!($float);
+            const ZERO: Self = 0.0;
+            const DEFAULT_TOLERANCE: Self = DEFAULT_FLOAT_TOLERANCE as $float;
+
+            fn parse(text: &str) -> Option<Self> {
+                text.trim().parse().ok().filter(|number: &Self| number.is_finite())
+            }
+
+            fn from_i64(value: i64) -> Option<Self> {
+                Some(value as Self)
+            }
+
+            fn to_i64(self) -> Option<i64> {
+                // Every float of the range of i64 converts exactly once it is whole
+                let whole: bool = self.fract() == 0.0 && (i64::MIN as Self..i64::MAX as Self).contains(&self);
+                whole.then_some(self as i64)
+            }
+
+            fn is_valid_tolerance(self) -> bool {
+                self.is_finite() && self > 0.0
+            }
+
+            fn is_valid_range(low: Self, high: Self) -> bool {
+                low < high && (high - low).is_finite()
+            }
+
+            fn is_within(self, other: Self, tolerance: Self) -> bool {
+                (self - other).abs() <= tolerance
+            }
+
+            fn midpoint(low: Self, high: Self) -> Self {
+                (low / 2.0 + high / 2.0).clamp(low, high)
+            }
+
+            fn above(self, tolerance: Self) -> Option<Self> {
+                // A tolerance below the precision still moves on
+                Some((self + tolerance).max(self.next_up())).filter(|number| number.is_finite())
+            }
+
+            fn below(self, tolerance: Self) -> Option<Self> {
+                Some((self - tolerance).min(self.next_down())).filter(|number| number.is_finite())
+            }
+
+            fn attempt_bound(low: Self, high: Self, tolerance: Self) -> u32 {
+                // The guess wins once the candidates are at most twice the tolerance wide
+                let halvings: f64 = (f64::from(high / 2.0 - low / 2.0) / f64::from(tolerance)).log2().ceil();
+                halvings.max(0.0) as u32 + 1
+            }
+
+            fn count(low: Self, high: Self, tolerance: Self) -> u128 {
+                // Casting saturates, so even the widest ranges have a count
+                (f64::from(high / 2.0 - low / 2.0) / f64::from(tolerance)).ceil().max(1.0) as u128
+            }
+
+            fn random<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+                rng.random_range(low..=high)
+            }
+        }
+    )*};
+}
+
+impl_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_float!(f32, f64);
+
+/// The distance from `low` to `high`, as two's complement `u128`.
+fn span(low: u128, high: u128) -> u128 {
+    high.wrapping_sub(low)
+}
+
+/// Every type that secret numbers can be drawn from, as picked with `--type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberType {
+    /// 8-bit signed integers.
+    I8,
+    /// 16-bit signed integers.
+    I16,
+    /// 32-bit signed integers.
+    I32,
+    /// 64-bit signed integers.
+    I64,
+    /// 128-bit signed integers.
+    I128,
+    /// 8-bit unsigned integers.
+    U8,
+    /// 16-bit unsigned integers.
+    U16,
+    /// 32-bit unsigned integers.
+    U32,
+    /// 64-bit unsigned integers.
+    U64,
+    /// 128-bit unsigned integers.
+    U128,
+    /// Single-precision floats.
+    F32,
+    /// Double-precision floats.
+    F64,
+}
+
+impl NumberType {
+    /// Every type, signed integers first, as in `02-data-types`.
+    pub const ALL: [NumberType; 12] = [
+        NumberType::I8,
+        NumberType::I16,
+        NumberType::I32,
+        NumberType::I64,
+        NumberType::I128,
+        NumberType::U8,
+        NumberType::U16,
+        NumberType::U32,
+        NumberType::U64,
+        NumberType::U128,
+        NumberType::F32,
+        NumberType::F64,
+    ];
+
+    /// The name of the type, as typed on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            NumberType::I8 => i8::NAME,
+            NumberType::I16 => i16::NAME,
+            NumberType::I32 => i32::NAME,
+            NumberType::I64 => i64::NAME,
+            NumberType::I128 => i128::NAME,
+            NumberType::U8 => u8::NAME,
+            NumberType::U16 => u16::NAME,
+            NumberType::U32 => u32::NAME,
+            NumberType::U64 => u64::NAME,
+            NumberType::U128 => u128::NAME,
+            NumberType::F32 => f32::NAME,
+            NumberType::F64 => f64::NAME,
+        }
+    }
+}
+
+impl fmt::Display for NumberType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for NumberType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NumberType::ALL
+            .into_iter()
+            .find(|number_type| number_type.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| DomainError::UnknownType(s.to_string()))
+    }
+}
+
+/// The reasons why a domain can be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainError {
+    /// The type is not one of [`NumberType::ALL`].
+    UnknownType(String),
+    /// A bound or tolerance is not a number of the type.
+    NotANumber { value: String, type_name: &'static str },
+    /// The range holds a single number, is inverted, or is too wide to draw from.
+    InvalidRange { min: String, max: String },
+    /// The tolerance is negative, or zero for a float.
+    InvalidTolerance(String),
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::domain_error(self))
+    }
+}
+
+impl Error for DomainError {}
+
+/// Parse a bound or a tolerance given on the command line.
+pub fn parse_number<T: Number>(text: &str) -> Result<T, DomainError> {
+    T::parse(text).ok_or_else(|| DomainError::NotANumber {
+        value: text.to_string(),
+        type_name: T::NAME,
+    })
+}
+
+/// Let a bisection play `game` until it is over, reporting every step.
+///
+/// Every guess is the middle of the [`Game::candidates`] left, so the
+/// secret number is found within the attempt bound of its settings.
+pub fn solve<T, F>(game: &mut Game<T>, mut on_step: F) -> GameStatus
+where
+    T: Number,
+    F: FnMut(T, GuessOutcome),
+{
+    while !game.is_over() {
+        let Some((low, high)) = game.candidates() else {
+            break;
+        };
+
+        // Feed the guess through the same path as typed input
+        let guess: T = T::midpoint(low, high);
+        let outcome: GuessOutcome = game.submit(&guess.to_string());
+        on_step(guess, outcome);
+    }
+
+    game.status()
+}
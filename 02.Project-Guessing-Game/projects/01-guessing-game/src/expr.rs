@@ -0,0 +1,236 @@
+/**************************************************************/
+/* Expr: Arithmetic expressions typed as guesses, like 50+25. */
+/**************************************************************/
+
+// Import libraries/modules
+use crate::messages;
+use std::error::Error;
+use std::fmt;
+
+/// The deepest nesting of parentheses and signs accepted, so that a hostile
+/// line such as `((((...` cannot overflow the stack of a server.
+const MAX_DEPTH: usize = 64;
+
+/// The reasons why an expression can be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprErrorKind {
+    /// A number, `last`, or `(` was expected.
+    ExpectedOperand(Option<char>),
+    /// An operator or the end of the expression was expected.
+    ExpectedOperator(char),
+    /// A `(` was never closed.
+    UnclosedParen,
+    /// A name other than `last` was used.
+    UnknownName(String),
+    /// `last` was used before any valid guess.
+    NoLastGuess,
+    /// The right-hand side of `/` or `%` is zero.
+    DivisionByZero,
+    /// A number or result does not fit in 64 bits.
+    Overflow,
+    /// The parentheses or signs are nested too deeply.
+    TooDeep,
+}
+
+/// An expression that could not be evaluated, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    /// The column of the offending character, from 1, counted in characters.
+    pub column: usize,
+    /// What went wrong.
+    pub kind: ExprErrorKind,
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::expr_error(self))
+    }
+}
+
+impl Error for ExprError {}
+
+/// Evaluate an expression with integer semantics.
+///
+/// The grammar is the usual one: `+ - * / %` with the usual precedence,
+/// unary signs, parentheses, and `last` for the last valid guess. Division
+/// truncates toward zero, as in Rust.
+pub fn evaluate(input: &str, last: Option<i64>) -> Result<i64, ExprError> {
+    let mut parser: Parser = Parser {
+        chars: input.chars().collect(),
+        pos: 0,
+        depth: 0,
+        last,
+    };
+    let value: i64 = parser.expr()?;
+
+    // Anything left over is a missing operator
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(value),
+        Some(found) => Err(parser.error(ExprErrorKind::ExpectedOperator(found))),
+    }
+}
+
+/// A recursive-descent parser that evaluates as it goes.
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    depth: usize,
+    last: Option<i64>,
+}
+
+impl Parser {
+    /// `term (('+' | '-') term)*`
+    fn expr(&mut self) -> Result<i64, ExprError> {
+        let mut value: i64 = self.term()?;
+        loop {
+            self.skip_whitespace();
+            let op: char = match self.peek() {
+                Some(op @ ('+' | '-')) => op,
+                _ => return Ok(value),
+            };
+            let column: usize = self.column();
+            self.pos += 1;
+            let rhs: i64 = self.term()?;
+            let result: Option<i64> = match op {
+                '+' => value.checked_add(rhs),
+                _ => value.checked_sub(rhs),
+            };
+            value = result.ok_or(ExprError {
+                column,
+                kind: ExprErrorKind::Overflow,
+            })?;
+        }
+    }
+
+    /// `unary (('*' | '/' | '%') unary)*`
+    fn term(&mut self) -> Result<i64, ExprError> {
+        let mut value: i64 = self.unary()?;
+        loop {
+            self.skip_whitespace();
+            let op: char = match self.peek() {
+                Some(op @ ('*' | '/' | '%')) => op,
+                _ => return Ok(value),
+            };
+            let column: usize = self.column();
+            self.pos += 1;
+            let rhs: i64 = self.unary()?;
+            if op != '*' && rhs == 0 {
+                return Err(ExprError {
+                    column,
+                    kind: ExprErrorKind::DivisionByZero,
+                });
+            }
+            let result: Option<i64> = match op {
+                '*' => value.checked_mul(rhs),
+                '/' => value.checked_div(rhs),
+                _ => value.checked_rem(rhs),
+            };
+            value = result.ok_or(ExprError {
+                column,
+                kind: ExprErrorKind::Overflow,
+            })?;
+        }
+    }
+
+    /// `('+' | '-') unary | atom`
+    fn unary(&mut self) -> Result<i64, ExprError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(sign @ ('+' | '-')) => {
+                let column: usize = self.column();
+                self.pos += 1;
+                let value: i64 = self.nested(Self::unary)?;
+                match sign {
+                    '+' => Ok(value),
+                    _ => value.checked_neg().ok_or(ExprError {
+                        column,
+                        kind: ExprErrorKind::Overflow,
+                    }),
+                }
+            }
+            _ => self.atom(),
+        }
+    }
+
+    /// `number | 'last' | '(' expr ')'`
+    fn atom(&mut self) -> Result<i64, ExprError> {
+        self.skip_whitespace();
+        let start: usize = self.pos;
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let value: i64 = self.nested(Self::expr)?;
+                self.skip_whitespace();
+                if self.peek() != Some(')') {
+                    return Err(self.error(ExprErrorKind::UnclosedParen));
+                }
+                self.pos += 1;
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let digits: String = self.take_while(|c| c.is_ascii_digit());
+                digits.parse().map_err(|_| ExprError {
+                    column: start + 1,
+                    kind: ExprErrorKind::Overflow,
+                })
+            }
+            Some(c) if c.is_alphabetic() => {
+                let name: String = self.take_while(char::is_alphanumeric);
+                if !name.eq_ignore_ascii_case("last") {
+                    return Err(ExprError {
+                        column: start + 1,
+                        kind: ExprErrorKind::UnknownName(name),
+                    });
+                }
+                self.last.ok_or(ExprError {
+                    column: start + 1,
+                    kind: ExprErrorKind::NoLastGuess,
+                })
+            }
+            found => Err(self.error(ExprErrorKind::ExpectedOperand(found))),
+        }
+    }
+
+    /// Parse one level deeper, refusing to go past [`MAX_DEPTH`].
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<i64, ExprError>) -> Result<i64, ExprError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(ExprErrorKind::TooDeep));
+        }
+        self.depth += 1;
+        let value: Result<i64, ExprError> = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    /// Consume characters while they match.
+    fn take_while(&mut self, matches: impl Fn(char) -> bool) -> String {
+        let start: usize = self.pos;
+        while self.peek().is_some_and(&matches) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// The column of the next character, from 1.
+    fn column(&self) -> usize {
+        self.pos + 1
+    }
+
+    fn error(&self, kind: ExprErrorKind) -> ExprError {
+        ExprError {
+            column: self.column(),
+            kind,
+        }
+    }
+}
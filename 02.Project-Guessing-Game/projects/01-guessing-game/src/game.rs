@@ -0,0 +1,490 @@
+/*****************************************************/
+/* Game: Secret number, guesses, and their outcomes. */
+/*****************************************************/
+
+// Import libraries/modules
+use crate::domain::Number;
+use crate::expr::{self, ExprError};
+use crate::hint::{self, Hint, HintError};
+use crate::proximity::{self, Proximity};
+use crate::score;
+use crate::settings::Settings;
+use rand::rngs::Xoshiro256PlusPlus;
+use rand::{Rng, RngExt, SeedableRng};
+use std::time::{Duration, Instant};
+
+/// A game that may lie does so in one out of this many answers, until it
+/// has told as many lies as its settings allow.
+const LIE_ODDS: u32 = 3;
+
+/// The random number generator used by seeded games.
+///
+/// Xoshiro256++ is a portable generator: the same seed yields the same
+/// numbers across runs, platforms, and `rand` releases.
+pub type SeededRng = Xoshiro256PlusPlus;
+
+/// Create the random number generator of a seeded game.
+pub fn seeded_rng(seed: u64) -> SeededRng {
+    SeededRng::seed_from_u64(seed)
+}
+
+/// The outcome of submitting a guess to a [`Game`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessOutcome {
+    /// The guess is smaller than the secret number.
+    TooSmall,
+    /// The guess is bigger than the secret number.
+    TooBig,
+    /// The guess is the secret number.
+    Correct,
+    /// The guess is a number, but outside of the accepted range.
+    OutOfRange,
+    /// The guess is not a valid number.
+    Invalid,
+    /// The game is already over, so the guess was ignored.
+    GameOver,
+}
+
+/// A valid guess, as remembered by a [`Game`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Guess<T = u32> {
+    /// The number that was guessed.
+    pub value: T,
+    /// How the number compared to the secret number.
+    pub outcome: GuessOutcome,
+    /// The time since the start of the game when the guess was made.
+    pub elapsed: Duration,
+    /// Whether the outcome is a lie, in a game that may lie.
+    pub lie: bool,
+}
+
+impl GuessOutcome {
+    /// Every outcome.
+    pub const ALL: [GuessOutcome; 6] = [
+        GuessOutcome::TooSmall,
+        GuessOutcome::TooBig,
+        GuessOutcome::Correct,
+        GuessOutcome::OutOfRange,
+        GuessOutcome::Invalid,
+        GuessOutcome::GameOver,
+    ];
+
+    /// The stable `snake_case` name of the outcome, as written in transcripts.
+    pub fn name(self) -> &'static str {
+        match self {
+            GuessOutcome::TooSmall => "too_small",
+            GuessOutcome::TooBig => "too_big",
+            GuessOutcome::Correct => "correct",
+            GuessOutcome::OutOfRange => "out_of_range",
+            GuessOutcome::Invalid => "invalid",
+            GuessOutcome::GameOver => "game_over",
+        }
+    }
+
+    /// The outcome with the given [`name`](GuessOutcome::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        GuessOutcome::ALL.into_iter().find(|outcome| outcome.name() == name)
+    }
+}
+
+/// Where a game stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    /// The secret number is still to be found.
+    InProgress,
+    /// The secret number was found.
+    Won,
+    /// Every allowed attempt was used, or the time ran out, without finding
+    /// the secret number.
+    Lost,
+}
+
+/// A single round of the guessing game, over `u32` unless the settings
+/// pick another [`Number`] type.
+#[derive(Debug, Clone)]
+pub struct Game<T = u32> {
+    settings: Settings<T>,
+    secret_num: T,
+    guesses: Vec<Guess<T>>,
+    hints: Vec<Hint>,
+    status: GameStatus,
+    started: Instant,
+    finished: Option<Instant>,
+    timed_out: bool,
+    /// The seed that the lies are drawn from.
+    lie_seed: u64,
+    /// Decides when to lie, in a game that may lie.
+    liar: Option<SeededRng>,
+}
+
+impl Game {
+    /// Start a new game with a random secret number within the range of the settings.
+    pub fn new(settings: Settings) -> Self {
+        Self::from_rng(settings, &mut rand::rng())
+    }
+
+    /// Ask for a hint about the secret number.
+    ///
+    /// Hints are offered once the player has missed as many times as the
+    /// settings say, and each one lowers the score.
+    pub fn hint(&mut self) -> Result<Hint, HintError> {
+        if self.is_over() {
+            return Err(HintError::GameOver);
+        }
+        // Hints rely on the answers, which may be lies
+        if self.settings.lies() > 0 {
+            return Err(HintError::Disabled);
+        }
+        let hints_after: u32 = self.settings.hints_after().ok_or(HintError::Disabled)?;
+        if self.attempts() < hints_after {
+            return Err(HintError::TooEarly {
+                misses_needed: hints_after,
+            });
+        }
+
+        let (low, high): (u32, u32) = self.candidates().ok_or(HintError::NoneLeft)?;
+        let hint: Hint =
+            hint::next_hint(self.secret_num, low, high, self.last_guess(), &self.hints).ok_or(HintError::NoneLeft)?;
+        self.hints.push(hint);
+        Ok(hint)
+    }
+
+    /// The hot/cold feedback of the last valid guess, compared to the one before.
+    pub fn proximity(&self) -> Option<Proximity> {
+        let (previous, last): (Option<u32>, u32) = match self.guesses.as_slice() {
+            [] => return None,
+            [.., previous, last] => (Some(previous.value), last.value),
+            [.., last] => (None, last.value),
+        };
+        Some(proximity::proximity(self.settings.size(), self.secret_num, last, previous))
+    }
+}
+
+impl<T: Number> Game<T> {
+    /// Start a new game with a secret number drawn from the given random number generator.
+    ///
+    /// Drawing several games from the same seeded generator always yields
+    /// the same sequence of secret numbers.
+    pub fn from_rng<R>(settings: Settings<T>, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        // Generate a random number between min and max, inclusive
+        let secret_num: T = T::random(settings.min(), settings.max(), rng);
+        let game: Self = Self::with_secret(settings, secret_num);
+
+        // Games that never lie draw nothing more, so their seeds keep their numbers
+        match settings.lies() {
+            0 => game,
+            _ => game.with_lie_seed(rng.random()),
+        }
+    }
+
+    /// Start a new game with a known secret number.
+    ///
+    /// A game that may lie draws its lies from the seed 0, unless
+    /// [`Game::with_lie_seed`] picks another one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the secret number is outside of the range of the settings.
+    pub fn with_secret(settings: Settings<T>, secret_num: T) -> Self {
+        assert!(
+            settings.contains(secret_num),
+            "the secret number {secret_num} is outside of {}..={}",
+            settings.min(),
+            settings.max()
+        );
+        Self {
+            settings,
+            secret_num,
+            guesses: Vec::new(),
+            hints: Vec::new(),
+            status: GameStatus::InProgress,
+            started: Instant::now(),
+            finished: None,
+            timed_out: false,
+            lie_seed: 0,
+            liar: (settings.lies() > 0).then(|| seeded_rng(0)),
+        }
+    }
+
+    /// Draw the lies of the game from `seed`, so that the same seed always
+    /// tells the same lies.
+    ///
+    /// The answers already given are replayed, so that a resumed game goes
+    /// on with the lies that it would have told without a break.
+    pub fn with_lie_seed(mut self, seed: u64) -> Self {
+        self.lie_seed = seed;
+        if self.settings.lies() == 0 {
+            return self;
+        }
+
+        // Make the same draws as the answers that were given
+        let mut liar: SeededRng = seeded_rng(seed);
+        let mut lies_told: u32 = 0;
+        for guess in &self.guesses {
+            if self.may_lie(guess.value, lies_told) {
+                liar.random_ratio(1, LIE_ODDS);
+            }
+            lies_told += u32::from(guess.lie);
+        }
+        self.liar = Some(liar);
+        self
+    }
+
+    /// Resume a game in progress from its secret number, its valid guesses,
+    /// its hints, and the time already spent playing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the secret number is outside of the range of the settings.
+    pub fn resume(
+        settings: Settings<T>,
+        secret_num: T,
+        guesses: Vec<Guess<T>>,
+        hints: Vec<Hint>,
+        elapsed: Duration,
+    ) -> Self {
+        let mut game: Self = Self::with_secret(settings, secret_num);
+        game.guesses = guesses;
+        game.hints = hints;
+
+        // Move the start back, so that the clock carries on where it stopped
+        game.started = Instant::now().checked_sub(elapsed).unwrap_or(game.started);
+        game
+    }
+
+    /// Submit the raw text typed by the player and return its outcome.
+    ///
+    /// The text is a number of the type of the game, or an expression, as
+    /// accepted by [`Game::evaluate`]. Only valid guesses within the accepted
+    /// range count as attempts. The game is lost when the last allowed
+    /// attempt misses the secret number.
+    pub fn submit(&mut self, guess: &str) -> GuessOutcome {
+        if self.is_over() || self.check_clock() {
+            return GuessOutcome::GameOver;
+        }
+
+        // Handle user input errors
+        let guess: T = match T::parse(guess) {
+            Some(num) => num,
+            None => match self.evaluate(guess).map(T::from_i64) {
+                Ok(Some(num)) => num,
+                Ok(None) => return GuessOutcome::OutOfRange,
+                Err(_) => return GuessOutcome::Invalid,
+            },
+        };
+
+        // Handle when the number is too big or too small
+        if !self.settings.contains(guess) {
+            return GuessOutcome::OutOfRange;
+        }
+
+        // Compare guess vs secret_num
+        let mut outcome: GuessOutcome = self.compare(guess);
+        let lie: bool = self.should_lie(guess);
+        if lie {
+            outcome = match outcome {
+                GuessOutcome::TooSmall => GuessOutcome::TooBig,
+                _ => GuessOutcome::TooSmall,
+            };
+        }
+        self.guesses.push(Guess {
+            value: guess,
+            outcome,
+            elapsed: self.started.elapsed(),
+            lie,
+        });
+
+        // Settle the game on a win, or when no attempt is left
+        if outcome == GuessOutcome::Correct {
+            self.finish(GameStatus::Won);
+        } else if self.attempts_left() == Some(0) {
+            self.finish(GameStatus::Lost);
+        }
+
+        outcome
+    }
+
+    /// How `guess` truly compares to the secret number: close enough wins,
+    /// as for a tolerance of zero on integers.
+    fn compare(&self, guess: T) -> GuessOutcome {
+        if guess.is_within(self.secret_num, self.settings.tolerance()) {
+            GuessOutcome::Correct
+        } else if guess < self.secret_num {
+            GuessOutcome::TooSmall
+        } else {
+            GuessOutcome::TooBig
+        }
+    }
+
+    /// Decide whether to lie about how `guess` compares to the secret number.
+    fn should_lie(&mut self, guess: T) -> bool {
+        let may_lie: bool = self.may_lie(guess, self.lies_told());
+        match &mut self.liar {
+            Some(liar) if may_lie => liar.random_ratio(1, LIE_ODDS),
+            _ => false,
+        }
+    }
+
+    /// Whether the answer to `guess` may be a lie, after `lies_told` lies.
+    ///
+    /// "Correct!" is never a lie, no more lies are told than the settings
+    /// allow, and a lie never points outside of the range: every answer stays
+    /// consistent with the secret number and at most that many lies.
+    fn may_lie(&self, guess: T, lies_told: u32) -> bool {
+        let tolerance: T = self.settings.tolerance();
+        let plausible: bool = match self.compare(guess) {
+            GuessOutcome::TooSmall => guess.below(tolerance).is_some_and(|below| below >= self.settings.min()),
+            GuessOutcome::TooBig => guess.above(tolerance).is_some_and(|above| above <= self.settings.max()),
+            _ => false,
+        };
+        lies_told < self.settings.lies() && plausible
+    }
+
+    /// The seed that the lies of the game are drawn from.
+    pub fn lie_seed(&self) -> u64 {
+        self.lie_seed
+    }
+
+    /// The number of lies told so far.
+    pub fn lies_told(&self) -> u32 {
+        self.guesses.iter().filter(|guess| guess.lie).count() as u32
+    }
+
+    /// End the game as lost if its time limit has passed, and return whether it did.
+    ///
+    /// [`Game::submit`] checks the clock too, so a guess made too late never
+    /// counts, but a session waiting for input calls this to end on time.
+    pub fn check_clock(&mut self) -> bool {
+        if self.status == GameStatus::InProgress && self.time_left() == Some(Duration::ZERO) {
+            self.finish(GameStatus::Lost);
+            self.timed_out = true;
+            return true;
+        }
+        false
+    }
+
+    /// The time left to find the number, or `None` if time is unlimited.
+    pub fn time_left(&self) -> Option<Duration> {
+        self.settings
+            .time_limit()
+            .map(|time_limit| time_limit.saturating_sub(self.elapsed()))
+    }
+
+    /// Whether the game was lost because its time limit passed.
+    pub fn is_out_of_time(&self) -> bool {
+        self.timed_out
+    }
+
+    /// Evaluate a guess, which may be an expression such as `(1+100)/2` or
+    /// `last+10`, where `last` is the last valid guess.
+    pub fn evaluate(&self, guess: &str) -> Result<i64, ExprError> {
+        expr::evaluate(guess.trim(), self.last_guess().and_then(T::to_i64))
+    }
+
+    /// Every hint given so far, oldest first.
+    pub fn hints(&self) -> &[Hint] {
+        &self.hints
+    }
+
+    /// The numbers still consistent with every "Too small!" and "Too big!", as
+    /// an inclusive `(low, high)` interval, taking every answer as the truth,
+    /// or `None` once the answers contradict each other.
+    pub fn candidates(&self) -> Option<(T, T)> {
+        let tolerance: T = self.settings.tolerance();
+        self.guesses
+            .iter()
+            .try_fold((self.settings.min(), self.settings.max()), |(low, high), guess| {
+                let (low, high): (T, T) = match guess.outcome {
+                    GuessOutcome::TooSmall => match guess.value.above(tolerance)? {
+                        above if above > low => (above, high),
+                        _ => (low, high),
+                    },
+                    GuessOutcome::TooBig => match guess.value.below(tolerance)? {
+                        below if below < high => (low, below),
+                        _ => (low, high),
+                    },
+                    GuessOutcome::Correct => (guess.value, guess.value),
+                    _ => (low, high),
+                };
+                (low <= high).then_some((low, high))
+            })
+    }
+
+    /// Record the end of the game.
+    fn finish(&mut self, status: GameStatus) {
+        self.status = status;
+        self.finished = Some(Instant::now());
+    }
+
+    /// The settings the game was started with.
+    pub fn settings(&self) -> &Settings<T> {
+        &self.settings
+    }
+
+    /// The secret number the player is trying to guess.
+    pub fn secret(&self) -> T {
+        self.secret_num
+    }
+
+    /// The number of valid guesses made so far.
+    pub fn attempts(&self) -> u32 {
+        self.guesses.len() as u32
+    }
+
+    /// Every valid guess made so far, oldest first.
+    pub fn guesses(&self) -> &[Guess<T>] {
+        &self.guesses
+    }
+
+    /// The last valid guess, if any.
+    pub fn last_guess(&self) -> Option<T> {
+        self.guesses.last().map(|guess| guess.value)
+    }
+
+    /// The number of attempts left, or `None` if they are unlimited.
+    pub fn attempts_left(&self) -> Option<u32> {
+        self.settings
+            .max_attempts()
+            .map(|max_attempts| max_attempts.saturating_sub(self.attempts()))
+    }
+
+    /// Where the game stands.
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    /// Whether the secret number has been found.
+    pub fn is_won(&self) -> bool {
+        self.status == GameStatus::Won
+    }
+
+    /// Whether the game is won or lost.
+    pub fn is_over(&self) -> bool {
+        self.status != GameStatus::InProgress
+    }
+
+    /// The time spent playing, up to the end of the game.
+    pub fn elapsed(&self) -> Duration {
+        self.finished.unwrap_or_else(Instant::now) - self.started
+    }
+
+    /// The score of the game: zero unless it was won.
+    pub fn score(&self) -> u32 {
+        match self.status {
+            GameStatus::Won => {
+                let bound: u32 = self.settings.attempt_bound();
+                score::score_for_bound(self.attempts(), bound, self.elapsed(), self.hints.len() as u32)
+            }
+            GameStatus::InProgress | GameStatus::Lost => 0,
+        }
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new(Settings::default())
+    }
+}
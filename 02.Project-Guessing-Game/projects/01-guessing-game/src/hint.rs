@@ -0,0 +1,163 @@
+/**********************************************************************/
+/* Hint: Clues about the secret number, worth at most one guess each. */
+/**********************************************************************/
+
+// Import libraries/modules
+use crate::messages;
+use std::error::Error;
+use std::fmt;
+
+/// The divisors that divisibility hints are about, as in `04-control-flow`.
+const DIVISORS: [u32; 2] = [3, 5];
+
+/// Up to this many candidates, hints are checked against every candidate.
+/// Above it, the candidates are counted with formulas, and primes are
+/// estimated with the prime number theorem.
+const ENUMERATION_LIMIT: u64 = 1_000_000;
+
+/// A true statement about the secret number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    /// The number is even.
+    Even,
+    /// The number is odd.
+    Odd,
+    /// The number is divisible by the divisor.
+    DivisibleBy(u32),
+    /// The number is not divisible by the divisor.
+    NotDivisibleBy(u32),
+    /// The number is prime.
+    Prime,
+    /// The number is not prime.
+    NotPrime,
+    /// The number is at most `distance` away from `guess`.
+    Near { guess: u32, distance: u32 },
+    /// The number is more than `distance` away from `guess`.
+    Far { guess: u32, distance: u32 },
+}
+
+impl Hint {
+    /// Whether a number satisfies the hint.
+    pub fn holds_for(self, number: u32) -> bool {
+        match self {
+            Hint::Even => number.is_multiple_of(2),
+            Hint::Odd => !number.is_multiple_of(2),
+            Hint::DivisibleBy(divisor) => number.is_multiple_of(divisor),
+            Hint::NotDivisibleBy(divisor) => !number.is_multiple_of(divisor),
+            Hint::Prime => is_prime(number),
+            Hint::NotPrime => !is_prime(number),
+            Hint::Near { guess, distance } => number.abs_diff(guess) <= distance,
+            Hint::Far { guess, distance } => number.abs_diff(guess) > distance,
+        }
+    }
+
+    /// The family of the hint: two hints of the same family are never both given.
+    fn family(self) -> u32 {
+        match self {
+            Hint::Even | Hint::Odd => 2,
+            Hint::DivisibleBy(divisor) | Hint::NotDivisibleBy(divisor) => divisor,
+            Hint::Prime | Hint::NotPrime => 1,
+            Hint::Near { .. } | Hint::Far { .. } => 0,
+        }
+    }
+
+    /// The number of candidates in `low..=high` that satisfy the hint, by formula.
+    fn count(self, low: u32, high: u32) -> u64 {
+        // The number of multiples of `divisor` in `low..=high`
+        let multiples = |divisor: u32| -> u64 {
+            let (low, high, divisor): (u64, u64, u64) = (u64::from(low), u64::from(high), u64::from(divisor));
+            high / divisor - low.div_ceil(divisor) + 1
+        };
+        let size: u64 = u64::from(high - low) + 1;
+
+        match self {
+            Hint::Even => multiples(2),
+            Hint::Odd => size - multiples(2),
+            Hint::DivisibleBy(divisor) => multiples(divisor),
+            Hint::NotDivisibleBy(divisor) => size - multiples(divisor),
+            Hint::Prime | Hint::NotPrime => {
+                let primes: u64 = ((size as f64 / f64::from(high).ln()) as u64).clamp(1, size - 1);
+                if self == Hint::Prime { primes } else { size - primes }
+            }
+            Hint::Near { guess, distance } => {
+                let near_low: u32 = guess.saturating_sub(distance).max(low);
+                let near_high: u32 = guess.saturating_add(distance).min(high);
+                if near_low > near_high { 0 } else { u64::from(near_high - near_low) + 1 }
+            }
+            Hint::Far { guess, distance } => size - Hint::Near { guess, distance }.count(low, high),
+        }
+    }
+}
+
+/// The reasons why no hint can be given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintError {
+    /// Hints are turned off.
+    Disabled,
+    /// Hints are only offered after this many misses.
+    TooEarly { misses_needed: u32 },
+    /// Every hint left would give away too much.
+    NoneLeft,
+    /// The game is already over.
+    GameOver,
+}
+
+impl fmt::Display for HintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::hint_error(*self))
+    }
+}
+
+impl Error for HintError {}
+
+/// Pick the next hint about `secret`, among the candidates `low..=high`.
+///
+/// The candidates are the numbers of `low..=high` that satisfy every hint
+/// already `given`. A new hint is only given if it leaves at least half of
+/// them: no hint is worth more than one more guess, so no hint ever reveals
+/// more than the remaining search space justifies. Hints of a family already
+/// given are skipped.
+pub fn next_hint(secret: u32, low: u32, high: u32, last_guess: Option<u32>, given: &[Hint]) -> Option<Hint> {
+    let size: u64 = u64::from(high - low) + 1;
+
+    // Every true statement, in order of preference
+    let mut facts: Vec<Hint> = vec![if secret.is_multiple_of(2) { Hint::Even } else { Hint::Odd }];
+    for divisor in DIVISORS {
+        facts.push(if secret.is_multiple_of(divisor) {
+            Hint::DivisibleBy(divisor)
+        } else {
+            Hint::NotDivisibleBy(divisor)
+        });
+    }
+    facts.push(if is_prime(secret) { Hint::Prime } else { Hint::NotPrime });
+    if let Some(guess) = last_guess {
+        let distance: u32 = (size / 10).clamp(1, u64::from(u32::MAX)) as u32;
+        let near: Hint = Hint::Near { guess, distance };
+        facts.push(if near.holds_for(secret) { near } else { Hint::Far { guess, distance } });
+    }
+
+    // Count the candidates before and after each hint
+    let counts = |hint: Hint| -> (u64, u64) {
+        if size > ENUMERATION_LIMIT {
+            return (size, hint.count(low, high));
+        }
+        (low..=high)
+            .filter(|number| given.iter().all(|given| given.holds_for(*number)))
+            .fold((0, 0), |(before, after), number| (before + 1, after + u64::from(hint.holds_for(number))))
+    };
+
+    facts.into_iter().find(|hint| {
+        let new_family: bool = given.iter().all(|given| given.family() != hint.family());
+        let (before, after): (u64, u64) = counts(*hint);
+        new_family && after < before && after * 2 >= before
+    })
+}
+
+/// Whether a number is prime, by trial division.
+pub fn is_prime(number: u32) -> bool {
+    if number < 2 {
+        return false;
+    }
+    let number: u64 = u64::from(number);
+    (2..).take_while(|divisor| divisor * divisor <= number).all(|divisor| !number.is_multiple_of(divisor))
+}
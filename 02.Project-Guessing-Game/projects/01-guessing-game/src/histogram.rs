@@ -0,0 +1,40 @@
+/****************************************************************/
+/* Histogram: ASCII bar charts of how many attempts games took. */
+/****************************************************************/
+
+// Import libraries/modules
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// The width of the longest bar of a histogram.
+const BAR_WIDTH: usize = 40;
+
+/// The most rows a histogram has before attempts are grouped into buckets.
+const MAX_ROWS: u32 = 20;
+
+/// Render the number of games per number of attempts as horizontal bars.
+pub fn render(histogram: &BTreeMap<u32, usize>) -> String {
+    let mut text: String = String::new();
+
+    // Group the attempts into buckets of equal width when there are too many
+    let best: u32 = histogram.keys().next().copied().unwrap_or(0);
+    let worst: u32 = histogram.keys().next_back().copied().unwrap_or(0);
+    let width: u32 = (worst - best + 1).div_ceil(MAX_ROWS).max(1);
+    let mut buckets: BTreeMap<u32, usize> = BTreeMap::new();
+    for (attempts, count) in histogram {
+        *buckets.entry(best + (attempts - best) / width * width).or_default() += count;
+    }
+
+    // Scale the bars to the biggest bucket
+    let most: usize = buckets.values().copied().max().unwrap_or(0).max(1);
+    for (start, count) in buckets {
+        let label: String = match width {
+            1 => start.to_string(),
+            _ => format!("{start}-{}", start + width - 1),
+        };
+        let bar: String = "#".repeat((count * BAR_WIDTH).div_ceil(most));
+        let _ = writeln!(text, "{label:>11} | {bar} {count}");
+    }
+
+    text
+}
@@ -0,0 +1,238 @@
+/************************************************************/
+/* History: Every finished game, persisted in a local file. */
+/************************************************************/
+
+// Import libraries/modules
+use crate::date;
+use crate::game::Game;
+use crate::settings::Settings;
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The environment variable that overrides the data directory.
+pub const DATA_DIR_VAR: &str = "GUESSING_GAME_DATA_DIR";
+
+/// The name of the history file inside the data directory.
+const HISTORY_FILE: &str = "history.tsv";
+
+/// The first field of every record, to tell formats apart after upgrades.
+const FORMAT_VERSION: &str = "v1";
+
+/// One finished game, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    /// The name of the player.
+    pub player: String,
+    /// The smallest possible secret number.
+    pub min: u32,
+    /// The largest possible secret number.
+    pub max: u32,
+    /// The number of attempts used.
+    pub attempts: u32,
+    /// Whether the secret number was found.
+    pub won: bool,
+    /// The score of the game.
+    pub score: u32,
+    /// The time spent playing.
+    pub duration: Duration,
+    /// When the game ended, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl GameRecord {
+    /// Record a finished game, played by `player`, ending now.
+    pub fn from_game(player: &str, game: &Game) -> Self {
+        Self {
+            player: player.to_string(),
+            min: game.settings().min(),
+            max: game.settings().max(),
+            attempts: game.attempts(),
+            won: game.is_won(),
+            score: game.score(),
+            duration: game.elapsed(),
+            timestamp: date::unix_timestamp(),
+        }
+    }
+
+    /// The name of the difficulty of the game, or `custom` for other ranges.
+    pub fn category(&self) -> String {
+        Settings::new(self.min, self.max)
+            .ok()
+            .and_then(|settings| settings.difficulty())
+            .map_or_else(|| "custom".to_string(), |difficulty| difficulty.to_string())
+    }
+
+    /// Encode the record as one tab-separated line, without the line ending.
+    pub fn to_line(&self) -> String {
+        // Tabs and line breaks in the name would break the format
+        let player: String = self
+            .player
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect();
+
+        format!(
+            "{FORMAT_VERSION}\t{player}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.min,
+            self.max,
+            self.attempts,
+            u8::from(self.won),
+            self.score,
+            self.duration.as_millis(),
+            self.timestamp
+        )
+    }
+
+    /// Decode a line written by [`GameRecord::to_line`], or `None` if it is corrupted.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.trim_end_matches(['\r', '\n']).split('\t').collect();
+        let [version, player, min, max, attempts, won, score, duration, timestamp] = fields[..] else {
+            return None;
+        };
+        if version != FORMAT_VERSION || player.is_empty() {
+            return None;
+        }
+
+        let record: GameRecord = GameRecord {
+            player: player.to_string(),
+            min: min.parse().ok()?,
+            max: max.parse().ok()?,
+            attempts: attempts.parse().ok()?,
+            won: match won {
+                "0" => false,
+                "1" => true,
+                _ => return None,
+            },
+            score: score.parse().ok()?,
+            duration: Duration::from_millis(duration.parse().ok()?),
+            timestamp: timestamp.parse().ok()?,
+        };
+        (record.min < record.max).then_some(record)
+    }
+}
+
+/// The records read from a history file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Loaded {
+    /// Every record that could be decoded, oldest first.
+    pub records: Vec<GameRecord>,
+    /// The number of lines that were skipped because they are corrupted.
+    pub corrupted: usize,
+}
+
+/// The file that finished games are appended to.
+///
+/// Writers take an exclusive lock on the file and readers a shared one, so
+/// several games can finish at the same time without mixing their lines.
+/// Corrupted lines, e.g. from a crash halfway through a write, are skipped
+/// when reading instead of making the whole file unreadable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    /// Use the history file at the given path.
+    pub fn at<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Use the history file inside the user's data directory.
+    pub fn open_default() -> io::Result<Self> {
+        Ok(Self::at(data_dir()?.join(HISTORY_FILE)))
+    }
+
+    /// The path of the history file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a record to the file, creating it if needed.
+    pub fn append(&self, record: &GameRecord) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file: File = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&self.path)?;
+        file.lock()?;
+
+        // Terminate a line left unfinished by a crash, so that it does not swallow ours
+        let mut line: String = String::new();
+        if file.metadata()?.len() > 0 {
+            let mut last: [u8; 1] = [0];
+            file.seek(SeekFrom::End(-1))?;
+            file.read_exact(&mut last)?;
+            if last[0] != b'\n' {
+                line.push('\n');
+            }
+        }
+        line.push_str(&record.to_line());
+        line.push('\n');
+
+        // Write the whole line at once
+        file.write_all(line.as_bytes())?;
+        file.flush()
+    }
+
+    /// Read every record of the file; a missing file holds no records.
+    pub fn load(&self) -> io::Result<Loaded> {
+        let file: File = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Loaded::default()),
+            Err(err) => return Err(err),
+        };
+        file.lock_shared()?;
+
+        let mut loaded: Loaded = Loaded::default();
+        let mut reader: BufReader<File> = BufReader::new(file);
+        let mut bytes: Vec<u8> = Vec::new();
+        while reader.read_until(b'\n', &mut bytes)? > 0 {
+            // Invalid UTF-8 is corruption too
+            let decoded: Option<GameRecord> = std::str::from_utf8(&bytes).ok().and_then(GameRecord::from_line);
+            match decoded {
+                Some(record) => loaded.records.push(record),
+                None if bytes.iter().all(u8::is_ascii_whitespace) => {}
+                None => loaded.corrupted += 1,
+            }
+            bytes.clear();
+        }
+
+        Ok(loaded)
+    }
+}
+
+/// The directory where the game keeps its files.
+///
+/// In order of preference: `$GUESSING_GAME_DATA_DIR`, the platform's data
+/// directory (`$XDG_DATA_HOME`, `~/.local/share`, `~/Library/Application Support`,
+/// or `%APPDATA%`), with a `guessing-game` folder inside.
+pub fn data_dir() -> io::Result<PathBuf> {
+    if let Some(dir) = env::var_os(DATA_DIR_VAR).filter(|dir| !dir.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+
+    let base: Option<PathBuf> = if cfg!(windows) {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        env::var_os("XDG_DATA_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+    };
+
+    base.map(|base| base.join("guessing-game")).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("cannot find a data directory, set {DATA_DIR_VAR}"),
+        )
+    })
+}
@@ -0,0 +1,145 @@
+/***************************************************************/
+/* Input: Lines typed by the player, end of input, and Ctrl-C. */
+/***************************************************************/
+
+// Import libraries/modules
+use std::io;
+use std::io::BufRead;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often a blocked read checks whether Ctrl-C was pressed.
+const INTERRUPT_POLL: Duration = Duration::from_millis(50);
+
+/// One event read from the player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A line of text, including its line ending.
+    Line(String),
+    /// The input was closed, e.g. with Ctrl-D or at the end of a pipe.
+    Eof,
+    /// The player pressed Ctrl-C.
+    Interrupted,
+}
+
+/// A source of player input that a session can be driven by.
+pub trait InputSource {
+    /// Block until the next input event.
+    fn read_input(&mut self) -> io::Result<Input>;
+
+    /// Block until the next input event, or until `timeout` has passed, in
+    /// which case `None` is returned.
+    ///
+    /// Sources that cannot stop waiting, like a plain [`BufRead`], simply
+    /// block: a game with a time limit still ends on their next line.
+    fn read_input_timeout(&mut self, timeout: Duration) -> io::Result<Option<Input>> {
+        let _ = timeout;
+        self.read_input().map(Some)
+    }
+}
+
+/// Reads input from any [`BufRead`], such as a file, a pipe, or a socket.
+///
+/// There is no Ctrl-C to listen to, so it only ever yields lines and EOF.
+#[derive(Debug)]
+pub struct LineInput<R> {
+    reader: R,
+}
+
+impl<R: BufRead> LineInput<R> {
+    /// Wrap a reader.
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+}
+
+impl<R: BufRead> InputSource for LineInput<R> {
+    fn read_input(&mut self) -> io::Result<Input> {
+        let mut line: String = String::new();
+
+        // Reading 0 bytes means the input was closed
+        match self.reader.read_line(&mut line)? {
+            0 => Ok(Input::Eof),
+            _ => Ok(Input::Line(line)),
+        }
+    }
+}
+
+/// Reads input from the terminal, and turns Ctrl-C into [`Input::Interrupted`].
+///
+/// Stdin is read on a background thread so that a pending `read_line` never
+/// prevents the session from noticing Ctrl-C.
+#[derive(Debug)]
+pub struct TerminalInput {
+    lines: Receiver<io::Result<String>>,
+    interrupted: Arc<AtomicBool>,
+}
+
+impl TerminalInput {
+    /// Start reading stdin and catching Ctrl-C.
+    pub fn new() -> io::Result<Self> {
+        // Ctrl-C only raises a flag instead of killing the process, and so do
+        // a closing terminal and `kill`, so that the game can still be saved
+        let interrupted: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        signal_hook::flag::register(signal_hook::consts::SIGINT, Arc::clone(&interrupted))?;
+        signal_hook::flag::register(signal_hook::consts::SIGTERM, Arc::clone(&interrupted))?;
+        #[cfg(unix)]
+        signal_hook::flag::register(signal_hook::consts::SIGHUP, Arc::clone(&interrupted))?;
+
+        // Forward every line of stdin, and stop after EOF or an error
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            let stdin = io::stdin();
+            loop {
+                let mut line: String = String::new();
+                let result: io::Result<usize> = stdin.read_line(&mut line);
+                let done: bool = !matches!(result, Ok(n) if n > 0);
+                if sender.send(result.map(|_| line)).is_err() || done {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self { lines, interrupted })
+    }
+}
+
+impl TerminalInput {
+    /// Wait for the next input event, up to `deadline` if there is one.
+    fn read_until(&mut self, deadline: Option<Instant>) -> io::Result<Option<Input>> {
+        loop {
+            if self.interrupted.swap(false, Ordering::SeqCst) {
+                return Ok(Some(Input::Interrupted));
+            }
+
+            // Wake up at the deadline, if it comes before the next poll
+            let poll: Duration = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(left) if !left.is_zero() => left.min(INTERRUPT_POLL),
+                    _ => return Ok(None),
+                },
+                None => INTERRUPT_POLL,
+            };
+            match self.lines.recv_timeout(poll) {
+                Ok(Ok(line)) if line.is_empty() => return Ok(Some(Input::Eof)),
+                Ok(Ok(line)) => return Ok(Some(Input::Line(line))),
+                Ok(Err(err)) => return Err(err),
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return Ok(Some(Input::Eof)),
+            }
+        }
+    }
+}
+
+impl InputSource for TerminalInput {
+    fn read_input(&mut self) -> io::Result<Input> {
+        self.read_until(None).map(|input| input.expect("reading without a deadline never times out"))
+    }
+
+    fn read_input_timeout(&mut self, timeout: Duration) -> io::Result<Option<Input>> {
+        self.read_until(Some(Instant::now() + timeout))
+    }
+}
@@ -0,0 +1,81 @@
+/************************************************************/
+/* Leaderboard: The best games of every difficulty, ranked. */
+/************************************************************/
+
+// Import libraries/modules
+use crate::date::Date;
+use crate::history::GameRecord;
+use crate::messages::{self, Message};
+use crate::settings::Difficulty;
+use std::cmp::Reverse;
+use std::fmt::Write;
+
+/// The best won games of one difficulty, best first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board<'a> {
+    /// The difficulty name, or `custom` for other ranges.
+    pub category: String,
+    /// The top games, best first.
+    pub entries: Vec<&'a GameRecord>,
+}
+
+/// Rank the won games of every difficulty and keep the `top` best of each.
+///
+/// Games are ranked by score, then by fewest attempts, then by fastest time.
+/// Boards follow the order of the difficulties, with `custom` last.
+pub fn leaderboard(records: &[GameRecord], top: usize) -> Vec<Board<'_>> {
+    let mut categories: Vec<String> = Difficulty::ALL.iter().map(Difficulty::to_string).collect();
+    categories.push("custom".to_string());
+
+    categories
+        .into_iter()
+        .filter_map(|category| {
+            let mut entries: Vec<&GameRecord> = records
+                .iter()
+                .filter(|record| record.won && record.category() == category)
+                .collect();
+            entries.sort_by_key(|record| (Reverse(record.score), record.attempts, record.duration));
+            entries.truncate(top);
+            (!entries.is_empty()).then_some(Board { category, entries })
+        })
+        .collect()
+}
+
+/// Render the boards as plain-text tables.
+pub fn render(boards: &[Board<'_>]) -> String {
+    let mut text: String = String::new();
+    if boards.is_empty() {
+        let _ = writeln!(text, "{}", messages::text(Message::NoWins));
+    }
+
+    for board in boards {
+        let _ = writeln!(text, "== {} ==", board.category);
+        let _ = writeln!(
+            text,
+            "{:>4}  {:<16} {:>7} {:>8} {:>9} {:>15}  {}",
+            messages::text(Message::ColumnRank),
+            messages::text(Message::ColumnPlayer),
+            messages::text(Message::ColumnScore),
+            messages::text(Message::ColumnAttempts),
+            messages::text(Message::ColumnTime),
+            messages::text(Message::ColumnRange),
+            messages::text(Message::ColumnDate)
+        );
+        for (rank, record) in board.entries.iter().enumerate() {
+            let _ = writeln!(
+                text,
+                "{:>4}  {:<16} {:>7} {:>8} {:>9.1} {:>15}  {}",
+                rank + 1,
+                record.player,
+                record.score,
+                record.attempts,
+                record.duration.as_secs_f64(),
+                format!("{}-{}", record.min, record.max),
+                Date::from_timestamp(record.timestamp)
+            );
+        }
+        text.push('\n');
+    }
+
+    text
+}
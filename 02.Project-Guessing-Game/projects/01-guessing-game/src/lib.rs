@@ -0,0 +1,52 @@
+/******************************************************************/
+/* Guessing Game Library: The rules of the game, free of any I/O. */
+/******************************************************************/
+
+//! The engine behind the guessing game.
+//!
+//! The binary in `main.rs` is only a thin stdin/stdout front-end: every rule
+//! of the game lives here so that it can be embedded, driven by other
+//! front-ends, and unit-tested.
+
+// Declare modules
+pub mod batch;
+pub mod bench;
+pub mod bulls;
+pub mod cli;
+pub mod daily;
+pub mod date;
+pub mod domain;
+pub mod expr;
+pub mod game;
+pub mod hint;
+pub mod histogram;
+pub mod history;
+pub mod input;
+pub mod json;
+pub mod leaderboard;
+pub mod lies;
+pub mod messages;
+pub mod multiplayer;
+pub mod net;
+pub mod proximity;
+pub mod score;
+pub mod reverse;
+pub mod save;
+pub mod session;
+pub mod settings;
+pub mod stats;
+pub mod strategy;
+pub mod transcript;
+pub mod tui;
+pub mod words;
+
+// Re-export the main types at the crate root
+pub use game::{seeded_rng, Game, GameStatus, Guess, GuessOutcome, SeededRng};
+pub use hint::Hint;
+pub use history::{GameRecord, History};
+pub use input::{Input, InputSource, LineInput, TerminalInput};
+pub use multiplayer::HotSeat;
+pub use reverse::ReverseGame;
+pub use session::SessionEnd;
+pub use settings::{Difficulty, Feedback, Settings, SettingsError};
+pub use strategy::{Strategy, StrategyKind};
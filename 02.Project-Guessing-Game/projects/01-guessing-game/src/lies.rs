@@ -0,0 +1,152 @@
+/***********************************************************************/
+/* Lies: Ulam's game, where up to K "Too small!/Too big!" may be lies. */
+/***********************************************************************/
+
+// Import libraries/modules
+use crate::game::{Game, GameStatus, GuessOutcome};
+
+/// The most lies a game may tell, which keeps the attempt bound within reach.
+pub const MAX_LIES: u32 = 5;
+
+/// How much more a candidate weighs than one that needs one more lie.
+///
+/// A candidate that needs no lie yet can survive `K` more lies, so it is
+/// worth more than one that already needs some of them.
+const LIE_WEIGHT: u128 = 4;
+
+/// The fewest questions that can find one of `range_size` numbers when up
+/// to `lies` answers may be lies, plus the final guess of the number.
+///
+/// Each answer at most halves the "volume" of the possible situations, and
+/// every number can be paired with any choice of up to `lies` lied answers
+/// among `q` questions, so `2^q ≥ range_size × Σ C(q, i)` for `i ≤ lies`.
+/// This is Berlekamp's volume bound.
+///
+/// The volume saturates at `u128::MAX`, so for the widest 128-bit ranges
+/// the bound stops at 128 questions, as many as without lies.
+pub fn attempt_bound(range_size: u128, lies: u32) -> u32 {
+    let volume = |questions: u32| -> u128 {
+        let mut choices: u128 = 1;
+        let mut total: u128 = 1;
+        for i in 1..=u128::from(lies.min(questions)) {
+            choices = choices * (u128::from(questions) + 1 - i) / i;
+            total += choices;
+        }
+        range_size.saturating_mul(total)
+    };
+    let questions: u32 = (0..)
+        .find(|&questions| questions >= u128::BITS || (1u128 << questions) >= volume(questions))
+        .unwrap_or(0);
+    questions + 1
+}
+
+/// A run of candidates that need as many lies to be the secret number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    low: u32,
+    high: u32,
+    lies: u32,
+}
+
+impl Segment {
+    fn len(&self) -> u64 {
+        u64::from(self.high - self.low) + 1
+    }
+}
+
+/// Finds the secret number although up to `lies` answers may be lies.
+///
+/// Every candidate is kept with the number of answers it contradicts, and
+/// dropped once it contradicts more than `lies` of them. Each guess splits
+/// the candidates in two halves of equal weight, where a candidate weighs
+/// more when it needs fewer lies. "Correct!" is never a lie, so the number
+/// is found by guessing the last candidate standing.
+#[derive(Debug, Clone)]
+pub struct LieSolver {
+    lies: u32,
+    /// The candidates in increasing order, with the lies they need.
+    segments: Vec<Segment>,
+}
+
+impl LieSolver {
+    /// Start with every number of `min..=max` as a candidate.
+    pub fn new(min: u32, max: u32, lies: u32) -> Self {
+        Self {
+            lies,
+            segments: vec![Segment {
+                low: min,
+                high: max,
+                lies: 0,
+            }],
+        }
+    }
+
+    /// The number of candidates left.
+    pub fn candidates(&self) -> u64 {
+        self.segments.iter().map(Segment::len).sum()
+    }
+
+    /// Pick the next guess: the weighted median of the candidates.
+    pub fn next_guess(&self) -> u32 {
+        let weight = |segment: &Segment| -> u128 { LIE_WEIGHT.pow(self.lies - segment.lies) };
+        let total: u128 = self.segments.iter().map(|segment| u128::from(segment.len()) * weight(segment)).sum();
+
+        // Walk to the segment where half of the weight is reached
+        let mut below: u128 = 0;
+        for segment in &self.segments {
+            let size: u128 = u128::from(segment.len()) * weight(segment);
+            if 2 * (below + size) >= total {
+                let offset: u128 = (total / 2).saturating_sub(below) / weight(segment);
+                return segment.low + offset.min(u128::from(segment.high - segment.low)) as u32;
+            }
+            below += size;
+        }
+        self.segments.last().map_or(0, |segment| segment.high)
+    }
+
+    /// Count the answer against every candidate that it contradicts.
+    pub fn record(&mut self, guess: u32, outcome: GuessOutcome) {
+        let mut segments: Vec<Segment> = Vec::with_capacity(self.segments.len() + 2);
+        for segment in &self.segments {
+            // Split the segment around the guess, which is never the number
+            let parts: [(u32, u32, bool); 2] = [
+                (segment.low, segment.high.min(guess.saturating_sub(1)), guess > 0),
+                (segment.low.max(guess.saturating_add(1)), segment.high, guess < u32::MAX),
+            ];
+            for (index, (low, high, exists)) in parts.into_iter().enumerate() {
+                if !exists || low > high {
+                    continue;
+                }
+                let below: bool = index == 0;
+                let contradicted: bool = match outcome {
+                    GuessOutcome::TooSmall => below,
+                    GuessOutcome::TooBig => !below,
+                    _ => false,
+                };
+                let lies: u32 = segment.lies + u32::from(contradicted);
+                if lies <= self.lies {
+                    segments.push(Segment { low, high, lies });
+                }
+            }
+        }
+        self.segments = segments;
+    }
+}
+
+/// Let the lie-tolerant solver play `game` until it is over, reporting every step.
+pub fn solve<F>(game: &mut Game, mut on_step: F) -> GameStatus
+where
+    F: FnMut(u32, GuessOutcome),
+{
+    let settings = *game.settings();
+    let mut solver: LieSolver = LieSolver::new(settings.min(), settings.max(), settings.lies());
+    while !game.is_over() && solver.candidates() > 0 {
+        // Feed the guess through the same path as typed input
+        let guess: u32 = solver.next_guess();
+        let outcome: GuessOutcome = game.submit(&guess.to_string());
+        on_step(guess, outcome);
+        solver.record(guess, outcome);
+    }
+
+    game.status()
+}
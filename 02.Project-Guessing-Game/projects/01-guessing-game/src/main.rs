@@ -3,62 +3,524 @@
 /************************************************************/
 
 // Import libraries/modules
-use rand::RngExt;
-use std::cmp::Ordering;
+use guessing_game::batch;
+use guessing_game::bench;
+use guessing_game::bulls::{self, Code, CodeGame, Marks};
+use guessing_game::cli::{self, Command, Options};
+use guessing_game::daily::{self, DailyLog, DailyRecorder, DailyResult};
+use guessing_game::date::Date;
+use guessing_game::domain::{self, Number, NumberType};
+use guessing_game::history::Loaded;
+use guessing_game::leaderboard;
+use guessing_game::lies;
+use guessing_game::messages::{self, Language, Message};
+use guessing_game::net;
+use guessing_game::save::{Autosave, SaveFile};
+use guessing_game::stats;
+use guessing_game::session::{self, Observer, SessionEnd};
+use guessing_game::strategy::{self, Strategy, StrategyKind};
+use guessing_game::words::{self, WordGame, WordList};
+use guessing_game::transcript::{self, ReplayReport, TranscriptWriter};
+use guessing_game::tui;
+use guessing_game::{
+    seeded_rng, Game, GameRecord, GameStatus, GuessOutcome, History, HotSeat, ReverseGame, Settings,
+    TerminalInput,
+};
+use std::env;
+use std::fs;
+use std::fs::File;
 use std::io;
-use std::io::Stdin;
+use std::io::IsTerminal;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::process::ExitCode;
+
+/// The exit code used when the terminal or the data files cannot be used.
+const IO_ERROR: u8 = 74;
 
 /// The entry-point of the program.
-fn main() {
-    // Generate a random integer between 1 and 100, inclusive
-    let secret_num: u32 = rand::rng().random_range(1..=100);
-
-    // Create a reader
-    let reader: Stdin = io::stdin();
-
-    loop {
-        // Prompt the player to enter a guess
-        println!("Take a guess, what number between 1 and 100?");
-
-        // Process the user input
-        let mut guess: String = String::new();
-
-        // Check that the input is in the expected form
-        reader.read_line(&mut guess)
-              .expect("Failed to read line");
-
-        // Set explicit cast to u32
-        // Handle user input errors
-        // let guess: u32 = guess.trim().parse().expect("Please type a number!");
-        let guess: u32 = match guess.trim().parse() {
-            Ok(num) => num,
-            Err(_) => {
-                println!("That was not a valid number!");
-                continue;
-            }
-        };
-
-        // Confirm user's guess
-        println!("You guessed: {guess}");
-
-        // Handle when the number is too big or too small
-        if guess < 1 || guess > 100 {
-            println!("Your guess is outside of the accepted range.");
-            continue;
+fn main() -> ExitCode {
+    // Speak the language asked for, or the one of the locale, even to reject the command line
+    let args: Vec<String> = env::args().skip(1).collect();
+    messages::set_language(cli::language_of(&args).or_else(Language::from_env).unwrap_or_default());
+
+    // Read the options from the command line
+    let options: Options = match cli::parse_args(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}", messages::format(Message::Error, &[("error", &err)]));
+            eprintln!("{}", cli::usage());
+            return ExitCode::from(2);
         }
+    };
+    if options.help {
+        println!("{}", cli::usage());
+        return ExitCode::SUCCESS;
+    }
+
+    // Run the requested command
+    let result: io::Result<ExitCode> = match options.command {
+        Command::Play | Command::Load => play(&options),
+        Command::Daily => play_daily(&options),
+        Command::Scores => show_scores(&options),
+        Command::Stats => show_stats(&options),
+        Command::Bulls => play_bulls(&options),
+        Command::Words => play_words(&options),
+        Command::Reverse => play_reverse(&options),
+        Command::Bench => run_bench(&options),
+        Command::Serve => net::serve(options.addr.as_str(), options.settings, options.seed).map(|_| ExitCode::SUCCESS),
+        Command::Connect => net::connect(options.addr.as_str()).map(|_| ExitCode::SUCCESS),
+        Command::Replay => run_replay(options.file.as_deref().expect("replay always has a file")),
+    };
+
+    result.unwrap_or_else(|err| {
+        eprintln!("{}", messages::format(Message::Error, &[("error", &err)]));
+        ExitCode::from(IO_ERROR)
+    })
+}
+
+/// Play a game on the terminal, and save it to the history once it is over.
+fn play(options: &Options) -> io::Result<ExitCode> {
+    if let Some(number_type) = options.number_type {
+        return play_typed(options, number_type);
+    }
+    let settings: Settings = options.settings;
+
+    // A recorded game needs a seed to be replayed
+    let seed: Option<u64> = options.seed.or_else(|| options.record.as_ref().map(|_| rand::random()));
+
+    // Generate a random integer within the configured range, inclusive,
+    // unless a saved game goes on with its own settings
+    let mut game: Game = match seed {
+        _ if options.resume => load_saved_game()?,
+        Some(seed) => Game::from_rng(settings, &mut seeded_rng(seed)),
+        None => Game::new(settings),
+    };
+
+    if options.batch {
+        let end: SessionEnd = batch::play_batch(&mut game, io::stdin().lock(), &mut io::stdout().lock())?;
+        return Ok(end.exit_code());
+    }
+    if options.auto {
+        return Ok(play_auto(options, &mut game));
+    }
+    if let Some(players) = &options.players {
+        return play_hot_seat(game, players.clone());
+    }
+
+    if options.resume {
+        print_resumed(&game);
+    }
+
+    // Keep the game saved while it is played, if there is somewhere to save it
+    let save: Option<SaveFile> = SaveFile::open_default().ok();
+    if !options.resume && save.as_ref().is_some_and(SaveFile::exists) {
+        println!("{}", messages::text(Message::ReplaceSave));
+    }
+    let mut autosave: Option<Autosave> = save.map(Autosave::new);
+
+    // Play until the game is over or the player leaves, full-screen only on a terminal
+    let mut input: TerminalInput = TerminalInput::new()?;
+    let full_screen: bool = options.tui && io::stdout().is_terminal();
+    let end: SessionEnd = match (&options.record, seed) {
+        (Some(path), Some(seed)) => {
+            let recorder = TranscriptWriter::new(BufWriter::new(File::create(path)?), seed, &settings)?;
+            play_session(&mut game, &mut input, full_screen, &mut (recorder, &mut autosave))?
+        }
+        _ => play_session(&mut game, &mut input, full_screen, &mut autosave)?,
+    };
+    if let Some(err) = autosave.as_ref().and_then(Autosave::error) {
+        eprintln!("{}", messages::format(Message::AutosaveFailed, &[("error", &err)]));
+    }
+
+    // A lost save is not worth failing the game for
+    if game.is_over() {
+        let record: GameRecord = GameRecord::from_game(&player_name(options), &game);
+        if let Err(err) = History::open_default().and_then(|history| history.append(&record)) {
+            eprintln!("{}", messages::format(Message::HistoryFailed, &[("error", &err)]));
+        }
+    }
+
+    Ok(end.exit_code())
+}
+
+/// Play the number of the day, scored only the first time it is played.
+fn play_daily(options: &Options) -> io::Result<ExitCode> {
+    // The day is fixed at the start, even if the game goes past midnight
+    let today: Date = Date::today();
+    let mut game: Game = daily::game(today);
+    let log: DailyLog = DailyLog::open_default()?;
+    let played: Option<DailyResult> = log.find(today)?;
 
-        // Indicate whether the guess is too low, too high, or correct
-        // Compare guess vs secret_num
-        match guess.cmp(&secret_num) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
-            Ordering::Equal => {
-                // Print a congratulatory message and exit
-                println!("You win!!!");
-                break;
-            }
+    println!("{}", messages::format(Message::DailyIntro, &[("date", &today)]));
+    if let Some(played) = &played {
+        println!("{}", messages::format(Message::DailyPlayed, &[("date", &today)]));
+        println!("{}", played.share());
+        println!("{}", messages::text(Message::DailyReplay));
+    }
+
+    // Only the first game of the day is recorded, and a daily game is never saved for '--resume'
+    let mut input: TerminalInput = TerminalInput::new()?;
+    let full_screen: bool = options.tui && io::stdout().is_terminal();
+    let mut recorder: Option<DailyRecorder> = played.is_none().then(|| DailyRecorder::new(log, today));
+    let end: SessionEnd = play_session(&mut game, &mut input, full_screen, &mut recorder)?;
+    let Some(recorder) = recorder else {
+        return Ok(end.exit_code());
+    };
+    if let Some(err) = recorder.error() {
+        eprintln!("{}", messages::format(Message::DailyFailed, &[("error", &err)]));
+    }
+
+    // Score the game like any other, and give the result to share
+    if game.is_over() {
+        let record: GameRecord = GameRecord::from_game(&player_name(options), &game);
+        if let Err(err) = History::open_default().and_then(|history| history.append(&record)) {
+            eprintln!("{}", messages::format(Message::HistoryFailed, &[("error", &err)]));
         }
     }
+    println!();
+    println!("{}", messages::text(Message::DailyShare));
+    println!("{}", DailyResult::from_game(today, &game).share());
+
+    Ok(end.exit_code())
+}
+
+/// Play a game on the terminal, full-screen or line by line.
+fn play_session<O>(
+    game: &mut Game,
+    input: &mut TerminalInput,
+    full_screen: bool,
+    observer: &mut O,
+) -> io::Result<SessionEnd>
+where
+    O: Observer,
+{
+    match full_screen {
+        true => tui::play_tui(game, input, &mut io::stdout(), observer),
+        false => session::play_observed(game, input, &mut io::stdout(), observer),
+    }
+}
+
+/// Load the saved game, which must exist.
+fn load_saved_game() -> io::Result<Game> {
+    match SaveFile::open_default()?.load() {
+        Ok(Some(game)) => Ok(game),
+        Ok(None) => Err(io::Error::new(io::ErrorKind::NotFound, "there is no saved game to resume")),
+        Err(err) => Err(io::Error::other(err)),
+    }
+}
+
+/// Remind the player of where a resumed game stands.
+fn print_resumed(game: &Game) {
+    let guesses: Vec<String> = game
+        .guesses()
+        .iter()
+        .map(|guess| {
+            let outcome: String = match guess.outcome {
+                GuessOutcome::TooSmall => messages::text(Message::WasTooSmall).to_string(),
+                GuessOutcome::TooBig => messages::text(Message::WasTooBig).to_string(),
+                outcome => outcome.name().replace('_', " "),
+            };
+            format!("{} ({outcome})", guess.value)
+        })
+        .collect();
+    match guesses.as_slice() {
+        [] => println!("{}", messages::text(Message::WelcomeBack)),
+        _ => println!("{}", messages::format(Message::WelcomeGuesses, &[("guesses", &guesses.join(", "))])),
+    }
+    for hint in game.hints() {
+        println!("{}", messages::format(Message::Hint, &[("hint", &messages::hint(*hint))]));
+    }
+}
+
+/// Let several players take turns on the terminal.
+fn play_hot_seat(game: Game, players: Vec<String>) -> io::Result<ExitCode> {
+    let mut hot_seat: HotSeat = HotSeat::new(game, players).map_err(io::Error::other)?;
+
+    let mut input: TerminalInput = TerminalInput::new()?;
+    let end: SessionEnd = session::play_hot_seat(&mut hot_seat, &mut input, &mut io::stdout())?;
+
+    Ok(end.exit_code())
+}
+
+/// Let the computer play the game, printing each step.
+fn play_auto(options: &Options, game: &mut Game) -> ExitCode {
+    let print_step = |guess: u32, outcome: GuessOutcome| {
+        println!("{} {guess:>7} -> {}", messages::text(Message::AutoGuess), messages::outcome(outcome));
+    };
+
+    // Only the lie-tolerant solver survives a game that lies
+    let (min, max): (u32, u32) = (game.settings().min(), game.settings().max());
+    let status: GameStatus = if game.settings().lies() > 0 {
+        let lies: u64 = u64::from(game.settings().lies());
+        println!("{}", messages::plural(Message::AutoLies, lies, &[("min", &min), ("max", &max)]));
+        lies::solve(game, print_step)
+    } else {
+        let mut player: Box<dyn Strategy> =
+            options.strategy.unwrap_or_default().build(options.seed.unwrap_or_else(rand::random));
+        let strategy: &str = player.name();
+        println!(
+            "{}",
+            messages::format(Message::AutoStrategy, &[("strategy", &strategy), ("min", &min), ("max", &max)])
+        );
+        strategy::solve(game, player.as_mut(), print_step)
+    };
+
+    println!();
+    println!("{}", messages::format(Message::SecretNumber, &[("secret", &game.secret())]));
+    println!("{}", messages::plural(Message::ComputerAttempts, u64::from(game.attempts()), &[]));
+    if game.settings().lies() > 0 {
+        println!("{}", messages::plural(Message::GameLied, u64::from(game.lies_told()), &[]));
+    }
+    match status {
+        GameStatus::Won => SessionEnd::Won.exit_code(),
+        _ => SessionEnd::Lost.exit_code(),
+    }
+}
+
+/// Play a game over another type than `u32`, by picking the engine of that type.
+fn play_typed(options: &Options, number_type: NumberType) -> io::Result<ExitCode> {
+    match number_type {
+        NumberType::I8 => play_domain::<i8>(options),
+        NumberType::I16 => play_domain::<i16>(options),
+        NumberType::I32 => play_domain::<i32>(options),
+        NumberType::I64 => play_domain::<i64>(options),
+        NumberType::I128 => play_domain::<i128>(options),
+        NumberType::U8 => play_domain::<u8>(options),
+        NumberType::U16 => play_domain::<u16>(options),
+        NumberType::U32 => play_domain::<u32>(options),
+        NumberType::U64 => play_domain::<u64>(options),
+        NumberType::U128 => play_domain::<u128>(options),
+        NumberType::F32 => play_domain::<f32>(options),
+        NumberType::F64 => play_domain::<f64>(options),
+    }
+}
+
+/// Play a game over the number type `T` on the terminal, or let the
+/// bisection play with `--auto`.
+fn play_domain<T: Number>(options: &Options) -> io::Result<ExitCode> {
+    let settings: Settings<T> = match build_settings(options) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{}", messages::format(Message::Error, &[("error", &err)]));
+            return Ok(ExitCode::from(2));
+        }
+    };
+
+    // The number is drawn like the classic one, so seeds work the same way
+    let mut game: Game<T> = match options.seed {
+        Some(seed) => Game::from_rng(settings, &mut seeded_rng(seed)),
+        None => Game::from_rng(settings, &mut rand::rng()),
+    };
+
+    if options.auto {
+        let (min, max): (T, T) = (settings.min(), settings.max());
+        println!("{}", messages::format(Message::AutoBisect, &[("type", &T::NAME), ("min", &min), ("max", &max)]));
+        let status: GameStatus = domain::solve(&mut game, |guess: T, outcome: GuessOutcome| {
+            println!("{} {guess:>12} -> {}", messages::text(Message::AutoGuess), messages::outcome(outcome));
+        });
+        println!();
+        println!("{}", messages::format(Message::SecretNumber, &[("secret", &game.secret())]));
+        let attempts: u64 = u64::from(game.attempts());
+        println!("{}", messages::plural(Message::ComputerBound, attempts, &[("bound", &settings.attempt_bound())]));
+        return Ok(match status {
+            GameStatus::Won => SessionEnd::Won.exit_code(),
+            _ => SessionEnd::Lost.exit_code(),
+        });
+    }
+
+    let mut input: TerminalInput = TerminalInput::new()?;
+    let end: SessionEnd = session::play_numbers(&mut game, &mut input, &mut io::stdout())?;
+
+    Ok(end.exit_code())
+}
+
+/// Parse the settings of a game over `T` from the options.
+///
+/// The bounds default to the difficulty, which must then fit the type.
+fn build_settings<T: Number>(options: &Options) -> Result<Settings<T>, String> {
+    let parse = |value: Option<&String>, default: String| domain::parse_number::<T>(value.unwrap_or(&default));
+    let min: T = parse(options.min.as_ref(), options.settings.min().to_string()).map_err(|err| err.to_string())?;
+    let max: T = parse(options.max.as_ref(), options.settings.max().to_string()).map_err(|err| err.to_string())?;
+    let tolerance: T =
+        parse(options.tolerance.as_ref(), T::DEFAULT_TOLERANCE.to_string()).map_err(|err| err.to_string())?;
+
+    // The rest of the settings apply in the order of the command line
+    let settings: Settings<T> = Settings::new_typed(min, max, tolerance)
+        .map_err(|err| err.to_string())?
+        .with_lies(options.settings.lies())
+        .with_time_limit(options.settings.time_limit());
+    match options.attempts {
+        Some(attempts) => settings.with_max_attempts(attempts).map_err(|err| err.to_string()),
+        None => Ok(settings),
+    }
+}
+
+/// Play bulls and cows on the terminal, or let the solver play with `--auto`.
+fn play_bulls(options: &Options) -> io::Result<ExitCode> {
+    // The code is drawn like the secret number, so seeds work the same way
+    let max_attempts: Option<u32> = options.attempts.unwrap_or(Some(bulls::attempt_bound(options.digits)));
+    let mut game: CodeGame = match options.seed {
+        Some(seed) => CodeGame::from_rng(options.digits, max_attempts, &mut seeded_rng(seed)),
+        None => CodeGame::from_rng(options.digits, max_attempts, &mut rand::rng()),
+    };
+
+    if options.auto {
+        println!("{}", messages::format(Message::AutoCode, &[("digits", &game.length())]));
+        let status: GameStatus = bulls::solve(&mut game, |guess: &Code, marks: Marks| {
+            println!("{} {guess:>7} -> {}", messages::text(Message::AutoGuess), messages::marks(marks));
+        });
+        println!();
+        println!("{}", messages::format(Message::SecretCode, &[("secret", &game.secret())]));
+        println!("{}", messages::plural(Message::ComputerAttempts, u64::from(game.attempts()), &[]));
+        return Ok(match status {
+            GameStatus::Won => SessionEnd::Won.exit_code(),
+            _ => SessionEnd::Lost.exit_code(),
+        });
+    }
+
+    let mut input: TerminalInput = TerminalInput::new()?;
+    let end: SessionEnd = session::play_bulls(&mut game, &mut input, &mut io::stdout())?;
+
+    Ok(end.exit_code())
+}
+
+/// Play the word mode on the terminal, in colour when it is a terminal.
+fn play_words(options: &Options) -> io::Result<ExitCode> {
+    let list: WordList = match &options.word_list {
+        Some(path) => WordList::from_text(&fs::read_to_string(path)?, options.letters),
+        None => WordList::from_text(words::EMBEDDED_WORDS, options.letters),
+    }
+    .map_err(io::Error::other)?;
+
+    // The word is drawn like the secret number, so seeds work the same way
+    let max_attempts: Option<u32> = options.attempts.unwrap_or(Some(words::DEFAULT_ATTEMPTS));
+    let mut game: WordGame = match options.seed {
+        Some(seed) => WordGame::from_rng(list, max_attempts, &mut seeded_rng(seed)),
+        None => WordGame::from_rng(list, max_attempts, &mut rand::rng()),
+    };
+
+    // Colours are only for terminals, and never when NO_COLOR is set
+    let colour: bool = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty());
+
+    let mut input: TerminalInput = TerminalInput::new()?;
+    let end: SessionEnd = session::play_words(&mut game, &mut input, &mut io::stdout(), colour)?;
+
+    Ok(end.exit_code())
+}
+
+/// Let the computer guess a number the player is thinking of.
+fn play_reverse(options: &Options) -> io::Result<ExitCode> {
+    let seed: u64 = options.seed.unwrap_or_else(rand::random);
+    let player: Box<dyn Strategy> = options.strategy.unwrap_or_default().build(seed);
+    let mut game: ReverseGame = ReverseGame::with_strategy(options.settings, player);
+
+    let mut input: TerminalInput = TerminalInput::new()?;
+    let end: SessionEnd = session::play_reverse(&mut game, &mut input, &mut io::stdout())?;
+
+    Ok(end.exit_code())
+}
+
+/// Compare the strategies over many seeded games.
+fn run_bench(options: &Options) -> io::Result<ExitCode> {
+    // Without a seed, pick one and print it so that the run can be repeated
+    let seed: u64 = options.seed.unwrap_or_else(rand::random);
+    let kinds: Vec<StrategyKind> = match options.strategy {
+        Some(kind) => vec![kind],
+        None => StrategyKind::ALL.to_vec(),
+    };
+
+    let (min, max): (u32, u32) = (options.settings.min(), options.settings.max());
+    println!("{}", messages::format(Message::BenchIntro, &[("min", &min), ("max", &max), ("seed", &seed)]));
+    println!();
+    for kind in kinds {
+        let report = bench::bench(kind, options.settings, options.games, seed);
+        println!("{}", bench::render(&report));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Replay a transcript and report every outcome that changed.
+fn run_replay(path: &Path) -> io::Result<ExitCode> {
+    let file: File = File::open(path)?;
+    let report: ReplayReport = match transcript::replay(BufReader::new(file)) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("{}", messages::format(Message::FileError, &[("path", &path.display()), ("error", &err)]));
+            return Ok(ExitCode::from(IO_ERROR));
+        }
+    };
+
+    for mismatch in &report.mismatches {
+        let input: String = format!("{:?}", mismatch.input);
+        println!(
+            "{}",
+            messages::format(
+                Message::ReplayMismatch,
+                &[
+                    ("line", &mismatch.line),
+                    ("input", &input),
+                    ("expected", &mismatch.expected),
+                    ("actual", &mismatch.actual),
+                ],
+            )
+        );
+    }
+    if !report.complete {
+        println!("{}", messages::text(Message::ReplayIncomplete));
+    }
+    let lines: u64 = report.lines as u64;
+    if report.is_ok() {
+        println!("{}", messages::plural(Message::ReplayOk, lines, &[]));
+        Ok(ExitCode::SUCCESS)
+    } else {
+        let mismatches: u64 = report.mismatches.len() as u64;
+        println!("{}", messages::plural(Message::ReplayFailed, mismatches, &[("lines", &lines)]));
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+/// Print the best games of every difficulty.
+fn show_scores(options: &Options) -> io::Result<ExitCode> {
+    let history: History = History::open_default()?;
+    let loaded: Loaded = history.load()?;
+    warn_corrupted(&history, &loaded);
+
+    print!("{}", leaderboard::render(&leaderboard::leaderboard(&loaded.records, options.top)));
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Print the lifetime statistics of the player, computed from the history.
+fn show_stats(options: &Options) -> io::Result<ExitCode> {
+    let history: History = History::open_default()?;
+    let loaded: Loaded = history.load()?;
+    warn_corrupted(&history, &loaded);
+
+    let player: String = player_name(options);
+    let records = loaded.records.iter().filter(|record| record.player == player);
+    let title: String = messages::format(Message::StatsTitle, &[("player", &player)]);
+    print!("{}", stats::render(&title, &stats::stats(records)));
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Warn about the lines of the history that could not be read.
+fn warn_corrupted(history: &History, loaded: &Loaded) {
+    if loaded.corrupted > 0 {
+        let path = history.path().display();
+        eprintln!("{}", messages::plural(Message::SkippedCorrupted, loaded.corrupted as u64, &[("path", &path)]));
+    }
+}
+
+/// The name of the player: `--name`, or the name of the user.
+fn player_name(options: &Options) -> String {
+    options
+        .name
+        .clone()
+        .or_else(|| env::var("USER").ok())
+        .or_else(|| env::var("USERNAME").ok())
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "player".to_string())
 }
 
 // Check:               $ cargo check
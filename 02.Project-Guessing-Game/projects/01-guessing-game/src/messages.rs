@@ -0,0 +1,1660 @@
+/*********************************************************************/
+/* Messages: What the player reads, in English, French, or Malagasy. */
+/*********************************************************************/
+
+//! Every line shown while playing is looked up in a catalog of the
+//! language picked with `--lang` or the locale (`LC_ALL`, `LC_MESSAGES`,
+//! `LANG`), with `{name}` placeholders filled in by the caller.
+//!
+//! A catalog is a plain table of keys and texts, so that a translation can
+//! be added without touching the game. Messages about a count have one form
+//! per plural category of the language, separated by `|`:
+//!
+//! ```text
+//! ("attempts_left", "You have {count} attempt left.|You have {count} attempts left."),
+//! ```
+//!
+//! Commands (`hint`, `save`, `quit`, and the answers `h`, `l`, `c`) are the
+//! same in every language, so that a game can be played from any locale.
+
+// Import libraries/modules
+use crate::bulls::{CodeError, Marks};
+use crate::cli::CliError;
+use crate::domain::DomainError;
+use crate::expr::{ExprError, ExprErrorKind};
+use crate::game::GuessOutcome;
+use crate::hint::{Hint, HintError};
+use crate::json::JsonError;
+use crate::proximity::{Heat, Trend};
+use crate::reverse::{Answer, Contradiction};
+use crate::save::SaveError;
+use crate::settings::SettingsError;
+use crate::transcript::ReplayError;
+use crate::words::WordError;
+use std::cmp::Ordering;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write as _;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// The language of the messages, set once at start-up.
+static LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+/// A language with a catalog of every message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    /// English, the language of the source code.
+    #[default]
+    English,
+    /// French.
+    French,
+    /// Malagasy.
+    Malagasy,
+}
+
+impl Language {
+    /// Every language with a catalog.
+    pub const ALL: [Language; 3] = [Language::English, Language::French, Language::Malagasy];
+
+    /// The ISO 639-1 code of the language, as typed on the command line.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+            Language::Malagasy => "mg",
+        }
+    }
+
+    /// The language of a locale such as `fr_FR.UTF-8`, if it has a catalog.
+    pub fn from_locale(locale: &str) -> Option<Language> {
+        let code: &str = locale.trim().split(['_', '-', '.', '@']).next().unwrap_or_default();
+        Language::ALL.into_iter().find(|language| language.code().eq_ignore_ascii_case(code))
+    }
+
+    /// The language of the environment, looked up like `gettext` does:
+    /// `LC_ALL` first, then `LC_MESSAGES`, then `LANG`.
+    pub fn from_env() -> Option<Language> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|locale| !locale.is_empty())
+            .and_then(|locale| Language::from_locale(&locale))
+    }
+
+    /// The catalog of the language: every key of [`Message::ALL`], with its text.
+    pub fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => ENGLISH,
+            Language::French => FRENCH,
+            Language::Malagasy => MALAGASY,
+        }
+    }
+
+    /// The number of plural forms of the language, i.e. of texts in a
+    /// message about a count.
+    pub fn plural_forms(self) -> usize {
+        match self {
+            Language::English | Language::French => 2,
+            // Malagasy nouns do not change in the plural
+            Language::Malagasy => 1,
+        }
+    }
+
+    /// The plural form to use for a count, as an index into the forms of a message.
+    pub fn plural_form(self, count: u64) -> usize {
+        match self {
+            Language::English => usize::from(count != 1),
+            // French uses the singular for zero too
+            Language::French => usize::from(count > 1),
+            Language::Malagasy => 0,
+        }
+    }
+
+    /// The raw text of a message, with its placeholders and plural forms.
+    ///
+    /// A key missing from the catalog falls back to English, then to the key
+    /// itself, so that a gap in a translation never hides a message.
+    pub fn text(self, message: Message) -> &'static str {
+        let key: &str = message.key();
+        let find = |catalog: &'static [(&'static str, &'static str)]| {
+            catalog.iter().find(|(entry, _)| *entry == key).map(|(_, text)| *text)
+        };
+        find(self.catalog()).or_else(|| find(ENGLISH)).unwrap_or(key)
+    }
+
+    /// A message with its placeholders filled in.
+    pub fn format(self, message: Message, args: &[(&str, &dyn fmt::Display)]) -> String {
+        fill(self.text(message), args)
+    }
+
+    /// A message about `count`, in the plural form for that count, with
+    /// `{count}` and the other placeholders filled in.
+    pub fn plural(self, message: Message, count: u64, args: &[(&str, &dyn fmt::Display)]) -> String {
+        let forms: Vec<&str> = self.text(message).split('|').collect();
+        let form: &str = forms[self.plural_form(count).min(forms.len() - 1)];
+        let mut args: Vec<(&str, &dyn fmt::Display)> = args.to_vec();
+        args.push(("count", &count));
+        fill(form, &args)
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Language {
+    type Err = UnknownLanguage;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::from_locale(s).ok_or_else(|| UnknownLanguage(s.to_string()))
+    }
+}
+
+/// A language without a catalog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownLanguage(pub String);
+
+impl fmt::Display for UnknownLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format(Message::UnknownLanguage, &[("language", &self.0)]))
+    }
+}
+
+impl Error for UnknownLanguage {}
+
+/// Declare the messages, with the key that every catalog files them under.
+macro_rules! messages {
+    ($($(#[$doc:meta])* $variant:ident => $key:literal,)*) => {
+        /// A message shown to the player.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Message {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl Message {
+            /// Every message, in the order of the catalogs.
+            pub const ALL: &'static [Message] = &[$(Message::$variant,)*];
+
+            /// The key of the message in the catalogs.
+            pub fn key(self) -> &'static str {
+                match self {
+                    $(Message::$variant => $key,)*
+                }
+            }
+        }
+    };
+}
+
+messages! {
+    /// The warning of a game that may lie.
+    Lies => "lies",
+    /// The prompt of a guess.
+    Prompt => "prompt",
+    /// The time left, after the prompt of a timed game.
+    TimeLeft => "time_left",
+    /// The clock ran out.
+    TimeUp => "time_up",
+    /// A hint given on request.
+    Hint => "hint",
+    /// The game was saved with `save`.
+    Saved => "saved",
+    /// Nothing can save the game.
+    CannotSave => "cannot_save",
+    /// Saving the game failed.
+    SaveFailed => "save_failed",
+    /// The guess as understood.
+    Guessed => "guessed",
+    /// The guess is not a number.
+    InvalidNumber => "invalid_number",
+    /// The guess is outside of the range.
+    OutOfRange => "out_of_range",
+    /// The guess is too small.
+    TooSmall => "too_small",
+    /// The guess is too big.
+    TooBig => "too_big",
+    /// The player found the secret.
+    Win => "win",
+    /// The player used every attempt.
+    Lose => "lose",
+    /// The attempts left, a count.
+    AttemptsLeft => "attempts_left",
+    /// The nudge towards `hint`.
+    Stuck => "stuck",
+    /// The secret number, revealed to a player who did not find it.
+    Goodbye => "goodbye",
+    /// The attempts and time of a game, a count.
+    Summary => "summary",
+    /// The score of a game.
+    Score => "score",
+    /// A game that could lie told the truth.
+    NeverLied => "never_lied",
+    /// The lies told, a count.
+    Lied => "lied",
+    /// The time taken by every guess.
+    SplitTimes => "split_times",
+    /// The time taken by one guess.
+    Split => "split",
+    /// The prompt of bulls and cows.
+    PromptCode => "prompt_code",
+    /// The guess is not a code.
+    InvalidCode => "invalid_code",
+    /// The bulls of a code, a count.
+    Bulls => "bulls",
+    /// The cows of a code, a count.
+    Cows => "cows",
+    /// The secret code, revealed to a player who did not find it.
+    GoodbyeCode => "goodbye_code",
+    /// The legend of the plain-text word marks.
+    WordLegend => "word_legend",
+    /// The prompt of the word mode.
+    PromptWord => "prompt_word",
+    /// The guess is not a word.
+    InvalidWord => "invalid_word",
+    /// The secret word, revealed to a player who did not find it.
+    GoodbyeWord => "goodbye_word",
+    /// The prompt of a game over another type than `u32`.
+    PromptTyped => "prompt_typed",
+    /// How close a guess must be, after the prompt.
+    Within => "within",
+    /// The guess is not a number of the type.
+    InvalidTyped => "invalid_typed",
+    /// The exact secret, after a win within the tolerance.
+    ExactSecret => "exact_secret",
+    /// The start of a reverse game.
+    ReverseIntro => "reverse_intro",
+    /// The question about a guess of the computer.
+    ReverseAsk => "reverse_ask",
+    /// The answer was not understood.
+    ReverseAnswer => "reverse_answer",
+    /// The computer found the number.
+    ReverseFound => "reverse_found",
+    /// The answers contradict each other.
+    Cheater => "cheater",
+    /// The candidates left.
+    ReverseBetween => "reverse_between",
+    /// The guesses of the computer, a count.
+    ReverseSummary => "reverse_summary",
+    /// The prompt of a player of a hot-seat game.
+    PromptPlayer => "prompt_player",
+    /// The guess of a player, as understood.
+    PlayerGuessed => "player_guessed",
+    /// A player found the secret.
+    PlayerWins => "player_wins",
+    /// The attempts left to a player, a count.
+    PlayerAttemptsLeft => "player_attempts_left",
+    /// `hint` or `save` was typed in a hot-seat game.
+    HotSeatCommand => "hot_seat_command",
+    /// The winner of a hot-seat game.
+    Winner => "winner",
+    /// Nobody found the secret.
+    NobodyWon => "nobody_won",
+    /// The title of the turns of the scoreboard.
+    Turns => "turns",
+    /// The title of the players of the scoreboard.
+    Players => "players",
+    /// The winning turn of the scoreboard.
+    Correct => "correct",
+    /// The mark of the winner on the scoreboard.
+    WinnerTag => "winner_tag",
+    /// The attempts of a player on the scoreboard, a count.
+    PlayerAttempts => "player_attempts",
+    /// [`Hint::Even`].
+    HintEven => "hint_even",
+    /// [`Hint::Odd`].
+    HintOdd => "hint_odd",
+    /// [`Hint::DivisibleBy`].
+    HintDivisible => "hint_divisible",
+    /// [`Hint::NotDivisibleBy`].
+    HintNotDivisible => "hint_not_divisible",
+    /// [`Hint::Prime`].
+    HintPrime => "hint_prime",
+    /// [`Hint::NotPrime`].
+    HintNotPrime => "hint_not_prime",
+    /// [`Hint::Near`].
+    HintNear => "hint_near",
+    /// [`Hint::Far`].
+    HintFar => "hint_far",
+    /// [`HintError::Disabled`].
+    HintsDisabled => "hints_disabled",
+    /// [`HintError::TooEarly`], a count.
+    HintsTooEarly => "hints_too_early",
+    /// [`HintError::NoneLeft`].
+    NoHintsLeft => "no_hints_left",
+    /// [`HintError::GameOver`].
+    HintGameOver => "hint_game_over",
+    /// [`Heat::Freezing`].
+    Freezing => "freezing",
+    /// [`Heat::Cold`].
+    Cold => "cold",
+    /// [`Heat::Warm`].
+    Warm => "warm",
+    /// [`Heat::Hot`].
+    Hot => "hot",
+    /// [`Heat::Burning`].
+    Burning => "burning",
+    /// [`Trend::Warmer`].
+    Warmer => "warmer",
+    /// [`Trend::Colder`].
+    Colder => "colder",
+    /// [`Trend::Same`].
+    Same => "same",
+    /// The title of the full-screen mode.
+    Title => "title",
+    /// The attempt counter, with a limit.
+    AttemptOf => "attempt_of",
+    /// The attempt counter, without a limit.
+    Attempt => "attempt",
+    /// The score if the next guess wins.
+    WinNow => "win_now",
+    /// The time left, in the header of the full-screen mode.
+    SecondsLeft => "seconds_left",
+    /// The first message of the full-screen mode.
+    FindSecret => "find_secret",
+    /// The guess is not a number, in the full-screen mode.
+    NotANumber => "not_a_number",
+    /// The game was saved, in the full-screen mode.
+    SavedTo => "saved_to",
+    /// The candidates are secret in hot/cold mode.
+    CandidatesHidden => "candidates_hidden",
+    /// The candidates are secret when the game may lie.
+    CandidatesLies => "candidates_lies",
+    /// The candidates left, a count.
+    Candidates => "candidates",
+    /// The history is empty.
+    NoGuess => "no_guess",
+    /// The commands of the full-screen mode.
+    Commands => "commands",
+    /// A resumed game without guesses.
+    WelcomeBack => "welcome_back",
+    /// A resumed game, with its guesses.
+    WelcomeGuesses => "welcome_guesses",
+    /// A resumed guess that was too small.
+    WasTooSmall => "was_too_small",
+    /// A resumed guess that was too big.
+    WasTooBig => "was_too_big",
+    /// A new game is about to replace the saved one.
+    ReplaceSave => "replace_save",
+    /// The start of a computer game that may lie, a count.
+    AutoLies => "auto_lies",
+    /// The start of a computer game.
+    AutoStrategy => "auto_strategy",
+    /// The start of a computer game over another type than `u32`.
+    AutoBisect => "auto_bisect",
+    /// The start of a computer game of bulls and cows.
+    AutoCode => "auto_code",
+    /// The label of a guess of the computer.
+    AutoGuess => "auto_guess",
+    /// The secret number, after a computer game.
+    SecretNumber => "secret_number",
+    /// The secret code, after a computer game.
+    SecretCode => "secret_code",
+    /// The attempts of the computer, a count.
+    ComputerAttempts => "computer_attempts",
+    /// The attempts of the computer and their bound, a count.
+    ComputerBound => "computer_bound",
+    /// The lies told to the computer, a count.
+    GameLied => "game_lied",
+    /// The start of the daily challenge.
+    DailyIntro => "daily_intro",
+    /// The day was already played.
+    DailyPlayed => "daily_played",
+    /// A replay of the day is not scored.
+    DailyReplay => "daily_replay",
+    /// The result to share.
+    DailyShare => "daily_share",
+    /// An expression error, after the column of the offending character.
+    ExprError => "expr_error",
+    /// An expression holds something else where a number belongs.
+    ExprExpectedNumber => "expr_expected_number",
+    /// An expression ends where a number belongs.
+    ExprMissingNumber => "expr_missing_number",
+    /// An expression holds something else where an operator belongs.
+    ExprExpectedOperator => "expr_expected_operator",
+    /// A parenthesis of an expression is never closed.
+    ExprUnclosedParen => "expr_unclosed_paren",
+    /// An expression names something other than `last`.
+    ExprUnknownName => "expr_unknown_name",
+    /// An expression uses `last` before the first guess.
+    ExprNoLastGuess => "expr_no_last_guess",
+    /// An expression divides by zero.
+    ExprDivisionByZero => "expr_division_by_zero",
+    /// An expression overflows.
+    ExprOverflow => "expr_overflow",
+    /// An expression is nested too deeply.
+    ExprTooDeep => "expr_too_deep",
+    /// A guessed code is not as long as the secret.
+    CodeLength => "code_length",
+    /// A guessed code holds something other than a digit.
+    CodeNotADigit => "code_not_a_digit",
+    /// A guessed code holds the same digit twice.
+    CodeRepeated => "code_repeated",
+    /// The word list has no word of the length asked for.
+    WordListEmpty => "word_list_empty",
+    /// A guessed word is not as long as the secret.
+    WordLength => "word_length",
+    /// A guessed word is not in the word list.
+    WordUnknown => "word_unknown",
+    /// The player answered that their number is higher.
+    AnswerHigher => "answer_higher",
+    /// The player answered that their number is lower.
+    AnswerLower => "answer_lower",
+    /// The player answered that the guess is their number.
+    AnswerEqual => "answer_equal",
+    /// An answer contradicts an earlier one.
+    ContradictsAnswer => "contradicts_answer",
+    /// An answer contradicts the range.
+    ContradictsRange => "contradicts_range",
+    /// The title of the statistics of a player.
+    StatsTitle => "stats_title",
+    /// The history holds no game of the player.
+    NoGames => "no_games",
+    /// The games played, in the statistics.
+    GamesPlayed => "games_played",
+    /// The games won, in the statistics.
+    Wins => "wins",
+    /// The mean attempts, in the statistics.
+    MeanAttempts => "mean_attempts",
+    /// The best game, in the statistics.
+    BestGame => "best_game",
+    /// The worst game, in the statistics.
+    WorstGame => "worst_game",
+    /// The current streak, in the statistics.
+    CurrentStreak => "current_streak",
+    /// The longest streaks, in the statistics.
+    LongestStreaks => "longest_streaks",
+    /// Games won in a row, a count.
+    WinCount => "win_count",
+    /// Games lost in a row, a count.
+    LossCount => "loss_count",
+    /// The title of the histogram of the statistics.
+    AttemptsToWin => "attempts_to_win",
+    /// A won game, on one line.
+    GameRecord => "game_record",
+    /// A lost game, on one line.
+    GameRecordLost => "game_record_lost",
+    /// The attempts of a game, a count.
+    AttemptCount => "attempt_count",
+    /// The history holds no won game.
+    NoWins => "no_wins",
+    /// The rank column of the leaderboard.
+    ColumnRank => "column_rank",
+    /// The player column of the leaderboard.
+    ColumnPlayer => "column_player",
+    /// The score column of the leaderboard.
+    ColumnScore => "column_score",
+    /// The attempts column of the leaderboard.
+    ColumnAttempts => "column_attempts",
+    /// The time column of the leaderboard.
+    ColumnTime => "column_time",
+    /// The range column of the leaderboard.
+    ColumnRange => "column_range",
+    /// The date column of the leaderboard.
+    ColumnDate => "column_date",
+    /// The start of a benchmark.
+    BenchIntro => "bench_intro",
+    /// The title of the report of a strategy, a count of games.
+    BenchTitle => "bench_title",
+    /// The mean attempts of a strategy.
+    BenchMean => "bench_mean",
+    /// The worst attempts of a strategy.
+    BenchWorst => "bench_worst",
+    /// A recorded outcome that replays differently.
+    ReplayMismatch => "replay_mismatch",
+    /// The transcript stops before the end of its session.
+    ReplayIncomplete => "replay_incomplete",
+    /// Every outcome replays the same, a count of lines.
+    ReplayOk => "replay_ok",
+    /// Some outcomes replay differently, a count of mismatches.
+    ReplayFailed => "replay_failed",
+    /// Lines of the history that could not be read, a count.
+    SkippedCorrupted => "skipped_corrupted",
+    /// An option that the game does not know about.
+    CliUnknownOption => "cli_unknown_option",
+    /// A command that the game does not know about.
+    CliUnknownCommand => "cli_unknown_command",
+    /// An argument that no command or option expects.
+    CliUnexpectedArgument => "cli_unexpected_argument",
+    /// A command was given without its file.
+    CliMissingArgument => "cli_missing_argument",
+    /// An option that needs a value was given none.
+    CliMissingValue => "cli_missing_value",
+    /// An option value that could not be parsed.
+    CliInvalidValue => "cli_invalid_value",
+    /// Two options that cannot be used together.
+    CliConflict => "cli_conflict",
+    /// An option that only makes sense along with another one.
+    CliRequires => "cli_requires",
+    /// A range whose minimum is bigger than its maximum.
+    InvertedRange => "inverted_range",
+    /// A range with a single number.
+    SingleNumberRange => "single_number_range",
+    /// A difficulty that does not exist.
+    UnknownDifficulty => "unknown_difficulty",
+    /// A game without any attempt.
+    NoAttempts => "no_attempts",
+    /// A strategy that does not exist.
+    UnknownStrategy => "unknown_strategy",
+    /// A hot-seat game with too few or too many players.
+    PlayerCount => "player_count",
+    /// A player name that is empty or used twice.
+    InvalidPlayer => "invalid_player",
+    /// A number type that does not exist.
+    UnknownType => "unknown_type",
+    /// A bound or tolerance that is not a number of its type.
+    NotOfType => "not_of_type",
+    /// A range of a number type that cannot be played.
+    InvalidRange => "invalid_range",
+    /// A tolerance that is negative, or zero for a float.
+    InvalidTolerance => "invalid_tolerance",
+    /// A language without a catalog.
+    UnknownLanguage => "unknown_language",
+    /// A text that is not valid JSON.
+    InvalidJson => "invalid_json",
+    /// The save could not be read.
+    SaveUnreadable => "save_unreadable",
+    /// The save is not a game that can be resumed.
+    SaveCorrupted => "save_corrupted",
+    /// The transcript could not be read.
+    TranscriptUnreadable => "transcript_unreadable",
+    /// A line of the transcript that cannot be replayed.
+    TranscriptLine => "transcript_line",
+    /// An error that stops the program.
+    Error => "error",
+    /// An error about a file that stops the program.
+    FileError => "file_error",
+    /// The game in progress could not be saved on exit.
+    AutosaveFailed => "autosave_failed",
+    /// A finished game could not be added to the history.
+    HistoryFailed => "history_failed",
+    /// The daily challenge could not be recorded.
+    DailyFailed => "daily_failed",
+    /// The address that the server listens on.
+    Listening => "listening",
+    /// The address of the server connected to.
+    Connected => "connected",
+    /// The server could not accept a connection.
+    AcceptFailed => "accept_failed",
+    /// How the game of a connection ended, a count of attempts.
+    PeerEnded => "peer_ended",
+    /// The game of a connection failed.
+    PeerFailed => "peer_failed",
+    /// A connection whose address is unknown.
+    UnknownPeer => "unknown_peer",
+    /// The help text of `--help`.
+    Usage => "usage",
+}
+
+/// The English catalog, which every other one is checked against.
+const ENGLISH: &[(&str, &str)] = &[
+    ("lies", "Beware: up to {lies} of my answers may be lies!"),
+    ("prompt", "Take a guess, what number between {min} and {max}?"),
+    ("time_left", "({seconds}s left)"),
+    ("time_up", "Time's up!"),
+    ("hint", "Hint: {hint}"),
+    ("saved", "Game saved to {path}. Continue it later with '--resume'."),
+    ("cannot_save", "This game cannot be saved."),
+    ("save_failed", "Could not save the game: {error}"),
+    ("guessed", "You guessed: {guess}"),
+    ("invalid_number", "That was not a valid number!"),
+    ("out_of_range", "Your guess is outside of the accepted range."),
+    ("too_small", "Too small!"),
+    ("too_big", "Too big!"),
+    ("win", "You win!!!"),
+    ("lose", "You lose! No attempts left."),
+    ("attempts_left", "You have {count} attempt left.|You have {count} attempts left."),
+    ("stuck", "Stuck? Type 'hint' for a clue, at the cost of some points."),
+    ("goodbye", "Goodbye! The secret number was {secret}."),
+    (
+        "summary",
+        "You made {count} attempt in {seconds} seconds.|You made {count} attempts in {seconds} seconds.",
+    ),
+    ("score", "Score: {score}"),
+    ("never_lied", "I never lied."),
+    ("lied", "I lied once, about: {guesses}.|I lied {count} times, about: {guesses}."),
+    ("split_times", "Time per guess: {splits}."),
+    ("split", "{guess} in {seconds}s"),
+    ("prompt_code", "Take a guess, what code of {digits} distinct digits?"),
+    ("invalid_code", "That was not a valid code! {error}"),
+    ("bulls", "{count} bull|{count} bulls"),
+    ("cows", "{count} cow|{count} cows"),
+    ("goodbye_code", "Goodbye! The secret code was {secret}."),
+    ("word_legend", "[A]: right place, (B): wrong place, C: not in the word."),
+    ("prompt_word", "Take a guess, what word of {letters} letters?"),
+    ("invalid_word", "That was not a valid word! {error}"),
+    ("goodbye_word", "Goodbye! The secret word was {secret}."),
+    ("prompt_typed", "Take a guess, what {type} between {min} and {max}?"),
+    ("within", "(within {tolerance})"),
+    ("invalid_typed", "That was not a valid {type}!"),
+    ("exact_secret", "The secret number was exactly {secret}."),
+    ("reverse_intro", "Think of a number between {min} and {max}, and I will guess it!"),
+    ("reverse_ask", "My guess is {guess}. Is your number (h)igher, (l)ower, or is it (c)orrect?"),
+    ("reverse_answer", "Please answer h, l, or c."),
+    ("reverse_found", "I found it: your number is {number}!"),
+    ("cheater", "Cheater! {contradiction}."),
+    ("reverse_between", "Your number is between {low} and {high}."),
+    ("reverse_summary", "I made {count} guess.|I made {count} guesses."),
+    ("prompt_player", "{name}, take a guess, what number between {min} and {max}?"),
+    ("player_guessed", "{name} guessed: {guess}"),
+    ("player_wins", "{name} wins!!!"),
+    ("player_attempts_left", "{name} has {count} attempt left.|{name} has {count} attempts left."),
+    ("hot_seat_command", "Hot-seat games have no hints and cannot be saved. Type a number or 'quit'."),
+    ("winner", "The winner is {name}!"),
+    ("nobody_won", "Nobody won. The secret number was {secret}."),
+    ("turns", "Turns:"),
+    ("players", "Players:"),
+    ("correct", "Correct!"),
+    ("winner_tag", "winner"),
+    ("player_attempts", "{count} attempt|{count} attempts"),
+    ("hint_even", "The number is even."),
+    ("hint_odd", "The number is odd."),
+    ("hint_divisible", "The number is divisible by {divisor}."),
+    ("hint_not_divisible", "The number is not divisible by {divisor}."),
+    ("hint_prime", "The number is prime."),
+    ("hint_not_prime", "The number is not prime."),
+    ("hint_near", "The number is within {distance} of your last guess ({guess})."),
+    ("hint_far", "The number is more than {distance} away from your last guess ({guess})."),
+    ("hints_disabled", "Hints are turned off for this game."),
+    (
+        "hints_too_early",
+        "Hints are offered after {count} miss. Keep guessing!|Hints are offered after {count} misses. Keep guessing!",
+    ),
+    ("no_hints_left", "No more hints: any other clue would give the number away."),
+    ("hint_game_over", "The game is over."),
+    ("freezing", "Freezing!"),
+    ("cold", "Cold."),
+    ("warm", "Warm."),
+    ("hot", "Hot!"),
+    ("burning", "Burning!!!"),
+    ("warmer", "Warmer than your last guess."),
+    ("colder", "Colder than your last guess."),
+    ("same", "As close as your last guess."),
+    ("title", "Guessing Game"),
+    ("attempt_of", "Attempt {attempt} of {max}"),
+    ("attempt", "Attempt {attempt}"),
+    ("win_now", "Win now: {score}"),
+    ("seconds_left", "{seconds}s left"),
+    ("find_secret", "Find the secret number!"),
+    ("not_a_number", "'{guess}' is not a valid number: {error}."),
+    ("saved_to", "Game saved to {path}."),
+    ("candidates_hidden", "Candidates: hidden, only the heat is known"),
+    ("candidates_lies", "Candidates: hidden, since some answers may be lies"),
+    ("candidates", "Candidates: {low} to {high}, {count} left|Candidates: {low} to {high}, {count} left"),
+    ("no_guess", "No guess yet."),
+    ("commands", "Type a number, 'hint', 'save', or 'quit', then press Enter."),
+    ("welcome_back", "Welcome back! You have not guessed yet."),
+    ("welcome_guesses", "Welcome back! Your guesses so far: {guesses}."),
+    ("was_too_small", "too small"),
+    ("was_too_big", "too big"),
+    (
+        "replace_save",
+        "Note: your first guess replaces the saved game. Type 'quit' and use '--resume' to continue it instead.",
+    ),
+    (
+        "auto_lies",
+        "The computer plays through up to {count} lie, between {min} and {max}.|The computer plays through up to {count} lies, between {min} and {max}.",
+    ),
+    ("auto_strategy", "The computer plays with the {strategy} strategy, between {min} and {max}."),
+    ("auto_bisect", "The computer bisects the {type} between {min} and {max}."),
+    ("auto_code", "The computer looks for a code of {digits} distinct digits."),
+    ("auto_guess", "Guess:"),
+    ("secret_number", "The secret number was {secret}."),
+    ("secret_code", "The secret code was {secret}."),
+    ("computer_attempts", "The computer made {count} attempt.|The computer made {count} attempts."),
+    (
+        "computer_bound",
+        "The computer made {count} attempt, for a bound of {bound}.|The computer made {count} attempts, for a bound of {bound}.",
+    ),
+    ("game_lied", "The game lied {count} time.|The game lied {count} times."),
+    ("daily_intro", "Daily challenge of {date}: everyone gets the same number today."),
+    ("daily_played", "You already played the daily challenge of {date}:"),
+    ("daily_replay", "This replay is just for fun: it will not be scored."),
+    ("daily_share", "Share your result:"),
+    ("expr_error", "column {column}: {error}"),
+    ("expr_expected_number", "expected a number, found '{found}'"),
+    ("expr_missing_number", "expected a number, found the end"),
+    ("expr_expected_operator", "expected an operator (+ - * / %), found '{found}'"),
+    ("expr_unclosed_paren", "expected ')'"),
+    ("expr_unknown_name", "unknown name '{name}', only 'last' is known"),
+    ("expr_no_last_guess", "'last' has no value before the first guess"),
+    ("expr_division_by_zero", "division by zero"),
+    ("expr_overflow", "the number is too large"),
+    ("expr_too_deep", "the expression is nested too deeply"),
+    ("code_length", "The code has {expected} digits, not {found}."),
+    ("code_not_a_digit", "'{found}' is not a digit."),
+    ("code_repeated", "The digit {digit} is repeated, but every digit is distinct."),
+    ("word_list_empty", "The word list has no word of {letters} letters."),
+    ("word_length", "The word has {expected} letters, not {found}."),
+    ("word_unknown", "'{word}' is not in the word list."),
+    ("answer_higher", "your number is higher than {guess}"),
+    ("answer_lower", "your number is lower than {guess}"),
+    ("answer_equal", "your number is {guess}"),
+    ("contradicts_answer", "answer #{number} ({answer}) contradicts answer #{earlier} ({earlier_answer})"),
+    (
+        "contradicts_range",
+        "answer #{number} ({answer}) contradicts the range: your number must be between {min} and {max}",
+    ),
+    ("stats_title", "Statistics of {player}"),
+    ("no_games", "No games played yet."),
+    ("games_played", "Games played:"),
+    ("wins", "Wins:"),
+    ("mean_attempts", "Mean attempts:"),
+    ("best_game", "Best game:"),
+    ("worst_game", "Worst game:"),
+    ("current_streak", "Current streak:"),
+    ("longest_streaks", "Longest streaks:"),
+    ("win_count", "{count} win|{count} wins"),
+    ("loss_count", "{count} loss|{count} losses"),
+    ("attempts_to_win", "Attempts to win:"),
+    ("game_record", "{score} points, {attempts}, {category} {min}-{max}, {date}"),
+    ("game_record_lost", "{score} points, {attempts}, {category} {min}-{max}, {date} (lost)"),
+    ("attempt_count", "{count} attempt|{count} attempts"),
+    ("no_wins", "No games won yet."),
+    ("column_rank", "Rank"),
+    ("column_player", "Player"),
+    ("column_score", "Score"),
+    ("column_attempts", "Attempts"),
+    ("column_time", "Time (s)"),
+    ("column_range", "Range"),
+    ("column_date", "Date"),
+    ("bench_intro", "Benchmark between {min} and {max}, seed {seed}."),
+    ("bench_title", "== {strategy} ({count} game) ==|== {strategy} ({count} games) =="),
+    ("bench_mean", "Mean attempts:"),
+    ("bench_worst", "Worst attempts:"),
+    ("replay_mismatch", "MISMATCH line {line}: {input} was recorded as {expected} but replays as {actual}"),
+    ("replay_incomplete", "note: the transcript has no 'end' record, the session did not finish"),
+    (
+        "replay_ok",
+        "OK: {count} line replayed, every outcome matches.|OK: {count} lines replayed, every outcome matches.",
+    ),
+    ("replay_failed", "FAILED: {count} mismatch in {lines} line(s).|FAILED: {count} mismatches in {lines} line(s)."),
+    (
+        "skipped_corrupted",
+        "warning: skipped {count} corrupted line in {path}|warning: skipped {count} corrupted lines in {path}",
+    ),
+    ("cli_unknown_option", "unknown option '{option}'"),
+    ("cli_unknown_command", "unknown command '{command}'"),
+    ("cli_unexpected_argument", "unexpected argument '{argument}'"),
+    ("cli_missing_argument", "'{command}' needs the file to read"),
+    ("cli_missing_value", "option '{option}' needs a value"),
+    ("cli_invalid_value", "invalid value '{value}' for option '{option}'"),
+    ("cli_conflict", "'{first}' cannot be used with '{second}'"),
+    ("cli_requires", "'{option}' needs '{needed}'"),
+    ("inverted_range", "the range {min}..={max} is inverted: the minimum is bigger than the maximum"),
+    ("single_number_range", "the range {min}..={max} holds a single number, there is nothing to guess"),
+    ("unknown_difficulty", "unknown difficulty '{name}', expected easy, normal, hard, or insane"),
+    ("no_attempts", "a game must allow at least one attempt"),
+    ("unknown_strategy", "unknown strategy '{name}', expected binary, random, or linear"),
+    ("player_count", "a hot-seat game needs between 2 and 8 players, not {players}"),
+    ("invalid_player", "the player name '{name}' is empty or used twice"),
+    ("unknown_type", "unknown number type '{name}'"),
+    ("not_of_type", "'{value}' is not a valid {type}"),
+    ("invalid_range", "invalid range {min}..={max}"),
+    ("invalid_tolerance", "invalid tolerance {tolerance}"),
+    ("unknown_language", "unknown language '{language}' (expected en, fr, or mg)"),
+    ("invalid_json", "invalid JSON: {error}"),
+    ("save_unreadable", "cannot read the saved game: {error}"),
+    ("save_corrupted", "the saved game is corrupted: {error}"),
+    ("transcript_unreadable", "cannot read the transcript: {error}"),
+    ("transcript_line", "line {line}: {error}"),
+    ("error", "error: {error}"),
+    ("file_error", "error: {path}: {error}"),
+    ("autosave_failed", "warning: could not save the game in progress: {error}"),
+    ("history_failed", "warning: could not save the game: {error}"),
+    ("daily_failed", "warning: could not record the daily challenge: {error}"),
+    ("listening", "Listening on {address}"),
+    ("connected", "Connected to {address}"),
+    ("accept_failed", "warning: failed to accept a connection: {error}"),
+    (
+        "peer_ended",
+        "[{peer}] session ended: {end} after {count} attempt|[{peer}] session ended: {end} after {count} attempts",
+    ),
+    ("peer_failed", "[{peer}] session failed: {error}"),
+    ("unknown_peer", "unknown peer"),
+    (
+        "usage",
+        "\
+Usage: guessing-game [COMMAND] [OPTIONS]
+
+Commands:
+  play                      Play a game (default)
+  scores                    Print the leaderboard of every difficulty
+  stats                     Print the lifetime statistics of a player ('--name', default: $USER)
+  bulls                     Find a code of distinct digits from its bulls and cows
+  words                     Find a secret word from which of its letters are right (Wordle)
+  reverse                   Think of a number and let the computer guess it
+  bench                     Let every strategy play many seeded games and compare them
+  serve                     Host games over TCP, one per connection
+  connect                   Play on a server started with 'serve'
+  replay <FILE>             Replay a transcript recorded with '--record' and check its outcomes
+  load                      Continue the saved game, like 'play --resume'
+  daily                     Play the number of the day, the same for everyone, scored once a day
+
+Options:
+  -d, --difficulty <LEVEL>  Preset range: easy (1-10), normal (1-100), hard (1-1000), insane (1-1000000)
+      --min <N>             Smallest possible secret number (overrides the difficulty)
+      --max <N>             Largest possible secret number (overrides the difficulty)
+      --type <TYPE>         Type of the secret number: i8 to i128, u8 to u128, f32, or f64 (default: the classic u32 game)
+      --tolerance <X>       How close a guess of '--type' must be to win (default: 0.01 for floats, 0 for integers)
+      --attempts <N>        Maximum number of attempts, or 'unlimited' (default: enough for a perfect binary search)
+      --digits <N>          Digits of the code of 'bulls', from 2 to 5 (default: 4)
+      --letters <N>         Letters of the word of 'words' (default: 5)
+      --word-list <FILE>    Words of 'words', as any text, instead of the embedded English list
+      --time <SECONDS>      Time attack: find the number within this many seconds
+      --lies <K>            Ulam's game: up to K answers may be lies (default: 0, at most 5)
+      --hot-cold            Answer with how close each guess is, from 'freezing' to 'burning', instead of its direction
+      --hints-after <N>     Misses before 'hint' gives clues, or 'never' (default: 3)
+      --seed <SEED>         Seed the secret number to replay the exact same game
+      --record <FILE>       Record the game as a JSON Lines transcript
+      --resume              Continue the game saved with 'save' or on exit, with its own settings
+      --name <NAME>         Player name saved with the score (default: $USER)
+      --players <A,B,...>   Hot-seat game for 2 to 8 named players taking turns
+      --top <N>             Number of games per difficulty shown by 'scores' (default: 10)
+      --lang <LANG>         Language of the game: en, fr, or mg (default: from LC_ALL, LC_MESSAGES, or LANG)
+      --tui                 Play full-screen, with the candidates left as a bar (plain mode when not on a terminal)
+      --auto                Let the computer play, printing each step
+      --batch               Read guesses from stdin, one per line, and answer each with a JSON object
+      --strategy <NAME>     Strategy of '--auto', 'reverse', and 'bench': binary (default), random, or linear
+      --games <N>           Number of games per strategy played by 'bench' (default: 1000)
+      --addr <HOST:PORT>    Address of 'serve' and 'connect' (default: 127.0.0.1:7878)
+  -h, --help                Print this help",
+    ),
+];
+
+/// The French catalog.
+const FRENCH: &[(&str, &str)] = &[
+    ("lies", "Attention : jusqu'à {lies} de mes réponses peuvent être des mensonges !"),
+    ("prompt", "Devinez, quel nombre entre {min} et {max} ?"),
+    ("time_left", "(encore {seconds} s)"),
+    ("time_up", "Temps écoulé !"),
+    ("hint", "Indice : {hint}"),
+    ("saved", "Partie sauvegardée dans {path}. Reprenez-la plus tard avec '--resume'."),
+    ("cannot_save", "Cette partie ne peut pas être sauvegardée."),
+    ("save_failed", "Impossible de sauvegarder la partie : {error}"),
+    ("guessed", "Vous avez proposé : {guess}"),
+    ("invalid_number", "Ce n'était pas un nombre valide !"),
+    ("out_of_range", "Votre proposition est en dehors de l'intervalle accepté."),
+    ("too_small", "Trop petit !"),
+    ("too_big", "Trop grand !"),
+    ("win", "Vous avez gagné !!!"),
+    ("lose", "Perdu ! Plus aucun essai."),
+    ("attempts_left", "Il vous reste {count} essai.|Il vous reste {count} essais."),
+    ("stuck", "Bloqué ? Tapez 'hint' pour un indice, au prix de quelques points."),
+    ("goodbye", "Au revoir ! Le nombre secret était {secret}."),
+    (
+        "summary",
+        "Vous avez fait {count} essai en {seconds} secondes.|Vous avez fait {count} essais en {seconds} secondes.",
+    ),
+    ("score", "Score : {score}"),
+    ("never_lied", "Je n'ai jamais menti."),
+    ("lied", "J'ai menti une fois, sur : {guesses}.|J'ai menti {count} fois, sur : {guesses}."),
+    ("split_times", "Temps par proposition : {splits}."),
+    ("split", "{guess} en {seconds} s"),
+    ("prompt_code", "Devinez, quel code de {digits} chiffres distincts ?"),
+    ("invalid_code", "Ce n'était pas un code valide ! {error}"),
+    ("bulls", "{count} taureau|{count} taureaux"),
+    ("cows", "{count} vache|{count} vaches"),
+    ("goodbye_code", "Au revoir ! Le code secret était {secret}."),
+    ("word_legend", "[A] : bien placée, (B) : mal placée, C : absente du mot."),
+    ("prompt_word", "Devinez, quel mot de {letters} lettres ?"),
+    ("invalid_word", "Ce n'était pas un mot valide ! {error}"),
+    ("goodbye_word", "Au revoir ! Le mot secret était {secret}."),
+    ("prompt_typed", "Devinez, quel {type} entre {min} et {max} ?"),
+    ("within", "(à {tolerance} près)"),
+    ("invalid_typed", "Ce n'était pas un {type} valide !"),
+    ("exact_secret", "Le nombre secret était exactement {secret}."),
+    ("reverse_intro", "Pensez à un nombre entre {min} et {max}, et je vais le deviner !"),
+    (
+        "reverse_ask",
+        "Je propose {guess}. Votre nombre est-il plus grand (h), plus petit (l), ou est-ce le bon (c) ?",
+    ),
+    ("reverse_answer", "Répondez h, l ou c, s'il vous plaît."),
+    ("reverse_found", "Trouvé : votre nombre est {number} !"),
+    ("cheater", "Tricheur ! {contradiction}."),
+    ("reverse_between", "Votre nombre est entre {low} et {high}."),
+    ("reverse_summary", "J'ai fait {count} proposition.|J'ai fait {count} propositions."),
+    ("prompt_player", "{name}, devinez, quel nombre entre {min} et {max} ?"),
+    ("player_guessed", "{name} a proposé : {guess}"),
+    ("player_wins", "{name} a gagné !!!"),
+    ("player_attempts_left", "Il reste {count} essai à {name}.|Il reste {count} essais à {name}."),
+    ("hot_seat_command", "Les parties à plusieurs n'ont pas d'indices et ne se sauvegardent pas. Tapez un nombre ou 'quit'."),
+    ("winner", "Le gagnant est {name} !"),
+    ("nobody_won", "Personne n'a gagné. Le nombre secret était {secret}."),
+    ("turns", "Tours :"),
+    ("players", "Joueurs :"),
+    ("correct", "Trouvé !"),
+    ("winner_tag", "gagnant"),
+    ("player_attempts", "{count} essai|{count} essais"),
+    ("hint_even", "Le nombre est pair."),
+    ("hint_odd", "Le nombre est impair."),
+    ("hint_divisible", "Le nombre est divisible par {divisor}."),
+    ("hint_not_divisible", "Le nombre n'est pas divisible par {divisor}."),
+    ("hint_prime", "Le nombre est premier."),
+    ("hint_not_prime", "Le nombre n'est pas premier."),
+    ("hint_near", "Le nombre est à {distance} au plus de votre dernière proposition ({guess})."),
+    ("hint_far", "Le nombre est à plus de {distance} de votre dernière proposition ({guess})."),
+    ("hints_disabled", "Les indices sont désactivés pour cette partie."),
+    (
+        "hints_too_early",
+        "Les indices sont proposés après {count} échec. Continuez !|Les indices sont proposés après {count} échecs. Continuez !",
+    ),
+    ("no_hints_left", "Plus d'indice : tout autre indice trahirait le nombre."),
+    ("hint_game_over", "La partie est terminée."),
+    ("freezing", "Glacial !"),
+    ("cold", "Froid."),
+    ("warm", "Tiède."),
+    ("hot", "Chaud !"),
+    ("burning", "Brûlant !!!"),
+    ("warmer", "Plus chaud que votre dernière proposition."),
+    ("colder", "Plus froid que votre dernière proposition."),
+    ("same", "Aussi proche que votre dernière proposition."),
+    ("title", "Jeu de devinette"),
+    ("attempt_of", "Essai {attempt} sur {max}"),
+    ("attempt", "Essai {attempt}"),
+    ("win_now", "En jeu : {score}"),
+    ("seconds_left", "encore {seconds} s"),
+    ("find_secret", "Trouvez le nombre secret !"),
+    ("not_a_number", "'{guess}' n'est pas un nombre valide : {error}."),
+    ("saved_to", "Partie sauvegardée dans {path}."),
+    ("candidates_hidden", "Candidats : cachés, seule la chaleur est connue"),
+    ("candidates_lies", "Candidats : cachés, car certaines réponses peuvent être des mensonges"),
+    (
+        "candidates",
+        "Candidats : de {low} à {high}, {count} restant|Candidats : de {low} à {high}, {count} restants",
+    ),
+    ("no_guess", "Aucune proposition pour l'instant."),
+    ("commands", "Tapez un nombre, 'hint', 'save' ou 'quit', puis Entrée."),
+    ("welcome_back", "Bon retour ! Vous n'avez encore rien proposé."),
+    ("welcome_guesses", "Bon retour ! Vos propositions jusqu'ici : {guesses}."),
+    ("was_too_small", "trop petit"),
+    ("was_too_big", "trop grand"),
+    (
+        "replace_save",
+        "Remarque : votre première proposition remplace la partie sauvegardée. Tapez 'quit' et utilisez '--resume' pour la reprendre.",
+    ),
+    (
+        "auto_lies",
+        "L'ordinateur joue malgré jusqu'à {count} mensonge, entre {min} et {max}.|L'ordinateur joue malgré jusqu'à {count} mensonges, entre {min} et {max}.",
+    ),
+    ("auto_strategy", "L'ordinateur joue avec la stratégie {strategy}, entre {min} et {max}."),
+    ("auto_bisect", "L'ordinateur procède par dichotomie sur les {type} entre {min} et {max}."),
+    ("auto_code", "L'ordinateur cherche un code de {digits} chiffres distincts."),
+    ("auto_guess", "Essai :"),
+    ("secret_number", "Le nombre secret était {secret}."),
+    ("secret_code", "Le code secret était {secret}."),
+    ("computer_attempts", "L'ordinateur a fait {count} essai.|L'ordinateur a fait {count} essais."),
+    (
+        "computer_bound",
+        "L'ordinateur a fait {count} essai, pour une borne de {bound}.|L'ordinateur a fait {count} essais, pour une borne de {bound}.",
+    ),
+    ("game_lied", "Le jeu a menti {count} fois.|Le jeu a menti {count} fois."),
+    ("daily_intro", "Défi du jour du {date} : tout le monde a le même nombre aujourd'hui."),
+    ("daily_played", "Vous avez déjà joué le défi du jour du {date} :"),
+    ("daily_replay", "Cette partie est juste pour le plaisir : elle ne sera pas comptée."),
+    ("daily_share", "Partagez votre résultat :"),
+    ("expr_error", "colonne {column} : {error}"),
+    ("expr_expected_number", "un nombre était attendu, et non '{found}'"),
+    ("expr_missing_number", "un nombre était attendu, et non la fin"),
+    ("expr_expected_operator", "un opérateur (+ - * / %) était attendu, et non '{found}'"),
+    ("expr_unclosed_paren", "')' était attendu"),
+    ("expr_unknown_name", "nom inconnu '{name}', seul 'last' est connu"),
+    ("expr_no_last_guess", "'last' n'a pas de valeur avant le premier essai"),
+    ("expr_division_by_zero", "division par zéro"),
+    ("expr_overflow", "le nombre est trop grand"),
+    ("expr_too_deep", "l'expression est trop imbriquée"),
+    ("code_length", "Le code a {expected} chiffres, et non {found}."),
+    ("code_not_a_digit", "'{found}' n'est pas un chiffre."),
+    ("code_repeated", "Le chiffre {digit} est répété, mais tous les chiffres sont distincts."),
+    ("word_list_empty", "La liste n'a aucun mot de {letters} lettres."),
+    ("word_length", "Le mot a {expected} lettres, et non {found}."),
+    ("word_unknown", "'{word}' n'est pas dans la liste de mots."),
+    ("answer_higher", "votre nombre est plus grand que {guess}"),
+    ("answer_lower", "votre nombre est plus petit que {guess}"),
+    ("answer_equal", "votre nombre est {guess}"),
+    ("contradicts_answer", "la réponse n° {number} ({answer}) contredit la réponse n° {earlier} ({earlier_answer})"),
+    (
+        "contradicts_range",
+        "la réponse n° {number} ({answer}) contredit l'intervalle : votre nombre doit être entre {min} et {max}",
+    ),
+    ("stats_title", "Statistiques de {player}"),
+    ("no_games", "Aucune partie jouée pour l'instant."),
+    ("games_played", "Parties jouées :"),
+    ("wins", "Victoires :"),
+    ("mean_attempts", "Essais en moyenne :"),
+    ("best_game", "Meilleure partie :"),
+    ("worst_game", "Pire partie :"),
+    ("current_streak", "Série en cours :"),
+    ("longest_streaks", "Plus longues séries :"),
+    ("win_count", "{count} victoire|{count} victoires"),
+    ("loss_count", "{count} défaite|{count} défaites"),
+    ("attempts_to_win", "Essais pour gagner :"),
+    ("game_record", "{score} points, {attempts}, {category} {min}-{max}, {date}"),
+    ("game_record_lost", "{score} points, {attempts}, {category} {min}-{max}, {date} (perdue)"),
+    ("attempt_count", "{count} essai|{count} essais"),
+    ("no_wins", "Aucune partie gagnée pour l'instant."),
+    ("column_rank", "Rang"),
+    ("column_player", "Joueur"),
+    ("column_score", "Score"),
+    ("column_attempts", "Essais"),
+    ("column_time", "Temps (s)"),
+    ("column_range", "Intervalle"),
+    ("column_date", "Date"),
+    ("bench_intro", "Banc d'essai entre {min} et {max}, graine {seed}."),
+    ("bench_title", "== {strategy} ({count} partie) ==|== {strategy} ({count} parties) =="),
+    ("bench_mean", "Essais en moyenne :"),
+    ("bench_worst", "Pire nombre d'essais :"),
+    ("replay_mismatch", "ÉCART ligne {line} : {input} a été enregistré comme {expected} mais rejoue comme {actual}"),
+    ("replay_incomplete", "note : la transcription n'a pas d'enregistrement 'end', la session ne s'est pas terminée"),
+    (
+        "replay_ok",
+        "OK : {count} ligne rejouée, chaque résultat concorde.|OK : {count} lignes rejouées, chaque résultat concorde.",
+    ),
+    ("replay_failed", "ÉCHEC : {count} écart sur {lines} ligne(s).|ÉCHEC : {count} écarts sur {lines} ligne(s)."),
+    (
+        "skipped_corrupted",
+        "avertissement : {count} ligne corrompue ignorée dans {path}|avertissement : {count} lignes corrompues ignorées dans {path}",
+    ),
+    ("cli_unknown_option", "option inconnue '{option}'"),
+    ("cli_unknown_command", "commande inconnue '{command}'"),
+    ("cli_unexpected_argument", "argument inattendu '{argument}'"),
+    ("cli_missing_argument", "'{command}' a besoin du fichier à lire"),
+    ("cli_missing_value", "l'option '{option}' a besoin d'une valeur"),
+    ("cli_invalid_value", "valeur '{value}' invalide pour l'option '{option}'"),
+    ("cli_conflict", "'{first}' ne peut pas être utilisé avec '{second}'"),
+    ("cli_requires", "'{option}' a besoin de '{needed}'"),
+    ("inverted_range", "l'intervalle {min}..={max} est inversé : le minimum est plus grand que le maximum"),
+    ("single_number_range", "l'intervalle {min}..={max} ne contient qu'un seul nombre, il n'y a rien à deviner"),
+    ("unknown_difficulty", "difficulté inconnue '{name}', attendu easy, normal, hard ou insane"),
+    ("no_attempts", "une partie doit permettre au moins un essai"),
+    ("unknown_strategy", "stratégie inconnue '{name}', attendu binary, random ou linear"),
+    ("player_count", "une partie à plusieurs demande entre 2 et 8 joueurs, pas {players}"),
+    ("invalid_player", "le nom de joueur '{name}' est vide ou utilisé deux fois"),
+    ("unknown_type", "type de nombre inconnu '{name}'"),
+    ("not_of_type", "'{value}' n'est pas un {type} valide"),
+    ("invalid_range", "intervalle invalide {min}..={max}"),
+    ("invalid_tolerance", "tolérance invalide {tolerance}"),
+    ("unknown_language", "langue inconnue '{language}' (attendu en, fr ou mg)"),
+    ("invalid_json", "JSON invalide : {error}"),
+    ("save_unreadable", "impossible de lire la partie sauvegardée : {error}"),
+    ("save_corrupted", "la partie sauvegardée est corrompue : {error}"),
+    ("transcript_unreadable", "impossible de lire la transcription : {error}"),
+    ("transcript_line", "ligne {line} : {error}"),
+    ("error", "erreur : {error}"),
+    ("file_error", "erreur : {path} : {error}"),
+    ("autosave_failed", "avertissement : impossible de sauvegarder la partie en cours : {error}"),
+    ("history_failed", "avertissement : impossible d'enregistrer la partie : {error}"),
+    ("daily_failed", "avertissement : impossible d'enregistrer le défi du jour : {error}"),
+    ("listening", "En écoute sur {address}"),
+    ("connected", "Connecté à {address}"),
+    ("accept_failed", "avertissement : impossible d'accepter une connexion : {error}"),
+    (
+        "peer_ended",
+        "[{peer}] session terminée : {end} après {count} essai|[{peer}] session terminée : {end} après {count} essais",
+    ),
+    ("peer_failed", "[{peer}] échec de la session : {error}"),
+    ("unknown_peer", "pair inconnu"),
+    (
+        "usage",
+        "\
+Utilisation : guessing-game [COMMAND] [OPTIONS]
+
+Commandes :
+  play                      Jouer une partie (par défaut)
+  scores                    Afficher le classement de chaque difficulté
+  stats                     Afficher les statistiques d'un joueur ('--name', par défaut : $USER)
+  bulls                     Trouver un code de chiffres distincts grâce à ses taureaux et ses vaches
+  words                     Trouver un mot secret d'après ses lettres justes (Wordle)
+  reverse                   Penser à un nombre et laisser l'ordinateur le deviner
+  bench                     Faire jouer à chaque stratégie de nombreuses parties avec graine et les comparer
+  serve                     Héberger des parties en TCP, une par connexion
+  connect                   Jouer sur un serveur lancé avec 'serve'
+  replay <FILE>             Rejouer une transcription enregistrée avec '--record' et vérifier ses résultats
+  load                      Reprendre la partie sauvegardée, comme 'play --resume'
+  daily                     Jouer le nombre du jour, le même pour tous, compté une fois par jour
+
+Options :
+  -d, --difficulty <LEVEL>  Intervalle prédéfini : easy (1-10), normal (1-100), hard (1-1000), insane (1-1000000)
+      --min <N>             Plus petit nombre secret possible (remplace la difficulté)
+      --max <N>             Plus grand nombre secret possible (remplace la difficulté)
+      --type <TYPE>         Type du nombre secret : i8 à i128, u8 à u128, f32 ou f64 (par défaut : le jeu classique en u32)
+      --tolerance <X>       Écart permis pour gagner avec '--type' (par défaut : 0.01 pour les flottants, 0 pour les entiers)
+      --attempts <N>        Nombre maximal d'essais, ou 'unlimited' (par défaut : assez pour une recherche dichotomique parfaite)
+      --digits <N>          Chiffres du code de 'bulls', de 2 à 5 (par défaut : 4)
+      --letters <N>         Lettres du mot de 'words' (par défaut : 5)
+      --word-list <FILE>    Mots de 'words', dans n'importe quel texte, au lieu de la liste anglaise intégrée
+      --time <SECONDS>      Contre la montre : trouver le nombre en autant de secondes
+      --lies <K>            Jeu d'Ulam : jusqu'à K réponses peuvent être des mensonges (par défaut : 0, au plus 5)
+      --hot-cold            Répondre par la proximité de chaque proposition, de 'glacial' à 'brûlant', au lieu de sa direction
+      --hints-after <N>     Échecs avant que 'hint' donne des indices, ou 'never' (par défaut : 3)
+      --seed <SEED>         Graine du nombre secret, pour rejouer exactement la même partie
+      --record <FILE>       Enregistrer la partie comme transcription JSON Lines
+      --resume              Reprendre la partie sauvegardée avec 'save' ou en quittant, avec ses propres réglages
+      --name <NAME>         Nom du joueur enregistré avec le score (par défaut : $USER)
+      --players <A,B,...>   Partie à plusieurs pour 2 à 8 joueurs nommés, chacun son tour
+      --top <N>             Nombre de parties par difficulté affichées par 'scores' (par défaut : 10)
+      --lang <LANG>         Langue du jeu : en, fr ou mg (par défaut : selon LC_ALL, LC_MESSAGES ou LANG)
+      --tui                 Jouer en plein écran, avec les candidats restants en barre (mode simple hors d'un terminal)
+      --auto                Laisser l'ordinateur jouer, en affichant chaque étape
+      --batch               Lire les propositions sur stdin, une par ligne, et répondre à chacune par un objet JSON
+      --strategy <NAME>     Stratégie de '--auto', 'reverse' et 'bench' : binary (par défaut), random ou linear
+      --games <N>           Nombre de parties par stratégie jouées par 'bench' (par défaut : 1000)
+      --addr <HOST:PORT>    Adresse de 'serve' et 'connect' (par défaut : 127.0.0.1:7878)
+  -h, --help                Afficher cette aide",
+    ),
+];
+
+/// The Malagasy catalog, with a single form for counts.
+const MALAGASY: &[(&str, &str)] = &[
+    ("lies", "Tandremo: hatramin'ny {lies} amin'ireo valiko no mety ho lainga!"),
+    ("prompt", "Maminavina, inona no isa eo anelanelan'ny {min} sy {max}?"),
+    ("time_left", "({seconds}s sisa)"),
+    ("time_up", "Tapitra ny fotoana!"),
+    ("hint", "Famantarana: {hint}"),
+    ("saved", "Voatahiry ao amin'ny {path} ny lalao. Tohizo any aoriana amin'ny '--resume'."),
+    ("cannot_save", "Tsy azo tehirizina ity lalao ity."),
+    ("save_failed", "Tsy voatahiry ny lalao: {error}"),
+    ("guessed", "Ny vinavinanao: {guess}"),
+    ("invalid_number", "Tsy isa manan-kery izany!"),
+    ("out_of_range", "Ivelan'ny elanelana ekena ny vinavinanao."),
+    ("too_small", "Kely loatra!"),
+    ("too_big", "Lehibe loatra!"),
+    ("win", "Nandresy ianao!!!"),
+    ("lose", "Resy ianao! Lany ny andrana."),
+    ("attempts_left", "Mbola manana andrana {count} ianao."),
+    ("stuck", "Sahirana? Soraty 'hint' hahazoana famantarana, saingy hihena kely ny naotinao."),
+    ("goodbye", "Veloma! Ny isa miafina dia {secret}."),
+    ("summary", "Nanao andrana {count} tao anatin'ny {seconds} segondra ianao."),
+    ("score", "Naoty: {score}"),
+    ("never_lied", "Tsy nandainga mihitsy aho."),
+    ("lied", "Nandainga in-{count} aho, momba ireto: {guesses}."),
+    ("split_times", "Fotoana isaky ny vinavina: {splits}."),
+    ("split", "{guess} tao anatin'ny {seconds}s"),
+    ("prompt_code", "Maminavina, inona no kaody misy tarehimarika {digits} samy hafa?"),
+    ("invalid_code", "Tsy kaody manan-kery izany! {error}"),
+    ("bulls", "ombilahy {count}"),
+    ("cows", "ombivavy {count}"),
+    ("goodbye_code", "Veloma! Ny kaody miafina dia {secret}."),
+    ("word_legend", "[A]: toerana marina, (B): toerana diso, C: tsy ao amin'ny teny."),
+    ("prompt_word", "Maminavina, inona no teny misy litera {letters}?"),
+    ("invalid_word", "Tsy teny manan-kery izany! {error}"),
+    ("goodbye_word", "Veloma! Ny teny miafina dia {secret}."),
+    ("prompt_typed", "Maminavina, inona no {type} eo anelanelan'ny {min} sy {max}?"),
+    ("within", "(hatramin'ny {tolerance} akaiky)"),
+    ("invalid_typed", "Tsy {type} manan-kery izany!"),
+    ("exact_secret", "Ny isa miafina dia {secret} tsy misy diso."),
+    ("reverse_intro", "Eritrereto isa iray eo anelanelan'ny {min} sy {max}, dia hovinavinaiko izany!"),
+    ("reverse_ask", "Ny vinavinako dia {guess}. Ambony kokoa (h), ambany kokoa (l), sa marina (c) ny isanao?"),
+    ("reverse_answer", "Valio h, l, na c azafady."),
+    ("reverse_found", "Hitako: {number} ny isanao!"),
+    ("cheater", "Mpisoloky! {contradiction}."),
+    ("reverse_between", "Eo anelanelan'ny {low} sy {high} ny isanao."),
+    ("reverse_summary", "Nanao vinavina {count} aho."),
+    ("prompt_player", "{name}, maminavina, inona no isa eo anelanelan'ny {min} sy {max}?"),
+    ("player_guessed", "Ny vinavinan'i {name}: {guess}"),
+    ("player_wins", "Nandresy i {name}!!!"),
+    ("player_attempts_left", "Mbola manana andrana {count} i {name}."),
+    ("hot_seat_command", "Tsy misy toro-hevitra ary tsy azo tehirizina ny lalao mifandimby. Soraty isa na 'quit'."),
+    ("winner", "{name} no mpandresy!"),
+    ("nobody_won", "Tsy nisy nandresy. Ny isa miafina dia {secret}."),
+    ("turns", "Fihodinana:"),
+    ("players", "Mpilalao:"),
+    ("correct", "Marina!"),
+    ("winner_tag", "mpandresy"),
+    ("player_attempts", "andrana {count}"),
+    ("hint_even", "Isa tsiroaroa ilay isa."),
+    ("hint_odd", "Isa tsy tsiroaroa ilay isa."),
+    ("hint_divisible", "Azo zaraina amin'ny {divisor} ilay isa."),
+    ("hint_not_divisible", "Tsy azo zaraina amin'ny {divisor} ilay isa."),
+    ("hint_prime", "Isa voalohany ilay isa."),
+    ("hint_not_prime", "Tsy isa voalohany ilay isa."),
+    ("hint_near", "Tsy mihoatra ny {distance} ny elanelan'ilay isa sy ny vinavinanao farany ({guess})."),
+    ("hint_far", "Mihoatra ny {distance} ny elanelan'ilay isa sy ny vinavinanao farany ({guess})."),
+    ("hints_disabled", "Tsy misy famantarana amin'ity lalao ity."),
+    ("hints_too_early", "Omena famantarana aorian'ny diso {count}. Tohizo ny vinavina!"),
+    ("no_hints_left", "Tsy misy famantarana intsony: hanambara ilay isa ny famantarana hafa rehetra."),
+    ("hint_game_over", "Tapitra ny lalao."),
+    ("freezing", "Mangatsiaka be!"),
+    ("cold", "Mangatsiaka."),
+    ("warm", "Mafana kely."),
+    ("hot", "Mafana!"),
+    ("burning", "Mirehitra!!!"),
+    ("warmer", "Mafana kokoa noho ny vinavinanao farany."),
+    ("colder", "Mangatsiaka kokoa noho ny vinavinanao farany."),
+    ("same", "Mitovy akaiky amin'ny vinavinanao farany."),
+    ("title", "Lalao Vinavina"),
+    ("attempt_of", "Andrana {attempt} amin'ny {max}"),
+    ("attempt", "Andrana {attempt}"),
+    ("win_now", "Naoty raha hita: {score}"),
+    ("seconds_left", "{seconds}s sisa"),
+    ("find_secret", "Tadiavo ny isa miafina!"),
+    ("not_a_number", "Tsy isa manan-kery '{guess}': {error}."),
+    ("saved_to", "Voatahiry ao amin'ny {path} ny lalao."),
+    ("candidates_hidden", "Kandida: miafina, ny hafanana ihany no fantatra"),
+    ("candidates_lies", "Kandida: miafina, satria mety ho lainga ny valiny sasany"),
+    ("candidates", "Kandida: {low} ka hatramin'ny {high}, {count} sisa"),
+    ("no_guess", "Mbola tsy nisy vinavina."),
+    ("commands", "Soraty isa, 'hint', 'save', na 'quit', dia tsindrio Enter."),
+    ("welcome_back", "Tongasoa indray! Mbola tsy nanao vinavina ianao."),
+    ("welcome_guesses", "Tongasoa indray! Ireo vinavinanao hatreto: {guesses}."),
+    ("was_too_small", "kely loatra"),
+    ("was_too_big", "lehibe loatra"),
+    (
+        "replace_save",
+        "Fanamarihana: ny vinavinanao voalohany no hisolo ny lalao voatahiry. Soraty 'quit' ary ampiasao '--resume' raha hanohy azy kosa.",
+    ),
+    (
+        "auto_lies",
+        "Milalao ny solosaina na dia mety misy lainga hatramin'ny {count} aza, eo anelanelan'ny {min} sy {max}.",
+    ),
+    ("auto_strategy", "Milalao amin'ny paikady {strategy} ny solosaina, eo anelanelan'ny {min} sy {max}."),
+    ("auto_bisect", "Mizarazara roa ny {type} eo anelanelan'ny {min} sy {max} ny solosaina."),
+    ("auto_code", "Mitady kaody misy tarehimarika {digits} samy hafa ny solosaina."),
+    ("auto_guess", "Vinavina:"),
+    ("secret_number", "Ny isa miafina dia {secret}."),
+    ("secret_code", "Ny kaody miafina dia {secret}."),
+    ("computer_attempts", "Nanao andrana {count} ny solosaina."),
+    ("computer_bound", "Nanao andrana {count} ny solosaina, ho an'ny fetra {bound}."),
+    ("game_lied", "Nandainga in-{count} ny lalao."),
+    ("daily_intro", "Fanamby isan'andro {date}: mitovy isa ny rehetra anio."),
+    ("daily_played", "Efa nilalao ny fanamby isan'andro {date} ianao:"),
+    ("daily_replay", "Fialam-boly fotsiny ity lalao ity: tsy hisaina ny naotiny."),
+    ("daily_share", "Zarao amin'ny hafa ny valinao:"),
+    ("expr_error", "tsanganana {column}: {error}"),
+    ("expr_expected_number", "isa no nampoizina fa tsy '{found}'"),
+    ("expr_missing_number", "isa no nampoizina fa tsy ny farany"),
+    ("expr_expected_operator", "fandidiana (+ - * / %) no nampoizina fa tsy '{found}'"),
+    ("expr_unclosed_paren", "')' no nampoizina"),
+    ("expr_unknown_name", "anarana tsy fantatra '{name}', 'last' ihany no fantatra"),
+    ("expr_no_last_guess", "tsy manana sanda 'last' alohan'ny vinavina voalohany"),
+    ("expr_division_by_zero", "fizarana amin'ny aotra"),
+    ("expr_overflow", "lehibe loatra ilay isa"),
+    ("expr_too_deep", "lalina loatra ny fifanakambanan'ilay fomba fiteny"),
+    ("code_length", "Misy tarehimarika {expected} ny kaody fa tsy {found}."),
+    ("code_not_a_digit", "Tsy tarehimarika '{found}'."),
+    ("code_repeated", "Miverimberina ny tarehimarika {digit}, nefa samy hafa ny tarehimarika rehetra."),
+    ("word_list_empty", "Tsy misy teny misy litera {letters} ao amin'ny lisitra."),
+    ("word_length", "Misy litera {expected} ilay teny fa tsy {found}."),
+    ("word_unknown", "Tsy ao amin'ny lisitry ny teny '{word}'."),
+    ("answer_higher", "ambony noho ny {guess} ny isanao"),
+    ("answer_lower", "ambany noho ny {guess} ny isanao"),
+    ("answer_equal", "{guess} ny isanao"),
+    ("contradicts_answer", "mifanohitra amin'ny valiny #{earlier} ({earlier_answer}) ny valiny #{number} ({answer})"),
+    (
+        "contradicts_range",
+        "mifanohitra amin'ny elanelana ny valiny #{number} ({answer}): tsy maintsy eo anelanelan'ny {min} sy {max} ny isanao",
+    ),
+    ("stats_title", "Antontan'isan'i {player}"),
+    ("no_games", "Mbola tsy nisy lalao nilalaovina."),
+    ("games_played", "Lalao nilalaovina:"),
+    ("wins", "Fandresena:"),
+    ("mean_attempts", "Andrana antonony:"),
+    ("best_game", "Lalao tsara indrindra:"),
+    ("worst_game", "Lalao ratsy indrindra:"),
+    ("current_streak", "Andiany ankehitriny:"),
+    ("longest_streaks", "Andiany lava indrindra:"),
+    ("win_count", "fandresena {count}"),
+    ("loss_count", "faharesena {count}"),
+    ("attempts_to_win", "Andrana hahazoana fandresena:"),
+    ("game_record", "isa {score}, {attempts}, {category} {min}-{max}, {date}"),
+    ("game_record_lost", "isa {score}, {attempts}, {category} {min}-{max}, {date} (resy)"),
+    ("attempt_count", "andrana {count}"),
+    ("no_wins", "Mbola tsy nisy lalao nandresena."),
+    ("column_rank", "Laharana"),
+    ("column_player", "Mpilalao"),
+    ("column_score", "Isa"),
+    ("column_attempts", "Andrana"),
+    ("column_time", "Fotoana (s)"),
+    ("column_range", "Elanelana"),
+    ("column_date", "Daty"),
+    ("bench_intro", "Fampitahana eo anelanelan'ny {min} sy {max}, voa {seed}."),
+    ("bench_title", "== {strategy} (lalao {count}) =="),
+    ("bench_mean", "Andrana antonony:"),
+    ("bench_worst", "Andrana ratsy indrindra:"),
+    (
+        "replay_mismatch",
+        "TSY MITOVY andalana {line}: {input} voarakitra ho {expected} nefa lasa {actual} rehefa averina",
+    ),
+    ("replay_incomplete", "fanamarihana: tsy misy firaketana 'end' ny dika, tsy vita ny lalao"),
+    ("replay_ok", "OK: andalana {count} naverina, mitovy avokoa ny valiny."),
+    ("replay_failed", "TSY NAHOMBY: tsy fitoviana {count} amin'ny andalana {lines}."),
+    ("skipped_corrupted", "fampitandremana: andalana simba {count} tsy noraharahiana tao amin'ny {path}"),
+    ("cli_unknown_option", "safidy tsy fantatra '{option}'"),
+    ("cli_unknown_command", "baiko tsy fantatra '{command}'"),
+    ("cli_unexpected_argument", "tohan-kevitra tsy nampoizina '{argument}'"),
+    ("cli_missing_argument", "mila ny rakitra hovakiana i '{command}'"),
+    ("cli_missing_value", "mila sanda ny safidy '{option}'"),
+    ("cli_invalid_value", "sanda '{value}' tsy mety ho an'ny safidy '{option}'"),
+    ("cli_conflict", "tsy azo ampiarahina amin'ny '{second}' i '{first}'"),
+    ("cli_requires", "mila '{needed}' i '{option}'"),
+    ("inverted_range", "mivadika ny elanelana {min}..={max}: lehibe noho ny farany ambony ny farany ambany"),
+    ("single_number_range", "isa iray monja no ao amin'ny elanelana {min}..={max}, tsy misy hovinavinaina"),
+    ("unknown_difficulty", "haavon-tsarotra tsy fantatra '{name}', andrasana easy, normal, hard, na insane"),
+    ("no_attempts", "tsy maintsy mamela andrana iray farafahakeliny ny lalao"),
+    ("unknown_strategy", "paikady tsy fantatra '{name}', andrasana binary, random, na linear"),
+    ("player_count", "mila mpilalao 2 ka hatramin'ny 8 ny lalao mifandimby, fa tsy {players}"),
+    ("invalid_player", "foana na efa nampiasaina indroa ny anaran'ny mpilalao '{name}'"),
+    ("unknown_type", "karazana isa tsy fantatra '{name}'"),
+    ("not_of_type", "tsy {type} mety '{value}'"),
+    ("invalid_range", "elanelana tsy mety {min}..={max}"),
+    ("invalid_tolerance", "fandeferana tsy mety {tolerance}"),
+    ("unknown_language", "fiteny tsy fantatra '{language}' (andrasana en, fr, na mg)"),
+    ("invalid_json", "JSON tsy mety: {error}"),
+    ("save_unreadable", "tsy vakiana ny lalao voatahiry: {error}"),
+    ("save_corrupted", "simba ny lalao voatahiry: {error}"),
+    ("transcript_unreadable", "tsy vakiana ny dika: {error}"),
+    ("transcript_line", "andalana {line}: {error}"),
+    ("error", "hadisoana: {error}"),
+    ("file_error", "hadisoana: {path}: {error}"),
+    ("autosave_failed", "fampitandremana: tsy voatahiry ny lalao mbola mandeha: {error}"),
+    ("history_failed", "fampitandremana: tsy voatahiry ny lalao: {error}"),
+    ("daily_failed", "fampitandremana: tsy voarakitra ny fanamby androany: {error}"),
+    ("listening", "Mihaino ao amin'ny {address}"),
+    ("connected", "Mifandray amin'ny {address}"),
+    ("accept_failed", "fampitandremana: tsy voaray ny fifandraisana: {error}"),
+    ("peer_ended", "[{peer}] vita ny lalao: {end} taorian'ny andrana {count}"),
+    ("peer_failed", "[{peer}] tsy nahomby ny lalao: {error}"),
+    ("unknown_peer", "mpifandray tsy fantatra"),
+    (
+        "usage",
+        "\
+Fampiasana: guessing-game [COMMAND] [OPTIONS]
+
+Baiko:
+  play                      Milalao lalao iray (raha tsy misy hafa)
+  scores                    Asehoy ny laharan'ny haavon-tsarotra tsirairay
+  stats                     Asehoy ny antontan'isan'ny mpilalao iray ('--name', raha tsy misy hafa: $USER)
+  bulls                     Tadiavo ny kaody misy isa tsy mitovy amin'ny alalan'ny omby sy ny ombivavy
+  words                     Tadiavo ny teny miafina amin'ny alalan'ny litera marina (Wordle)
+  reverse                   Saino isa iray ary avelao ny solosaina hamantatra azy
+  bench                     Avelao ny paikady tsirairay hilalao lalao maro misy voa ary ampitahao
+  serve                     Mampiantrano lalao amin'ny TCP, iray isaky ny fifandraisana
+  connect                   Milalao amin'ny mpizara natomboka tamin'ny 'serve'
+  replay <FILE>             Avereno ny dika voarakitra tamin'ny '--record' ary hamarino ny valiny
+  load                      Tohizo ny lalao voatahiry, toy ny 'play --resume'
+  daily                     Milalao ny isan'ny andro, mitovy ho an'ny rehetra, isaina indray mandeha isan'andro
+
+Safidy:
+  -d, --difficulty <LEVEL>  Elanelana efa voafaritra: easy (1-10), normal (1-100), hard (1-1000), insane (1-1000000)
+      --min <N>             Isa miafina kely indrindra azo atao (manolo ny haavon-tsarotra)
+      --max <N>             Isa miafina lehibe indrindra azo atao (manolo ny haavon-tsarotra)
+      --type <TYPE>         Karazan'ny isa miafina: i8 ka hatramin'ny i128, u8 ka hatramin'ny u128, f32, na f64 (raha tsy misy hafa: ny lalao u32 mahazatra)
+      --tolerance <X>       Akaiky toy inona ny vinavina '--type' vao mandresy (raha tsy misy hafa: 0.01 ho an'ny float, 0 ho an'ny isa manontolo)
+      --attempts <N>        Isan'ny andrana ambony indrindra, na 'unlimited' (raha tsy misy hafa: ampy ho an'ny fikarohana mizara roa tonga lafatra)
+      --digits <N>          Isa amin'ny kaodin'ny 'bulls', 2 ka hatramin'ny 5 (raha tsy misy hafa: 4)
+      --letters <N>         Litera amin'ny tenin'ny 'words' (raha tsy misy hafa: 5)
+      --word-list <FILE>    Tenin'ny 'words', amin'ny lahatsoratra rehetra, fa tsy ny lisitra anglisy voarakitra
+      --time <SECONDS>      Hazakazaka amin'ny fotoana: tadiavo ny isa ao anatin'ireo segondra ireo
+      --lies <K>            Lalaon'i Ulam: valiny K no mety ho lainga (raha tsy misy hafa: 0, 5 fara fahabetsany)
+      --hot-cold            Valio amin'ny hoe akaiky toy inona ny vinavina, manomboka amin'ny 'mangatsiaka' ka hatramin'ny 'may', fa tsy ny lalana
+      --hints-after <N>     Tsy fahombiazana alohan'ny hanomezan'ny 'hint' toro-hevitra, na 'never' (raha tsy misy hafa: 3)
+      --seed <SEED>         Voan'ny isa miafina hamerenana ilay lalao mitovy tanteraka
+      --record <FILE>       Raketo ho dika JSON Lines ny lalao
+      --resume              Tohizo ny lalao voatahiry tamin'ny 'save' na tamin'ny fivoahana, miaraka amin'ny fandrindrany
+      --name <NAME>         Anaran'ny mpilalao voatahiry miaraka amin'ny isa azo (raha tsy misy hafa: $USER)
+      --players <A,B,...>   Lalao mifandimby ho an'ny mpilalao 2 ka hatramin'ny 8 manana anarana
+      --top <N>             Isan'ny lalao isaky ny haavon-tsarotra asehon'ny 'scores' (raha tsy misy hafa: 10)
+      --lang <LANG>         Fitenin'ny lalao: en, fr, na mg (raha tsy misy hafa: avy amin'ny LC_ALL, LC_MESSAGES, na LANG)
+      --tui                 Milalao amin'ny efijery feno, miaraka amin'ny tsipika mampiseho ny isa sisa (maody tsotra raha tsy terminal)
+      --auto                Avelao hilalao ny solosaina, aseho ny dingana tsirairay
+      --batch               Vakio avy amin'ny stdin ny vinavina, iray isaky ny andalana, ary valio amin'ny zavatra JSON tsirairay
+      --strategy <NAME>     Paikadin'ny '--auto', 'reverse', ary 'bench': binary (raha tsy misy hafa), random, na linear
+      --games <N>           Isan'ny lalao isaky ny paikady lalaovin'ny 'bench' (raha tsy misy hafa: 1000)
+      --addr <HOST:PORT>    Adiresin'ny 'serve' sy 'connect' (raha tsy misy hafa: 127.0.0.1:7878)
+  -h, --help                Asehoy ity fanampiana ity",
+    ),
+];
+
+/// Pick the language of the messages, e.g. from `--lang`.
+///
+/// Only the first call counts: the language cannot change in the middle of
+/// a game.
+pub fn set_language(language: Language) {
+    let _ = LANGUAGE.set(language);
+}
+
+/// The language of the messages, English until [`set_language`] is called.
+pub fn language() -> Language {
+    LANGUAGE.get().copied().unwrap_or_default()
+}
+
+/// A message without placeholders, in the language of the messages.
+pub fn text(message: Message) -> &'static str {
+    language().text(message)
+}
+
+/// A message in the language of the messages, see [`Language::format`].
+pub fn format(message: Message, args: &[(&str, &dyn fmt::Display)]) -> String {
+    language().format(message, args)
+}
+
+/// A message about a count in the language of the messages, see [`Language::plural`].
+pub fn plural(message: Message, count: u64, args: &[(&str, &dyn fmt::Display)]) -> String {
+    language().plural(message, count, args)
+}
+
+/// What a guess outcome is shown as, or `?` if it has no feedback.
+pub fn outcome(outcome: GuessOutcome) -> &'static str {
+    match outcome {
+        GuessOutcome::TooSmall => text(Message::TooSmall),
+        GuessOutcome::TooBig => text(Message::TooBig),
+        GuessOutcome::Correct => text(Message::Win),
+        _ => "?",
+    }
+}
+
+/// A hint, in words.
+pub fn hint(hint: Hint) -> String {
+    match hint {
+        Hint::Even => text(Message::HintEven).to_string(),
+        Hint::Odd => text(Message::HintOdd).to_string(),
+        Hint::DivisibleBy(divisor) => format(Message::HintDivisible, &[("divisor", &divisor)]),
+        Hint::NotDivisibleBy(divisor) => format(Message::HintNotDivisible, &[("divisor", &divisor)]),
+        Hint::Prime => text(Message::HintPrime).to_string(),
+        Hint::NotPrime => text(Message::HintNotPrime).to_string(),
+        Hint::Near { guess, distance } => format(Message::HintNear, &[("distance", &distance), ("guess", &guess)]),
+        Hint::Far { guess, distance } => format(Message::HintFar, &[("distance", &distance), ("guess", &guess)]),
+    }
+}
+
+/// Why no hint was given, in words.
+pub fn hint_error(error: HintError) -> String {
+    match error {
+        HintError::Disabled => text(Message::HintsDisabled).to_string(),
+        HintError::TooEarly { misses_needed } => plural(Message::HintsTooEarly, u64::from(misses_needed), &[]),
+        HintError::NoneLeft => text(Message::NoHintsLeft).to_string(),
+        HintError::GameOver => text(Message::HintGameOver).to_string(),
+    }
+}
+
+/// How close a guess is, in words.
+pub fn heat(heat: Heat) -> &'static str {
+    text(match heat {
+        Heat::Freezing => Message::Freezing,
+        Heat::Cold => Message::Cold,
+        Heat::Warm => Message::Warm,
+        Heat::Hot => Message::Hot,
+        Heat::Burning => Message::Burning,
+    })
+}
+
+/// How a guess compares to the previous one, in words.
+pub fn trend(trend: Trend) -> &'static str {
+    text(match trend {
+        Trend::Warmer => Message::Warmer,
+        Trend::Colder => Message::Colder,
+        Trend::Same => Message::Same,
+    })
+}
+
+/// The bulls and cows of a code, in words.
+pub fn marks(marks: Marks) -> String {
+    format!(
+        "{}, {}",
+        plural(Message::Bulls, u64::from(marks.bulls), &[]),
+        plural(Message::Cows, u64::from(marks.cows), &[])
+    )
+}
+
+/// Why a guessed code is invalid, in words.
+pub fn code_error(error: &CodeError) -> String {
+    match error {
+        CodeError::Length { expected, found } => {
+            format(Message::CodeLength, &[("expected", expected), ("found", found)])
+        }
+        CodeError::NotADigit(found) => format(Message::CodeNotADigit, &[("found", found)]),
+        CodeError::Repeated(digit) => format(Message::CodeRepeated, &[("digit", digit)]),
+    }
+}
+
+/// Why a word list or a guessed word is invalid, in words.
+pub fn word_error(error: &WordError) -> String {
+    match error {
+        WordError::NoWords(letters) => format(Message::WordListEmpty, &[("letters", letters)]),
+        WordError::Length { expected, found } => {
+            format(Message::WordLength, &[("expected", expected), ("found", found)])
+        }
+        WordError::Unknown(word) => format(Message::WordUnknown, &[("word", word)]),
+    }
+}
+
+/// Why an expression cannot be evaluated, in words, after its column.
+pub fn expr_error(error: &ExprError) -> String {
+    let reason: String = match &error.kind {
+        ExprErrorKind::ExpectedOperand(Some(found)) => format(Message::ExprExpectedNumber, &[("found", found)]),
+        ExprErrorKind::ExpectedOperand(None) => text(Message::ExprMissingNumber).to_string(),
+        ExprErrorKind::ExpectedOperator(found) => format(Message::ExprExpectedOperator, &[("found", found)]),
+        ExprErrorKind::UnclosedParen => text(Message::ExprUnclosedParen).to_string(),
+        ExprErrorKind::UnknownName(name) => format(Message::ExprUnknownName, &[("name", name)]),
+        ExprErrorKind::NoLastGuess => text(Message::ExprNoLastGuess).to_string(),
+        ExprErrorKind::DivisionByZero => text(Message::ExprDivisionByZero).to_string(),
+        ExprErrorKind::Overflow => text(Message::ExprOverflow).to_string(),
+        ExprErrorKind::TooDeep => text(Message::ExprTooDeep).to_string(),
+    };
+    format(Message::ExprError, &[("column", &error.column), ("error", &reason)])
+}
+
+/// An answer of the player in a reverse game, in words.
+pub fn answer(answer: Answer) -> String {
+    let message: Message = match answer.ordering {
+        Ordering::Less => Message::AnswerHigher,
+        Ordering::Greater => Message::AnswerLower,
+        Ordering::Equal => Message::AnswerEqual,
+    };
+    format(message, &[("guess", &answer.guess)])
+}
+
+/// Which answers of a reverse game contradict each other, in words.
+pub fn contradiction(contradiction: &Contradiction) -> String {
+    let (number, answer): (usize, String) = (contradiction.number, self::answer(contradiction.answer));
+    match contradiction.earlier {
+        Some((earlier, earlier_answer)) => format(
+            Message::ContradictsAnswer,
+            &[
+                ("number", &number),
+                ("answer", &answer),
+                ("earlier", &earlier),
+                ("earlier_answer", &self::answer(earlier_answer)),
+            ],
+        ),
+        None => format(
+            Message::ContradictsRange,
+            &[
+                ("number", &number),
+                ("answer", &answer),
+                ("min", &contradiction.settings.min()),
+                ("max", &contradiction.settings.max()),
+            ],
+        ),
+    }
+}
+
+/// Why the command line is rejected, in words.
+pub fn cli_error(error: &CliError) -> String {
+    match error {
+        CliError::UnknownOption(option) => format(Message::CliUnknownOption, &[("option", option)]),
+        CliError::UnknownCommand(command) => format(Message::CliUnknownCommand, &[("command", command)]),
+        CliError::UnexpectedArgument(argument) => format(Message::CliUnexpectedArgument, &[("argument", argument)]),
+        CliError::MissingArgument(command) => format(Message::CliMissingArgument, &[("command", command)]),
+        CliError::MissingValue(option) => format(Message::CliMissingValue, &[("option", option)]),
+        CliError::InvalidValue { option, value } => {
+            format(Message::CliInvalidValue, &[("option", option), ("value", value)])
+        }
+        CliError::Settings(error) => settings_error(error),
+        CliError::Conflict(first, second) => format(Message::CliConflict, &[("first", first), ("second", second)]),
+        CliError::Requires(option, needed) => format(Message::CliRequires, &[("option", option), ("needed", needed)]),
+    }
+}
+
+/// Why settings are rejected, in words.
+pub fn settings_error(error: &SettingsError) -> String {
+    match error {
+        SettingsError::InvertedRange { min, max } => format(Message::InvertedRange, &[("min", min), ("max", max)]),
+        SettingsError::EmptyRange { min, max } => format(Message::SingleNumberRange, &[("min", min), ("max", max)]),
+        SettingsError::UnknownDifficulty(name) => format(Message::UnknownDifficulty, &[("name", name)]),
+        SettingsError::NoAttempts => text(Message::NoAttempts).to_string(),
+        SettingsError::UnknownStrategy(name) => format(Message::UnknownStrategy, &[("name", name)]),
+        SettingsError::PlayerCount(players) => format(Message::PlayerCount, &[("players", players)]),
+        SettingsError::InvalidPlayer(name) => format(Message::InvalidPlayer, &[("name", name)]),
+    }
+}
+
+/// Why the domain of a number type is rejected, in words.
+pub fn domain_error(error: &DomainError) -> String {
+    match error {
+        DomainError::UnknownType(name) => format(Message::UnknownType, &[("name", name)]),
+        DomainError::NotANumber { value, type_name } => format(Message::NotOfType, &[("value", value), ("type", type_name)]),
+        DomainError::InvalidRange { min, max } => format(Message::InvalidRange, &[("min", min), ("max", max)]),
+        DomainError::InvalidTolerance(tolerance) => format(Message::InvalidTolerance, &[("tolerance", tolerance)]),
+    }
+}
+
+/// Why a text is not valid JSON, in words.
+pub fn json_error(error: &JsonError) -> String {
+    format(Message::InvalidJson, &[("error", &error.message)])
+}
+
+/// Why a saved game cannot be resumed, in words.
+pub fn save_error(error: &SaveError) -> String {
+    match error {
+        SaveError::Io(error) => format(Message::SaveUnreadable, &[("error", error)]),
+        SaveError::Json(error) => format(Message::SaveCorrupted, &[("error", error)]),
+        SaveError::Format(message) => format(Message::SaveCorrupted, &[("error", message)]),
+    }
+}
+
+/// Why a transcript cannot be replayed, in words.
+pub fn replay_error(error: &ReplayError) -> String {
+    match error {
+        ReplayError::Io(error) => format(Message::TranscriptUnreadable, &[("error", error)]),
+        ReplayError::Json { line, error } => format(Message::TranscriptLine, &[("line", line), ("error", error)]),
+        ReplayError::Format { line, message } => format(Message::TranscriptLine, &[("line", line), ("error", message)]),
+    }
+}
+
+/// Replace every `{name}` of `text` with the value of `name` in `args`.
+///
+/// A placeholder without a value is kept as is, so that it shows up.
+fn fill(text: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut filled: String = String::with_capacity(text.len());
+    let mut rest: &str = text;
+    while let Some(start) = rest.find('{') {
+        filled.push_str(&rest[..start]);
+        let Some(length) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let placeholder: &str = &rest[start..start + length + 1];
+        match args.iter().find(|(name, _)| *name == &placeholder[1..length]) {
+            Some((_, value)) => {
+                let _ = write!(filled, "{value}");
+            }
+            None => filled.push_str(placeholder),
+        }
+        rest = &rest[start + length + 1..];
+    }
+    filled.push_str(rest);
+    filled
+}
@@ -0,0 +1,173 @@
+/***************************************************************/
+/* Multiplayer: Several players take turns on the same secret. */
+/***************************************************************/
+
+// Import libraries/modules
+use crate::game::{Game, GuessOutcome};
+use crate::settings::SettingsError;
+
+/// The fewest players of a hot-seat game.
+pub const MIN_PLAYERS: usize = 2;
+
+/// The most players of a hot-seat game.
+pub const MAX_PLAYERS: usize = 8;
+
+/// A player of a hot-seat game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Player {
+    /// The name of the player.
+    pub name: String,
+    /// The number of valid guesses made by the player.
+    pub attempts: u32,
+}
+
+/// One valid guess of a hot-seat game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    /// The index of the player who guessed.
+    pub player: usize,
+    /// The number that was guessed.
+    pub guess: u32,
+    /// How the number compared to the secret number.
+    pub outcome: GuessOutcome,
+}
+
+/// Check a list of player names: 2 to 8 distinct, non-empty names.
+pub fn validate_players(names: Vec<String>) -> Result<Vec<String>, SettingsError> {
+    let names: Vec<String> = names.into_iter().map(|name| name.trim().to_string()).collect();
+    if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&names.len()) {
+        return Err(SettingsError::PlayerCount(names.len()));
+    }
+    for (index, name) in names.iter().enumerate() {
+        if name.is_empty() || names[..index].contains(name) {
+            return Err(SettingsError::InvalidPlayer(name.clone()));
+        }
+    }
+    Ok(names)
+}
+
+/// A game where players take turns guessing the same secret number.
+///
+/// Every player gets the attempt limit of the settings for themselves.
+/// Invalid and out-of-range guesses do not pass the turn. The first player
+/// to find the secret number wins; nobody wins if every player runs out of
+/// attempts, or if the time limit of the settings, shared by every player,
+/// passes first.
+#[derive(Debug, Clone)]
+pub struct HotSeat {
+    game: Game,
+    max_attempts: Option<u32>,
+    players: Vec<Player>,
+    turns: Vec<Turn>,
+    current: usize,
+    winner: Option<usize>,
+}
+
+impl HotSeat {
+    /// Start a hot-seat game on the secret number of `game`.
+    pub fn new(game: Game, names: Vec<String>) -> Result<Self, SettingsError> {
+        let names: Vec<String> = validate_players(names)?;
+
+        // The limit applies to each player, not to the shared game
+        let max_attempts: Option<u32> = game.settings().max_attempts();
+        let settings = game.settings().with_max_attempts(None)?;
+        let game: Game = Game::with_secret(settings, game.secret()).with_lie_seed(game.lie_seed());
+
+        Ok(Self {
+            game,
+            max_attempts,
+            players: names.into_iter().map(|name| Player { name, attempts: 0 }).collect(),
+            turns: Vec::new(),
+            current: 0,
+            winner: None,
+        })
+    }
+
+    /// The shared game, with its settings and secret number.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Every player, in turn order.
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// Every valid guess, in the order they were made.
+    pub fn turns(&self) -> &[Turn] {
+        &self.turns
+    }
+
+    /// The index of the player whose turn it is.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// The player whose turn it is.
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.current]
+    }
+
+    /// The number of attempts left to the player at `index`, or `None` if they are unlimited.
+    pub fn attempts_left(&self, index: usize) -> Option<u32> {
+        self.max_attempts
+            .map(|max_attempts| max_attempts.saturating_sub(self.players[index].attempts))
+    }
+
+    /// The player who found the secret number, if any.
+    pub fn winner(&self) -> Option<&Player> {
+        self.winner.map(|index| &self.players[index])
+    }
+
+    /// Whether a player won, every player ran out of attempts, or the shared time is up.
+    pub fn is_over(&self) -> bool {
+        self.winner.is_some()
+            || self.game.is_over()
+            || (0..self.players.len()).all(|index| self.attempts_left(index) == Some(0))
+    }
+
+    /// End the game if its shared time limit has passed, and return whether it did.
+    pub fn check_clock(&mut self) -> bool {
+        self.game.check_clock()
+    }
+
+    /// Submit the raw text typed by the current player and return its outcome.
+    pub fn submit(&mut self, guess: &str) -> GuessOutcome {
+        if self.is_over() {
+            return GuessOutcome::GameOver;
+        }
+
+        let outcome: GuessOutcome = self.game.submit(guess);
+        let Some(guess) = self.game.last_guess().filter(|_| {
+            matches!(outcome, GuessOutcome::TooSmall | GuessOutcome::TooBig | GuessOutcome::Correct)
+        }) else {
+            // The same player tries again
+            return outcome;
+        };
+
+        // Count the attempt for the current player
+        self.players[self.current].attempts += 1;
+        self.turns.push(Turn {
+            player: self.current,
+            guess,
+            outcome,
+        });
+        if outcome == GuessOutcome::Correct {
+            self.winner = Some(self.current);
+        } else {
+            self.pass_turn();
+        }
+
+        outcome
+    }
+
+    /// Give the turn to the next player who still has attempts left.
+    fn pass_turn(&mut self) {
+        for _ in 0..self.players.len() {
+            self.current = (self.current + 1) % self.players.len();
+            if self.attempts_left(self.current) != Some(0) {
+                return;
+            }
+        }
+    }
+}
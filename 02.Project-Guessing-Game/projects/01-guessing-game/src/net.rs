@@ -0,0 +1,131 @@
+/****************************************************************/
+/* Net: Play over a plain line-based TCP protocol on localhost. */
+/****************************************************************/
+
+//! The protocol is the terminal game, over TCP.
+//!
+//! - Every message is a line of UTF-8 text, terminated by `\n`
+//! - The server speaks first, with the usual prompt
+//! - The client answers every prompt with one line: a guess, `quit`, or `exit`
+//! - The server answers with the usual feedback ("Too small!", "Too big!",
+//!   "You win!!!") and the next prompt
+//! - Once the game is over, the server sends the summary and closes the connection
+//!
+//! Every connection gets its own game and secret number, so any line-based
+//! client such as `nc localhost 7878` can play.
+
+// Import libraries/modules
+use crate::game::{seeded_rng, Game, SeededRng};
+use crate::input::{Input, InputSource, LineInput, TerminalInput};
+use crate::messages::{self, Message};
+use crate::session::{self, SessionEnd};
+use crate::settings::Settings;
+use std::fmt::Display;
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// The address the server listens on, unless `--addr` is given.
+pub const DEFAULT_ADDR: &str = "127.0.0.1:7878";
+
+/// How long the server waits for a line before dropping an idle connection.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// How long the server keeps reading leftover lines after the game is over.
+const LINGER_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Host games on `addr`, one per connection, until the process is stopped.
+///
+/// With a seed, the secret numbers of successive connections are drawn from
+/// the same seeded generator, so the sequence of games is reproducible.
+pub fn serve<A: ToSocketAddrs>(addr: A, settings: Settings, seed: Option<u64>) -> io::Result<()> {
+    let listener: TcpListener = TcpListener::bind(addr)?;
+    let address: SocketAddr = listener.local_addr()?;
+    eprintln!("{}", messages::format(Message::Listening, &[("address", &address)]));
+
+    let rng: Option<Arc<Mutex<SeededRng>>> = seed.map(|seed| Arc::new(Mutex::new(seeded_rng(seed))));
+    for stream in listener.incoming() {
+        let stream: TcpStream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("{}", messages::format(Message::AcceptFailed, &[("error", &err)]));
+                continue;
+            }
+        };
+
+        // Draw the secret number before handing the connection to its own thread
+        let game: Game = match &rng {
+            Some(rng) => {
+                let mut rng = rng.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                Game::from_rng(settings, &mut *rng)
+            }
+            None => Game::new(settings),
+        };
+        thread::spawn(move || {
+            let peer: String = stream
+                .peer_addr()
+                .map_or_else(|_| messages::text(Message::UnknownPeer).to_string(), |peer| peer.to_string());
+            match serve_one(stream, game) {
+                Ok((end, attempts)) => {
+                    let args: &[(&str, &dyn Display)] = &[("peer", &peer), ("end", &end.name())];
+                    eprintln!("{}", messages::plural(Message::PeerEnded, u64::from(attempts), args));
+                }
+                Err(err) => eprintln!("{}", messages::format(Message::PeerFailed, &[("peer", &peer), ("error", &err)])),
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Play one game over one connection.
+fn serve_one(stream: TcpStream, mut game: Game) -> io::Result<(SessionEnd, u32)> {
+    stream.set_read_timeout(Some(IDLE_TIMEOUT))?;
+    let mut input: LineInput<BufReader<TcpStream>> = LineInput::new(BufReader::new(stream.try_clone()?));
+    let mut output: TcpStream = stream;
+
+    let end: SessionEnd = session::play(&mut game, &mut input, &mut output)?;
+
+    // Closing with unread lines would reset the connection and lose the
+    // summary, so hang up first and drain what the client already sent
+    output.shutdown(Shutdown::Write)?;
+    output.set_read_timeout(Some(LINGER_TIMEOUT))?;
+    let _ = io::copy(&mut output.take(u64::from(u16::MAX)), &mut io::sink());
+
+    Ok((end, game.attempts()))
+}
+
+/// Play on a server from the terminal, until the server closes the connection.
+///
+/// Ctrl-C and the end of input send `quit`, so that the server still sends
+/// its summary before closing.
+pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<()> {
+    let stream: TcpStream = TcpStream::connect(addr)?;
+    let server: SocketAddr = stream.peer_addr()?;
+    eprintln!("{}", messages::format(Message::Connected, &[("address", &server)]));
+
+    // Forward the player's lines to the server in the background
+    let mut to_server: TcpStream = stream.try_clone()?;
+    let mut input: TerminalInput = TerminalInput::new()?;
+    thread::spawn(move || -> io::Result<()> {
+        loop {
+            match input.read_input()? {
+                Input::Line(line) => to_server.write_all(line.as_bytes())?,
+                Input::Eof | Input::Interrupted => return to_server.write_all(b"quit\n"),
+            }
+        }
+    });
+
+    // Print everything the server says, until it hangs up
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    for line in BufReader::new(stream).lines() {
+        writeln!(stdout, "{}", line?)?;
+        stdout.flush()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+/***********************************************************************/
+/* Proximity: Hot/cold feedback, graded by the distance to the secret. */
+/***********************************************************************/
+
+/// How close a guess is to the secret number, from farthest to closest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Heat {
+    /// Farther than the cold threshold.
+    Freezing,
+    /// Within the cold threshold.
+    Cold,
+    /// Within the warm threshold.
+    Warm,
+    /// Within the hot threshold.
+    Hot,
+    /// Within the burning threshold.
+    Burning,
+}
+
+/// How a guess compares to the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    /// Closer to the secret number than the previous guess.
+    Warmer,
+    /// Farther from the secret number than the previous guess.
+    Colder,
+    /// As far from the secret number as the previous guess.
+    Same,
+}
+
+/// The hot/cold feedback of a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Proximity {
+    /// How close the guess is.
+    pub heat: Heat,
+    /// How the guess compares to the previous one, if there was one.
+    pub trend: Option<Trend>,
+}
+
+/// The largest distance of each heat, scaled to the size of the range.
+///
+/// A guess is burning within 1% of the range, hot within 5%, warm within
+/// 15%, cold within 35%, and freezing beyond. Every threshold is at least one
+/// more than the previous one, so that small ranges keep every heat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Thresholds {
+    /// The largest burning distance.
+    pub burning: u64,
+    /// The largest hot distance.
+    pub hot: u64,
+    /// The largest warm distance.
+    pub warm: u64,
+    /// The largest cold distance.
+    pub cold: u64,
+}
+
+impl Thresholds {
+    /// The thresholds of a range holding `range_size` numbers.
+    pub fn for_range(range_size: u64) -> Self {
+        let share = |percent: u64, previous: u64| -> u64 { (range_size * percent / 100).max(previous + 1) };
+        let burning: u64 = share(1, 0);
+        let hot: u64 = share(5, burning);
+        let warm: u64 = share(15, hot);
+        let cold: u64 = share(35, warm);
+        Self {
+            burning,
+            hot,
+            warm,
+            cold,
+        }
+    }
+
+    /// The heat of a guess at `distance` from the secret number.
+    pub fn heat(&self, distance: u64) -> Heat {
+        match distance {
+            d if d <= self.burning => Heat::Burning,
+            d if d <= self.hot => Heat::Hot,
+            d if d <= self.warm => Heat::Warm,
+            d if d <= self.cold => Heat::Cold,
+            _ => Heat::Freezing,
+        }
+    }
+}
+
+/// Compare the distances of two successive guesses.
+pub fn trend(previous_distance: u64, distance: u64) -> Trend {
+    match distance.cmp(&previous_distance) {
+        std::cmp::Ordering::Less => Trend::Warmer,
+        std::cmp::Ordering::Greater => Trend::Colder,
+        std::cmp::Ordering::Equal => Trend::Same,
+    }
+}
+
+/// The hot/cold feedback of `guess`, after `previous`, in a range of `range_size` numbers.
+pub fn proximity(range_size: u64, secret: u32, guess: u32, previous: Option<u32>) -> Proximity {
+    let distance: u64 = u64::from(secret.abs_diff(guess));
+    Proximity {
+        heat: Thresholds::for_range(range_size).heat(distance),
+        trend: previous.map(|previous| trend(u64::from(secret.abs_diff(previous)), distance)),
+    }
+}
@@ -0,0 +1,197 @@
+/*********************************************************************/
+/* Reverse: The computer guesses a number the player is thinking of. */
+/*********************************************************************/
+
+// Import libraries/modules
+use crate::messages;
+use crate::settings::Settings;
+use crate::strategy::{BinarySearch, Strategy};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+
+/// One answer of the player to a guess of the computer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Answer {
+    /// The number the computer guessed.
+    pub guess: u32,
+    /// How the guess compares to the player's number, as in `guess.cmp(&secret_num)`.
+    ///
+    /// `Less` means "higher", `Greater` means "lower", `Equal` means "correct".
+    pub ordering: Ordering,
+}
+
+/// Where a bound of the remaining candidates comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoundSource {
+    /// The bound of the range itself.
+    Range,
+    /// The answer at this index.
+    Answer(usize),
+}
+
+/// An answer that no number of the range can satisfy, given the earlier answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contradiction {
+    /// The 1-based number of the answer that made the candidates empty.
+    pub number: usize,
+    /// The answer that made the candidates empty.
+    pub answer: Answer,
+    /// The earlier answer it contradicts, with its 1-based number, or
+    /// `None` if it contradicts the range itself.
+    pub earlier: Option<(usize, Answer)>,
+    /// The range the number had to be picked from.
+    pub settings: Settings,
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::contradiction(self))
+    }
+}
+
+impl Error for Contradiction {}
+
+/// Where a reverse game stands after an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReverseStatus {
+    /// The computer has not found the number yet.
+    InProgress,
+    /// The computer found the number.
+    Found(u32),
+}
+
+/// A game where the player thinks of a number and the computer guesses it.
+///
+/// The computer keeps the interval of candidates consistent with every
+/// answer, and remembers which answer set each bound. When an answer
+/// empties the interval, the answer that set the opposite bound is exactly
+/// the earlier answer it contradicts.
+pub struct ReverseGame {
+    settings: Settings,
+    strategy: Box<dyn Strategy>,
+    low: u32,
+    high: u32,
+    low_source: BoundSource,
+    high_source: BoundSource,
+    answers: Vec<Answer>,
+    pending: Option<u32>,
+    found: Option<u32>,
+}
+
+impl ReverseGame {
+    /// Start a reverse game where the computer plays a binary search.
+    pub fn new(settings: Settings) -> Self {
+        Self::with_strategy(settings, Box::new(BinarySearch))
+    }
+
+    /// Start a reverse game where the computer plays the given strategy.
+    pub fn with_strategy(settings: Settings, strategy: Box<dyn Strategy>) -> Self {
+        Self {
+            settings,
+            strategy,
+            low: settings.min(),
+            high: settings.max(),
+            low_source: BoundSource::Range,
+            high_source: BoundSource::Range,
+            answers: Vec::new(),
+            pending: None,
+            found: None,
+        }
+    }
+
+    /// The settings the game was started with.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// The guess of the computer, waiting for an answer.
+    ///
+    /// Asking again before answering returns the same guess.
+    pub fn guess(&mut self) -> u32 {
+        match self.pending {
+            Some(guess) => guess,
+            None => {
+                let guess: u32 = self.strategy.next_guess(self.low, self.high).clamp(self.low, self.high);
+                self.pending = Some(guess);
+                guess
+            }
+        }
+    }
+
+    /// Answer the pending guess with `guess.cmp(&secret_num)`.
+    ///
+    /// Returns the [`Contradiction`] if no number can satisfy every answer;
+    /// the contradictory answer is not recorded.
+    pub fn answer(&mut self, ordering: Ordering) -> Result<ReverseStatus, Contradiction> {
+        if let Some(found) = self.found {
+            return Ok(ReverseStatus::Found(found));
+        }
+        let guess: u32 = self.guess();
+        let answer: Answer = Answer { guess, ordering };
+        let index: usize = self.answers.len();
+
+        match ordering {
+            Ordering::Equal => self.found = Some(guess),
+            // The number is higher: the guess was the last candidate
+            Ordering::Less if guess == self.high => return Err(self.contradiction(answer, self.high_source)),
+            Ordering::Less => {
+                self.low = guess + 1;
+                self.low_source = BoundSource::Answer(index);
+            }
+            // The number is lower: the guess was the first candidate
+            Ordering::Greater if guess == self.low => return Err(self.contradiction(answer, self.low_source)),
+            Ordering::Greater => {
+                self.high = guess - 1;
+                self.high_source = BoundSource::Answer(index);
+            }
+        }
+        self.answers.push(answer);
+        self.pending = None;
+
+        Ok(match self.found {
+            Some(found) => ReverseStatus::Found(found),
+            None => ReverseStatus::InProgress,
+        })
+    }
+
+    /// Describe an answer that conflicts with the given bound.
+    fn contradiction(&self, answer: Answer, source: BoundSource) -> Contradiction {
+        Contradiction {
+            number: self.answers.len() + 1,
+            answer,
+            earlier: match source {
+                BoundSource::Range => None,
+                BoundSource::Answer(index) => Some((index + 1, self.answers[index])),
+            },
+            settings: self.settings,
+        }
+    }
+
+    /// The remaining candidates, as an inclusive `(low, high)` interval.
+    pub fn candidates(&self) -> (u32, u32) {
+        (self.low, self.high)
+    }
+
+    /// Every answer given so far, oldest first.
+    pub fn answers(&self) -> &[Answer] {
+        &self.answers
+    }
+
+    /// The number of guesses answered so far.
+    pub fn attempts(&self) -> u32 {
+        self.answers.len() as u32
+    }
+}
+
+/// Parse an answer of the player: `h`igher, `l`ower, or `c`orrect.
+///
+/// The result follows the semantics of `guess.cmp(&secret_num)`.
+pub fn parse_answer(line: &str) -> Option<Ordering> {
+    match line.trim().to_ascii_lowercase().as_str() {
+        "h" | "higher" | "+" => Some(Ordering::Less),
+        "l" | "lower" | "-" => Some(Ordering::Greater),
+        "c" | "correct" | "=" => Some(Ordering::Equal),
+        _ => None,
+    }
+}
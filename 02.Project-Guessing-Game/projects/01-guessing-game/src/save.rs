@@ -0,0 +1,385 @@
+/***************************************************************/
+/* Save: Keep a game in progress on disk, and resume it later. */
+/***************************************************************/
+
+//! A saved game is a single JSON object, rewritten after every line typed
+//! by the player and when the session ends:
+//!
+//! ```text
+//! {"version":1,"min":1,"max":100,"max_attempts":7,"hints_after":3,"feedback":"classic","lies":0,
+//!  "time_limit_ns":null,"secret":"9f3c0c1a6e2b44d7","lie_seed":0,"elapsed_ns":8120417385,
+//!  "guesses":[{"value":50,"outcome":"too_big","elapsed_ns":1520233614,"lie":false}],"hints":[{"kind":"odd"}]}
+//! ```
+//!
+//! The secret number is obfuscated, so that a glance at the file does not
+//! spoil the game; it is not meant to stop a determined cheater.
+
+// Import libraries/modules
+use crate::game::{Game, Guess, GuessOutcome};
+use crate::hint::Hint;
+use crate::history;
+use crate::json::{self, JsonError, Value};
+use crate::messages;
+use crate::session::{LineOutcome, Observer, SessionEnd};
+use crate::settings::{Feedback, Settings};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The name of the save file inside the data directory.
+const SAVE_FILE: &str = "save.json";
+
+/// The version written in every save.
+pub const VERSION: u64 = 1;
+
+/// The key that the secret number is mixed with.
+const SECRET_KEY: u64 = 0x5DEE_CE66_D1CE_F00D;
+
+/// The reasons why a saved game cannot be resumed.
+#[derive(Debug)]
+pub enum SaveError {
+    /// The save could not be read.
+    Io(io::Error),
+    /// The save is not valid JSON.
+    Json(JsonError),
+    /// The save is missing fields, or describes an impossible game.
+    Format(String),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::save_error(self))
+    }
+}
+
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SaveError::Io(err) => Some(err),
+            SaveError::Json(err) => Some(err),
+            SaveError::Format(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+/// The file that a game in progress is saved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveFile {
+    path: PathBuf,
+}
+
+impl SaveFile {
+    /// Use the save file at the given path.
+    pub fn at<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Use the save file inside the user's data directory.
+    pub fn open_default() -> io::Result<Self> {
+        Ok(Self::at(history::data_dir()?.join(SAVE_FILE)))
+    }
+
+    /// The path of the save file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether a game is saved.
+    pub fn exists(&self) -> bool {
+        self.path.is_file()
+    }
+
+    /// Save a game, replacing the previous save.
+    ///
+    /// The save is written next to the file and renamed over it, so that a
+    /// crash halfway through never leaves a truncated save behind.
+    pub fn save(&self, game: &Game) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let staging: PathBuf = self.path.with_extension("json.tmp");
+        fs::write(&staging, format!("{}\n", to_json(game)))?;
+        fs::rename(&staging, &self.path)
+    }
+
+    /// Resume the saved game, or `None` if no game is saved.
+    pub fn load(&self) -> Result<Option<Game>, SaveError> {
+        let text: String = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(SaveError::Io(err)),
+        };
+        let value: Value = json::parse(text.trim()).map_err(SaveError::Json)?;
+        from_json(&value).map(Some).map_err(SaveError::Format)
+    }
+
+    /// Delete the save, if there is one.
+    pub fn remove(&self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Keeps the save file in step with a session: the game is saved after
+/// every line and when the session ends early, and the save is deleted
+/// once the game is over, so that a finished game cannot be resumed.
+///
+/// Failing to save never stops the game: the last error is kept for the
+/// caller to report instead.
+#[derive(Debug)]
+pub struct Autosave {
+    file: SaveFile,
+    error: Option<io::Error>,
+}
+
+impl Autosave {
+    /// Keep a game saved to `file`.
+    pub fn new(file: SaveFile) -> Self {
+        Self { file, error: None }
+    }
+
+    /// The last error met while saving, if any.
+    pub fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+
+    /// Save the game while it is in progress, or delete the save once it is over.
+    ///
+    /// A game without any guess or hint has nothing worth saving, so a new
+    /// game only replaces an older save once it has really started.
+    fn sync(&mut self, game: &Game) {
+        let result: io::Result<()> = if game.is_over() {
+            self.file.remove()
+        } else if game.attempts() > 0 || !game.hints().is_empty() {
+            self.file.save(game)
+        } else {
+            Ok(())
+        };
+        if let Err(err) = result {
+            self.error = Some(err);
+        }
+    }
+}
+
+impl Observer for Autosave {
+    fn on_line(&mut self, _line: &str, _outcome: LineOutcome, game: &Game) -> io::Result<()> {
+        self.sync(game);
+        Ok(())
+    }
+
+    fn on_end(&mut self, _end: SessionEnd, game: &Game) -> io::Result<()> {
+        self.sync(game);
+        Ok(())
+    }
+
+    fn on_save(&mut self, game: &Game) -> io::Result<Option<PathBuf>> {
+        self.file.save(game)?;
+        Ok(Some(self.file.path().to_path_buf()))
+    }
+}
+
+/// Describe a game as the JSON object of a save.
+pub fn to_json(game: &Game) -> Value {
+    let settings: &Settings = game.settings();
+    let guesses: Vec<Value> = game
+        .guesses()
+        .iter()
+        .map(|guess| {
+            Value::object([
+                ("value", guess.value.into()),
+                ("outcome", guess.outcome.name().into()),
+                ("elapsed_ns", nanos(guess.elapsed).into()),
+                ("lie", guess.lie.into()),
+            ])
+        })
+        .collect();
+    Value::object([
+        ("version", VERSION.into()),
+        ("min", settings.min().into()),
+        ("max", settings.max().into()),
+        ("max_attempts", settings.max_attempts().into()),
+        ("hints_after", settings.hints_after().into()),
+        ("feedback", settings.feedback().name().into()),
+        ("lies", settings.lies().into()),
+        ("time_limit_ns", settings.time_limit().map(nanos).into()),
+        ("secret", obfuscate(game.secret()).into()),
+        ("lie_seed", game.lie_seed().into()),
+        ("elapsed_ns", nanos(game.elapsed()).into()),
+        ("guesses", Value::Array(guesses)),
+        ("hints", Value::Array(game.hints().iter().map(|hint| hint_to_json(*hint)).collect())),
+    ])
+}
+
+/// Resume the game described by the JSON object of a save.
+///
+/// Only games in progress are saved, so a save of a game that is over, or
+/// that breaks its own settings, is rejected.
+pub fn from_json(value: &Value) -> Result<Game, String> {
+    let version: u64 = value.get("version").and_then(Value::as_u64).ok_or("missing 'version'")?;
+    if version != VERSION {
+        return Err(format!("unsupported save version {version}"));
+    }
+
+    // Rebuild the settings in the order of the command line
+    let feedback: Feedback = match value.get("feedback").and_then(Value::as_str) {
+        Some(name) => Feedback::from_name(name).ok_or_else(|| format!("unknown feedback '{name}'"))?,
+        None => return Err("missing 'feedback'".to_string()),
+    };
+    let time_limit: Option<Duration> = optional_u64(value, "time_limit_ns")?.map(Duration::from_nanos);
+    let max_attempts: Option<u32> = optional_number(value, "max_attempts")?;
+    let settings: Settings = Settings::new(number(value, "min")?, number(value, "max")?)
+        .and_then(|settings| {
+            settings
+                .with_feedback(feedback)
+                .with_lies(number(value, "lies").unwrap_or(0))
+                .with_time_limit(time_limit)
+                .with_max_attempts(max_attempts)
+        })
+        .map_err(|err| err.to_string())?
+        .with_hints_after(optional_number(value, "hints_after")?);
+
+    let secret: u32 = value
+        .get("secret")
+        .and_then(Value::as_str)
+        .and_then(deobfuscate)
+        .filter(|secret| settings.contains(*secret))
+        .ok_or("missing or invalid 'secret'")?;
+
+    // Every guess must be a miss within the range
+    let mut guesses: Vec<Guess> = Vec::new();
+    for item in value.get("guesses").and_then(Value::as_array).ok_or("missing 'guesses'")? {
+        let outcome: GuessOutcome = item
+            .get("outcome")
+            .and_then(Value::as_str)
+            .and_then(GuessOutcome::from_name)
+            .ok_or("invalid guess outcome")?;
+        let guess: Guess = Guess {
+            value: number(item, "value")?,
+            outcome,
+            elapsed: Duration::from_nanos(item.get("elapsed_ns").and_then(Value::as_u64).unwrap_or(0)),
+            lie: item.get("lie").and_then(Value::as_bool).unwrap_or(false),
+        };
+        if !settings.contains(guess.value) || !matches!(outcome, GuessOutcome::TooSmall | GuessOutcome::TooBig) {
+            return Err(format!("the game is over, or the guess {} is impossible", guess.value));
+        }
+        guesses.push(guess);
+    }
+    if settings.max_attempts().is_some_and(|max_attempts| guesses.len() >= max_attempts as usize) {
+        return Err("the game is over: every attempt was used".to_string());
+    }
+    if guesses.iter().filter(|guess| guess.lie).count() > settings.lies() as usize {
+        return Err("more lies than the settings allow".to_string());
+    }
+
+    let mut hints: Vec<Hint> = Vec::new();
+    for item in value.get("hints").and_then(Value::as_array).unwrap_or_default() {
+        let hint: Hint = hint_from_json(item).ok_or("invalid hint")?;
+        if !hint.holds_for(secret) {
+            return Err("a hint does not hold for the secret number".to_string());
+        }
+        hints.push(hint);
+    }
+
+    // Saves that predate the seed of the lies draw them from 0
+    let elapsed: Duration = Duration::from_nanos(value.get("elapsed_ns").and_then(Value::as_u64).unwrap_or(0));
+    let lie_seed: u64 = value.get("lie_seed").and_then(Value::as_u64).unwrap_or(0);
+    Ok(Game::resume(settings, secret, guesses, hints, elapsed).with_lie_seed(lie_seed))
+}
+
+/// A duration in nanoseconds, so that a resumed clock is exact.
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+/// A required field holding a `u32`.
+fn number(value: &Value, key: &str) -> Result<u32, String> {
+    value
+        .get(key)
+        .and_then(Value::as_u64)
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or_else(|| format!("missing or invalid '{key}'"))
+}
+
+/// A field holding a `u32` or `null`.
+fn optional_number(value: &Value, key: &str) -> Result<Option<u32>, String> {
+    optional_u64(value, key)?
+        .map(|value| u32::try_from(value).map_err(|_| format!("invalid '{key}'")))
+        .transpose()
+}
+
+/// A field holding a `u64` or `null`.
+fn optional_u64(value: &Value, key: &str) -> Result<Option<u64>, String> {
+    match value.get(key) {
+        None => Err(format!("missing '{key}'")),
+        Some(Value::Null) => Ok(None),
+        Some(field) => field.as_u64().map(Some).ok_or_else(|| format!("invalid '{key}'")),
+    }
+}
+
+/// Hide the secret number from a glance at the save.
+fn obfuscate(secret: u32) -> String {
+    format!("{:016x}", (u64::from(secret) ^ SECRET_KEY).rotate_left(23))
+}
+
+/// Recover the secret number hidden by [`obfuscate`].
+fn deobfuscate(text: &str) -> Option<u32> {
+    let mixed: u64 = u64::from_str_radix(text, 16).ok()?;
+    u32::try_from(mixed.rotate_right(23) ^ SECRET_KEY).ok()
+}
+
+/// Describe a hint as a JSON object.
+fn hint_to_json(hint: Hint) -> Value {
+    match hint {
+        Hint::Even => Value::object([("kind", "even".into())]),
+        Hint::Odd => Value::object([("kind", "odd".into())]),
+        Hint::DivisibleBy(divisor) => Value::object([("kind", "divisible_by".into()), ("divisor", divisor.into())]),
+        Hint::NotDivisibleBy(divisor) => {
+            Value::object([("kind", "not_divisible_by".into()), ("divisor", divisor.into())])
+        }
+        Hint::Prime => Value::object([("kind", "prime".into())]),
+        Hint::NotPrime => Value::object([("kind", "not_prime".into())]),
+        Hint::Near { guess, distance } => Value::object([
+            ("kind", "near".into()),
+            ("guess", guess.into()),
+            ("distance", distance.into()),
+        ]),
+        Hint::Far { guess, distance } => Value::object([
+            ("kind", "far".into()),
+            ("guess", guess.into()),
+            ("distance", distance.into()),
+        ]),
+    }
+}
+
+/// Read a hint written by [`hint_to_json`].
+fn hint_from_json(value: &Value) -> Option<Hint> {
+    let field = |key: &str| number(value, key).ok();
+    match value.get("kind")?.as_str()? {
+        "even" => Some(Hint::Even),
+        "odd" => Some(Hint::Odd),
+        "divisible_by" => Some(Hint::DivisibleBy(field("divisor")?)),
+        "not_divisible_by" => Some(Hint::NotDivisibleBy(field("divisor")?)),
+        "prime" => Some(Hint::Prime),
+        "not_prime" => Some(Hint::NotPrime),
+        "near" => Some(Hint::Near {
+            guess: field("guess")?,
+            distance: field("distance")?,
+        }),
+        "far" => Some(Hint::Far {
+            guess: field("guess")?,
+            distance: field("distance")?,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,51 @@
+/**********************************************************/
+/* Score: How well a game was played, as a single number. */
+/**********************************************************/
+
+// Import libraries/modules
+use std::time::Duration;
+
+/// The time, per bit of the range, that a game can take before its speed bonus halves.
+const SECONDS_PER_BIT: f64 = 5.0;
+
+/// The share of the score kept after each hint.
+const HINT_FACTOR: f64 = 0.85;
+
+/// The number of guesses a perfect strategy needs, in the worst case, to find
+/// any number among `range_size` candidates.
+///
+/// Each guess splits the remaining candidates in two, so `k` guesses cover at
+/// most `2^k - 1` candidates: the bound is the number of bits of `range_size`.
+pub fn attempt_bound(range_size: u64) -> u32 {
+    u64::BITS - range_size.leading_zeros()
+}
+
+/// Compute the score of a won game.
+///
+/// ```text
+/// score = 100 × bits × (bits / attempts) × (1 + T / (T + seconds)) × 0.85^hints
+/// ```
+///
+/// - `bits` is the [`attempt_bound`] of the range, so bigger ranges are worth more
+/// - `bits / attempts` rewards using few guesses
+/// - `T` is 5 seconds per bit: a speed bonus of up to 2× that halves after `T`
+/// - every hint costs 15% of the score
+pub fn score(attempts: u32, range_size: u64, elapsed: Duration, hints: u32) -> u32 {
+    score_for_bound(attempts, attempt_bound(range_size), elapsed, hints)
+}
+
+/// Compute the score of a won game from the attempt bound of its range, for
+/// ranges that do not fit a `u64`, or floats.
+pub fn score_for_bound(attempts: u32, bound: u32, elapsed: Duration, hints: u32) -> u32 {
+    if attempts == 0 {
+        return 0;
+    }
+    let bits: f64 = f64::from(bound);
+    let accuracy: f64 = bits / f64::from(attempts);
+    let budget: f64 = SECONDS_PER_BIT * bits;
+    let speed: f64 = 1.0 + budget / (budget + elapsed.as_secs_f64());
+
+    let penalty: f64 = HINT_FACTOR.powi(hints as i32);
+
+    (100.0 * bits * accuracy * speed * penalty).round() as u32
+}
@@ -0,0 +1,853 @@
+/****************************************************************/
+/* Session: The prompt/response loop, for any input and output. */
+/****************************************************************/
+
+// Import libraries/modules
+use crate::bulls::{CodeGame, CodeOutcome};
+use crate::domain::Number;
+use crate::expr::ExprError;
+use crate::game::{Game, GameStatus, GuessOutcome};
+use crate::input::{Input, InputSource};
+use crate::messages::{self, Message};
+use crate::multiplayer::HotSeat;
+use crate::settings::{Feedback, Settings};
+use crate::words::{self, WordGame, WordOutcome};
+use crate::reverse::{self, ReverseGame, ReverseStatus};
+use std::cmp::Ordering;
+use std::io;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// How a session came to an end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEnd {
+    /// The player found the secret number.
+    Won,
+    /// The player used every allowed attempt.
+    Lost,
+    /// The player typed `quit` or `exit`.
+    Quit,
+    /// The input was closed before the secret number was found.
+    EndOfInput,
+    /// The player pressed Ctrl-C.
+    Interrupted,
+    /// The player gave contradictory answers in a reverse game.
+    Contradiction,
+    /// The time limit passed before the secret number was found.
+    TimeUp,
+}
+
+impl SessionEnd {
+    /// The stable `snake_case` name of the ending, as written in transcripts.
+    pub fn name(self) -> &'static str {
+        match self {
+            SessionEnd::Won => "won",
+            SessionEnd::Lost => "lost",
+            SessionEnd::Quit => "quit",
+            SessionEnd::EndOfInput => "end_of_input",
+            SessionEnd::Interrupted => "interrupted",
+            SessionEnd::Contradiction => "contradiction",
+            SessionEnd::TimeUp => "time_up",
+        }
+    }
+
+    /// The exit code of the process, distinct for every way of ending.
+    pub fn exit_code(self) -> ExitCode {
+        match self {
+            SessionEnd::Won => ExitCode::SUCCESS,
+            SessionEnd::Lost => ExitCode::from(1),
+            SessionEnd::Quit => ExitCode::from(3),
+            SessionEnd::EndOfInput => ExitCode::from(4),
+            SessionEnd::Contradiction => ExitCode::from(5),
+            SessionEnd::TimeUp => ExitCode::from(6),
+            SessionEnd::Interrupted => ExitCode::from(130),
+        }
+    }
+}
+
+/// What a session made of a line typed by the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineOutcome {
+    /// The line was submitted as a guess.
+    Guess(GuessOutcome),
+    /// The line asked for a hint, which was given.
+    Hint,
+    /// The line asked for a hint, which was refused.
+    HintRefused,
+    /// The line asked to leave the game.
+    Quit,
+    /// The line asked to save the game.
+    Save,
+}
+
+impl LineOutcome {
+    /// The stable `snake_case` name of the outcome, as written in transcripts.
+    pub fn name(self) -> &'static str {
+        match self {
+            LineOutcome::Guess(outcome) => outcome.name(),
+            LineOutcome::Hint => "hint",
+            LineOutcome::HintRefused => "hint_refused",
+            LineOutcome::Quit => "quit",
+            LineOutcome::Save => "save",
+        }
+    }
+}
+
+/// Watches a session as it is played, e.g. to record it.
+pub trait Observer {
+    /// Called after every line typed by the player, with what the session made of it.
+    fn on_line(&mut self, line: &str, outcome: LineOutcome, game: &Game) -> io::Result<()>;
+
+    /// Called once, when the session ends.
+    fn on_end(&mut self, end: SessionEnd, game: &Game) -> io::Result<()>;
+
+    /// Called when the player types `save`, and returns where the game was
+    /// saved, or `None` if this observer does not save games.
+    fn on_save(&mut self, game: &Game) -> io::Result<Option<PathBuf>> {
+        let _ = game;
+        Ok(None)
+    }
+}
+
+/// The observer that ignores everything.
+impl Observer for () {
+    fn on_line(&mut self, _line: &str, _outcome: LineOutcome, _game: &Game) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn on_end(&mut self, _end: SessionEnd, _game: &Game) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// An observer that may be missing, e.g. when there is nowhere to save.
+impl<O: Observer> Observer for Option<O> {
+    fn on_line(&mut self, line: &str, outcome: LineOutcome, game: &Game) -> io::Result<()> {
+        self.as_mut().map_or(Ok(()), |observer| observer.on_line(line, outcome, game))
+    }
+
+    fn on_end(&mut self, end: SessionEnd, game: &Game) -> io::Result<()> {
+        self.as_mut().map_or(Ok(()), |observer| observer.on_end(end, game))
+    }
+
+    fn on_save(&mut self, game: &Game) -> io::Result<Option<PathBuf>> {
+        self.as_mut().map_or(Ok(None), |observer| observer.on_save(game))
+    }
+}
+
+/// An observer that is borrowed, to be read again after the session.
+impl<O: Observer + ?Sized> Observer for &mut O {
+    fn on_line(&mut self, line: &str, outcome: LineOutcome, game: &Game) -> io::Result<()> {
+        (**self).on_line(line, outcome, game)
+    }
+
+    fn on_end(&mut self, end: SessionEnd, game: &Game) -> io::Result<()> {
+        (**self).on_end(end, game)
+    }
+
+    fn on_save(&mut self, game: &Game) -> io::Result<Option<PathBuf>> {
+        (**self).on_save(game)
+    }
+}
+
+/// Both observers, one after the other, e.g. to record a game and save it.
+impl<A: Observer, B: Observer> Observer for (A, B) {
+    fn on_line(&mut self, line: &str, outcome: LineOutcome, game: &Game) -> io::Result<()> {
+        self.0.on_line(line, outcome, game)?;
+        self.1.on_line(line, outcome, game)
+    }
+
+    fn on_end(&mut self, end: SessionEnd, game: &Game) -> io::Result<()> {
+        self.0.on_end(end, game)?;
+        self.1.on_end(end, game)
+    }
+
+    fn on_save(&mut self, game: &Game) -> io::Result<Option<PathBuf>> {
+        Ok(self.0.on_save(game)?.or(self.1.on_save(game)?))
+    }
+}
+
+/// Whether a line is a request to leave the game.
+pub fn is_quit_command(line: &str) -> bool {
+    let command: &str = line.trim();
+    command.eq_ignore_ascii_case("quit") || command.eq_ignore_ascii_case("exit")
+}
+
+/// Whether a line asks for a hint.
+pub fn is_hint_command(line: &str) -> bool {
+    line.trim().eq_ignore_ascii_case("hint")
+}
+
+/// Whether a line asks to save the game.
+pub fn is_save_command(line: &str) -> bool {
+    line.trim().eq_ignore_ascii_case("save")
+}
+
+/// Ask the game for a hint, as typed by the player.
+pub fn ask_hint(game: &mut Game) -> (LineOutcome, String) {
+    match game.hint() {
+        Ok(hint) => (LineOutcome::Hint, messages::format(Message::Hint, &[("hint", &messages::hint(hint))])),
+        Err(refusal) => (LineOutcome::HintRefused, messages::hint_error(refusal)),
+    }
+}
+
+/// Play a game until it is over or the player leaves, and return how it ended.
+///
+/// Every prompt and response is written to `out`, followed by a summary
+/// that reveals the secret number and the score.
+pub fn play<I, W>(game: &mut Game, input: &mut I, out: &mut W) -> io::Result<SessionEnd>
+where
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+{
+    play_observed(game, input, out, &mut ())
+}
+
+/// Play a game like [`play`], and report every line and the ending to `observer`.
+pub fn play_observed<I, W, O>(game: &mut Game, input: &mut I, out: &mut W, observer: &mut O) -> io::Result<SessionEnd>
+where
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+    O: Observer + ?Sized,
+{
+    if game.settings().lies() > 0 {
+        writeln!(out, "{}", messages::format(Message::Lies, &[("lies", &game.settings().lies())]))?;
+    }
+    let end: SessionEnd = loop {
+        // Prompt the player to enter a guess, with the time left if it is limited
+        let (min, max): (u32, u32) = (game.settings().min(), game.settings().max());
+        write!(out, "{}", messages::format(Message::Prompt, &[("min", &min), ("max", &max)]))?;
+        match game.time_left() {
+            Some(left) => {
+                let seconds: String = format!("{:.1}", left.as_secs_f64());
+                writeln!(out, " {}", messages::format(Message::TimeLeft, &[("seconds", &seconds)]))?
+            }
+            None => writeln!(out)?,
+        }
+        out.flush()?;
+
+        // Process the user input, unless the time runs out first
+        let input: Option<Input> = match game.time_left() {
+            Some(left) => input.read_input_timeout(left)?,
+            None => Some(input.read_input()?),
+        };
+        let guess: String = match input {
+            Some(Input::Line(line)) => line,
+            Some(Input::Eof) => break SessionEnd::EndOfInput,
+            Some(Input::Interrupted) => break SessionEnd::Interrupted,
+            None => {
+                game.check_clock();
+                writeln!(out, "{}", messages::text(Message::TimeUp))?;
+                break SessionEnd::TimeUp;
+            }
+        };
+        if is_quit_command(&guess) {
+            observer.on_line(&guess, LineOutcome::Quit, game)?;
+            break SessionEnd::Quit;
+        }
+        if is_hint_command(&guess) {
+            let (outcome, message): (LineOutcome, String) = ask_hint(game);
+            observer.on_line(&guess, outcome, game)?;
+            writeln!(out, "{message}")?;
+            continue;
+        }
+        if is_save_command(&guess) {
+            match observer.on_save(game) {
+                Ok(Some(path)) => writeln!(out, "{}", messages::format(Message::Saved, &[("path", &path.display())]))?,
+                Ok(None) => writeln!(out, "{}", messages::text(Message::CannotSave))?,
+                Err(err) => writeln!(out, "{}", messages::format(Message::SaveFailed, &[("error", &err)]))?,
+            }
+            observer.on_line(&guess, LineOutcome::Save, game)?;
+            continue;
+        }
+
+        // A guess typed too late does not count
+        if game.check_clock() {
+            writeln!(out, "{}", messages::text(Message::TimeUp))?;
+            break SessionEnd::TimeUp;
+        }
+
+        // Let the engine judge the guess, evaluated before it moves 'last'
+        let value: Result<i64, ExprError> = game.evaluate(&guess);
+        let outcome: GuessOutcome = game.submit(&guess);
+        observer.on_line(&guess, LineOutcome::Guess(outcome), game)?;
+
+        // Confirm user's guess, or point at what makes it invalid
+        match &value {
+            Ok(value) => {
+                let guess: String = describe_guess(&guess, *value);
+                writeln!(out, "{}", messages::format(Message::Guessed, &[("guess", &guess)]))?
+            }
+            Err(error) => write_invalid(&guess, error, out)?,
+        }
+
+        // Indicate whether the guess is too low, too high, or correct
+        match outcome {
+            GuessOutcome::Invalid => {}
+            GuessOutcome::OutOfRange => writeln!(out, "{}", messages::text(Message::OutOfRange))?,
+            GuessOutcome::TooSmall | GuessOutcome::TooBig => write_feedback(game, outcome, out)?,
+            GuessOutcome::Correct => {
+                // Print a congratulatory message and exit
+                writeln!(out, "{}", messages::text(Message::Win))?;
+                break SessionEnd::Won;
+            }
+            GuessOutcome::GameOver => {}
+        }
+
+        // Stop once every allowed attempt or the time is used
+        if game.is_out_of_time() {
+            writeln!(out, "{}", messages::text(Message::TimeUp))?;
+            break SessionEnd::TimeUp;
+        }
+        if game.status() == GameStatus::Lost {
+            writeln!(out, "{}", messages::text(Message::Lose))?;
+            break SessionEnd::Lost;
+        }
+        if let (Some(left), GuessOutcome::TooSmall | GuessOutcome::TooBig) = (game.attempts_left(), outcome) {
+            writeln!(out, "{}", messages::plural(Message::AttemptsLeft, u64::from(left), &[]))?;
+        }
+        if matches!(outcome, GuessOutcome::TooSmall | GuessOutcome::TooBig)
+            && game.settings().hints_after() == Some(game.attempts())
+            && game.settings().lies() == 0
+        {
+            writeln!(out, "{}", messages::text(Message::Stuck))?;
+        }
+    };
+
+    observer.on_end(end, game)?;
+    write_summary(game, end, out)?;
+    out.flush()?;
+
+    Ok(end)
+}
+
+/// Show a guess as typed, followed by its value if it was an expression.
+fn describe_guess(guess: &str, value: i64) -> String {
+    let guess: &str = guess.trim();
+    match guess.parse::<i64>() {
+        Ok(number) if number == value => guess.to_string(),
+        _ => format!("{guess} = {value}"),
+    }
+}
+
+/// Explain why a guess is invalid, with a caret under the offending column.
+fn write_invalid<W>(guess: &str, error: &ExprError, out: &mut W) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    writeln!(out, "{}", messages::text(Message::InvalidNumber))?;
+    writeln!(out, "  {}", guess.trim())?;
+    writeln!(out, "  {:>width$} {error}", "^", width = error.column)
+}
+
+/// Tell the player how a wrong guess compares to the secret number.
+pub fn write_feedback<W>(game: &Game, outcome: GuessOutcome, out: &mut W) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    match (game.settings().feedback(), game.proximity()) {
+        (Feedback::HotCold, Some(proximity)) => {
+            writeln!(out, "{}", messages::heat(proximity.heat))?;
+            match proximity.trend {
+                Some(trend) => writeln!(out, "{}", messages::trend(trend)),
+                None => Ok(()),
+            }
+        }
+        _ if outcome == GuessOutcome::TooSmall => writeln!(out, "{}", messages::text(Message::TooSmall)),
+        _ => writeln!(out, "{}", messages::text(Message::TooBig)),
+    }
+}
+
+/// Print the end-of-game summary: the secret number, the attempts, and the score.
+pub fn write_summary<W>(game: &Game, end: SessionEnd, out: &mut W) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    writeln!(out)?;
+    if end != SessionEnd::Won {
+        writeln!(out, "{}", messages::format(Message::Goodbye, &[("secret", &game.secret())]))?;
+    }
+    write_attempts(game.attempts(), game.elapsed(), out)?;
+    if game.settings().lies() > 0 {
+        write_lies(game, out)?;
+    }
+    if game.settings().time_limit().is_some() && game.attempts() > 0 {
+        write_split_times(game, out)?;
+    }
+    writeln!(out, "{}", messages::format(Message::Score, &[("score", &game.score())]))
+}
+
+/// Print how many attempts a game took, and how long.
+fn write_attempts<W>(attempts: u32, elapsed: Duration, out: &mut W) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    let seconds: String = format!("{:.1}", elapsed.as_secs_f64());
+    writeln!(out, "{}", messages::plural(Message::Summary, u64::from(attempts), &[("seconds", &seconds)]))
+}
+
+/// Reveal the lies told by a game that may lie.
+fn write_lies<T, W>(game: &Game<T>, out: &mut W) -> io::Result<()>
+where
+    T: Number,
+    W: Write + ?Sized,
+{
+    let lies: Vec<String> = game
+        .guesses()
+        .iter()
+        .filter(|guess| guess.lie)
+        .map(|guess| guess.value.to_string())
+        .collect();
+    match lies.as_slice() {
+        [] => writeln!(out, "{}", messages::text(Message::NeverLied)),
+        _ => {
+            let guesses: String = lies.join(", ");
+            writeln!(out, "{}", messages::plural(Message::Lied, lies.len() as u64, &[("guesses", &guesses)]))
+        }
+    }
+}
+
+/// Print the time taken by every guess of a timed game.
+fn write_split_times<T, W>(game: &Game<T>, out: &mut W) -> io::Result<()>
+where
+    T: Number,
+    W: Write + ?Sized,
+{
+    let mut previous: Duration = Duration::ZERO;
+    let splits: Vec<String> = game
+        .guesses()
+        .iter()
+        .map(|guess| {
+            let split: Duration = guess.elapsed - previous;
+            previous = guess.elapsed;
+            let seconds: String = format!("{:.1}", split.as_secs_f64());
+            messages::format(Message::Split, &[("guess", &guess.value), ("seconds", &seconds)])
+        })
+        .collect();
+    writeln!(out, "{}", messages::format(Message::SplitTimes, &[("splits", &splits.join(", "))]))
+}
+
+/// Play bulls and cows until the code is found, the attempts run out, or
+/// the player leaves, and return how it ended.
+pub fn play_bulls<I, W>(game: &mut CodeGame, input: &mut I, out: &mut W) -> io::Result<SessionEnd>
+where
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+{
+    let end: SessionEnd = loop {
+        // Prompt the player to enter a code
+        writeln!(out, "{}", messages::format(Message::PromptCode, &[("digits", &game.length())]))?;
+        out.flush()?;
+
+        let guess: String = match input.read_input()? {
+            Input::Line(line) => line,
+            Input::Eof => break SessionEnd::EndOfInput,
+            Input::Interrupted => break SessionEnd::Interrupted,
+        };
+        if is_quit_command(&guess) {
+            break SessionEnd::Quit;
+        }
+
+        // Let the engine mark the code
+        match game.submit(&guess) {
+            CodeOutcome::Invalid(error) => {
+                writeln!(out, "{}", messages::format(Message::InvalidCode, &[("error", &error)]))?;
+                continue;
+            }
+            CodeOutcome::Marked(marks) => {
+                writeln!(out, "{}", messages::format(Message::Guessed, &[("guess", &guess.trim())]))?;
+                if game.is_won() {
+                    writeln!(out, "{}", messages::text(Message::Win))?;
+                    break SessionEnd::Won;
+                }
+                writeln!(out, "{}", messages::marks(marks))?;
+            }
+            CodeOutcome::GameOver => {}
+        }
+
+        // Stop once every allowed attempt is used
+        if game.is_over() {
+            writeln!(out, "{}", messages::text(Message::Lose))?;
+            break SessionEnd::Lost;
+        }
+        if let Some(left) = game.attempts_left() {
+            writeln!(out, "{}", messages::plural(Message::AttemptsLeft, u64::from(left), &[]))?;
+        }
+    };
+
+    // Sum the game up, as for numbers
+    writeln!(out)?;
+    if end != SessionEnd::Won {
+        writeln!(out, "{}", messages::format(Message::GoodbyeCode, &[("secret", &game.secret())]))?;
+    }
+    write_attempts(game.attempts(), game.elapsed(), out)?;
+    writeln!(out, "{}", messages::format(Message::Score, &[("score", &game.score())]))?;
+    out.flush()?;
+
+    Ok(end)
+}
+
+/// Play the word mode until the word is found, the attempts run out, or
+/// the player leaves, and return how it ended.
+///
+/// Marked guesses are drawn as coloured tiles when `colour` is set, and
+/// with plain-text markers otherwise.
+pub fn play_words<I, W>(game: &mut WordGame, input: &mut I, out: &mut W, colour: bool) -> io::Result<SessionEnd>
+where
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+{
+    if !colour {
+        writeln!(out, "{}", messages::text(Message::WordLegend))?;
+    }
+    let end: SessionEnd = loop {
+        // Prompt the player to enter a word
+        writeln!(out, "{}", messages::format(Message::PromptWord, &[("letters", &game.letters())]))?;
+        out.flush()?;
+
+        let guess: String = match input.read_input()? {
+            Input::Line(line) => line,
+            Input::Eof => break SessionEnd::EndOfInput,
+            Input::Interrupted => break SessionEnd::Interrupted,
+        };
+        if is_quit_command(&guess) {
+            break SessionEnd::Quit;
+        }
+
+        // Let the engine mark the word
+        match game.submit(&guess) {
+            WordOutcome::Invalid(error) => {
+                writeln!(out, "{}", messages::format(Message::InvalidWord, &[("error", &error)]))?;
+                continue;
+            }
+            WordOutcome::Marked(marks) => {
+                writeln!(out, "{}", words::render(&words::normalize(&guess), &marks, colour))?;
+                if game.is_won() {
+                    writeln!(out, "{}", messages::text(Message::Win))?;
+                    break SessionEnd::Won;
+                }
+            }
+            WordOutcome::GameOver => {}
+        }
+
+        // Stop once every allowed attempt is used
+        if game.is_over() {
+            writeln!(out, "{}", messages::text(Message::Lose))?;
+            break SessionEnd::Lost;
+        }
+        if let Some(left) = game.attempts_left() {
+            writeln!(out, "{}", messages::plural(Message::AttemptsLeft, u64::from(left), &[]))?;
+        }
+    };
+
+    // Sum the game up, as for numbers
+    writeln!(out)?;
+    if end != SessionEnd::Won {
+        writeln!(out, "{}", messages::format(Message::GoodbyeWord, &[("secret", &game.secret())]))?;
+    }
+    write_attempts(game.attempts(), game.elapsed(), out)?;
+    writeln!(out, "{}", messages::format(Message::Score, &[("score", &game.score())]))?;
+    out.flush()?;
+
+    Ok(end)
+}
+
+/// Play a game over any [`Number`] type until it is over or the player
+/// leaves, and return how it ended.
+///
+/// This is the game of [`play`] without the features built on `u32`:
+/// hints, hot/cold feedback, saves, and transcripts.
+pub fn play_numbers<T, I, W>(game: &mut Game<T>, input: &mut I, out: &mut W) -> io::Result<SessionEnd>
+where
+    T: Number,
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+{
+    let settings: Settings<T> = *game.settings();
+    if settings.lies() > 0 {
+        writeln!(out, "{}", messages::format(Message::Lies, &[("lies", &settings.lies())]))?;
+    }
+    let end: SessionEnd = loop {
+        // Prompt the player to enter a guess, how close it must be, and the time left
+        let (min, max): (T, T) = (settings.min(), settings.max());
+        write!(out, "{}", messages::format(Message::PromptTyped, &[("type", &T::NAME), ("min", &min), ("max", &max)]))?;
+        if !settings.is_exact() {
+            write!(out, " {}", messages::format(Message::Within, &[("tolerance", &settings.tolerance())]))?;
+        }
+        if let Some(left) = game.time_left() {
+            let seconds: String = format!("{:.1}", left.as_secs_f64());
+            write!(out, " {}", messages::format(Message::TimeLeft, &[("seconds", &seconds)]))?;
+        }
+        writeln!(out)?;
+        out.flush()?;
+
+        // Process the user input, unless the time runs out first
+        let input: Option<Input> = match game.time_left() {
+            Some(left) => input.read_input_timeout(left)?,
+            None => Some(input.read_input()?),
+        };
+        let guess: String = match input {
+            Some(Input::Line(line)) => line,
+            Some(Input::Eof) => break SessionEnd::EndOfInput,
+            Some(Input::Interrupted) => break SessionEnd::Interrupted,
+            None => {
+                game.check_clock();
+                writeln!(out, "{}", messages::text(Message::TimeUp))?;
+                break SessionEnd::TimeUp;
+            }
+        };
+        if is_quit_command(&guess) {
+            break SessionEnd::Quit;
+        }
+        if game.check_clock() {
+            writeln!(out, "{}", messages::text(Message::TimeUp))?;
+            break SessionEnd::TimeUp;
+        }
+
+        // Let the engine judge the guess, parsed as the type of the game
+        let outcome: GuessOutcome = game.submit(&guess);
+        match outcome {
+            GuessOutcome::Invalid => {
+                writeln!(out, "{}", messages::format(Message::InvalidTyped, &[("type", &T::NAME)]))?
+            }
+            GuessOutcome::OutOfRange => writeln!(out, "{}", messages::text(Message::OutOfRange))?,
+            GuessOutcome::GameOver => {}
+            _ => writeln!(out, "{}", messages::format(Message::Guessed, &[("guess", &guess.trim())]))?,
+        }
+        match outcome {
+            GuessOutcome::TooSmall => writeln!(out, "{}", messages::text(Message::TooSmall))?,
+            GuessOutcome::TooBig => writeln!(out, "{}", messages::text(Message::TooBig))?,
+            GuessOutcome::Correct => {
+                writeln!(out, "{}", messages::text(Message::Win))?;
+                break SessionEnd::Won;
+            }
+            _ => {}
+        }
+
+        // Stop once every allowed attempt or the time is used
+        if game.is_out_of_time() {
+            writeln!(out, "{}", messages::text(Message::TimeUp))?;
+            break SessionEnd::TimeUp;
+        }
+        if game.is_over() {
+            writeln!(out, "{}", messages::text(Message::Lose))?;
+            break SessionEnd::Lost;
+        }
+        if let (Some(left), GuessOutcome::TooSmall | GuessOutcome::TooBig) = (game.attempts_left(), outcome) {
+            writeln!(out, "{}", messages::plural(Message::AttemptsLeft, u64::from(left), &[]))?;
+        }
+    };
+
+    // Sum the game up, with the exact number when close enough was enough
+    writeln!(out)?;
+    match (end, settings.is_exact()) {
+        (SessionEnd::Won, true) => {}
+        (SessionEnd::Won, false) => {
+            writeln!(out, "{}", messages::format(Message::ExactSecret, &[("secret", &game.secret())]))?
+        }
+        _ => writeln!(out, "{}", messages::format(Message::Goodbye, &[("secret", &game.secret())]))?,
+    }
+    write_attempts(game.attempts(), game.elapsed(), out)?;
+    if settings.lies() > 0 {
+        write_lies(game, out)?;
+    }
+    if settings.time_limit().is_some() && game.attempts() > 0 {
+        write_split_times(game, out)?;
+    }
+    writeln!(out, "{}", messages::format(Message::Score, &[("score", &game.score())]))?;
+    out.flush()?;
+
+    Ok(end)
+}
+
+/// Let the computer guess the player's number until it is found, the
+/// answers contradict each other, or the player leaves.
+pub fn play_reverse<I, W>(game: &mut ReverseGame, input: &mut I, out: &mut W) -> io::Result<SessionEnd>
+where
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+{
+    let (min, max): (u32, u32) = (game.settings().min(), game.settings().max());
+    writeln!(out, "{}", messages::format(Message::ReverseIntro, &[("min", &min), ("max", &max)]))?;
+
+    let end: SessionEnd = loop {
+        // Ask the player about the next guess
+        let guess: u32 = game.guess();
+        writeln!(out, "{}", messages::format(Message::ReverseAsk, &[("guess", &guess)]))?;
+        out.flush()?;
+
+        let line: String = match input.read_input()? {
+            Input::Line(line) => line,
+            Input::Eof => break SessionEnd::EndOfInput,
+            Input::Interrupted => break SessionEnd::Interrupted,
+        };
+        if is_quit_command(&line) {
+            break SessionEnd::Quit;
+        }
+        let Some(ordering) = reverse::parse_answer(&line) else {
+            writeln!(out, "{}", messages::text(Message::ReverseAnswer))?;
+            continue;
+        };
+
+        // Narrow the candidates, unless the answer is impossible
+        match game.answer(ordering) {
+            Ok(ReverseStatus::Found(number)) => {
+                writeln!(out, "{}", messages::format(Message::ReverseFound, &[("number", &number)]))?;
+                break SessionEnd::Won;
+            }
+            Ok(ReverseStatus::InProgress) => {}
+            Err(contradiction) => {
+                writeln!(out, "{}", messages::format(Message::Cheater, &[("contradiction", &contradiction)]))?;
+                break SessionEnd::Contradiction;
+            }
+        }
+        if ordering != Ordering::Equal {
+            let (low, high): (u32, u32) = game.candidates();
+            writeln!(out, "{}", messages::format(Message::ReverseBetween, &[("low", &low), ("high", &high)]))?;
+        }
+    };
+
+    writeln!(out)?;
+    writeln!(out, "{}", messages::plural(Message::ReverseSummary, u64::from(game.attempts()), &[]))?;
+    out.flush()?;
+
+    Ok(end)
+}
+
+/// Let the players of a hot-seat game take turns until one of them wins,
+/// everyone runs out of attempts or time, or they leave.
+///
+/// The game ends with a scoreboard of every turn and every player.
+pub fn play_hot_seat<I, W>(hot_seat: &mut HotSeat, input: &mut I, out: &mut W) -> io::Result<SessionEnd>
+where
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+{
+    let end: SessionEnd = loop {
+        if hot_seat.is_over() {
+            break match hot_seat.winner() {
+                Some(_) => SessionEnd::Won,
+                None => SessionEnd::Lost,
+            };
+        }
+
+        // Prompt the current player to enter a guess, with the time left if it is limited
+        let settings: Settings = *hot_seat.game().settings();
+        let name: &str = &hot_seat.current_player().name;
+        let (min, max): (u32, u32) = (settings.min(), settings.max());
+        write!(out, "{}", messages::format(Message::PromptPlayer, &[("name", &name), ("min", &min), ("max", &max)]))?;
+        match hot_seat.game().time_left() {
+            Some(left) => {
+                let seconds: String = format!("{:.1}", left.as_secs_f64());
+                writeln!(out, " {}", messages::format(Message::TimeLeft, &[("seconds", &seconds)]))?
+            }
+            None => writeln!(out)?,
+        }
+        out.flush()?;
+
+        // The clock is shared, so it runs out for every player at once
+        let input: Option<Input> = match hot_seat.game().time_left() {
+            Some(left) => input.read_input_timeout(left)?,
+            None => Some(input.read_input()?),
+        };
+        let guess: String = match input {
+            Some(Input::Line(line)) => line,
+            Some(Input::Eof) => break SessionEnd::EndOfInput,
+            Some(Input::Interrupted) => break SessionEnd::Interrupted,
+            None => {
+                hot_seat.check_clock();
+                writeln!(out, "{}", messages::text(Message::TimeUp))?;
+                break SessionEnd::TimeUp;
+            }
+        };
+        if is_quit_command(&guess) {
+            break SessionEnd::Quit;
+        }
+        if hot_seat.check_clock() {
+            writeln!(out, "{}", messages::text(Message::TimeUp))?;
+            break SessionEnd::TimeUp;
+        }
+
+        // Hints and saves belong to a single player, so they are refused without passing the turn
+        if is_hint_command(&guess) || is_save_command(&guess) {
+            writeln!(out, "{}", messages::text(Message::HotSeatCommand))?;
+            continue;
+        }
+
+        // Let the engine judge the guess, which may pass the turn
+        let current: usize = hot_seat.current();
+        let name: String = hot_seat.current_player().name.clone();
+        let value: Result<i64, ExprError> = hot_seat.game().evaluate(&guess);
+        let outcome: GuessOutcome = hot_seat.submit(&guess);
+        match &value {
+            Ok(value) => {
+                let guess: String = describe_guess(&guess, *value);
+                writeln!(out, "{}", messages::format(Message::PlayerGuessed, &[("name", &name), ("guess", &guess)]))?
+            }
+            Err(error) => write_invalid(&guess, error, out)?,
+        }
+        match outcome {
+            GuessOutcome::Invalid => {}
+            GuessOutcome::OutOfRange => writeln!(out, "{}", messages::text(Message::OutOfRange))?,
+            GuessOutcome::TooSmall | GuessOutcome::TooBig => write_feedback(hot_seat.game(), outcome, out)?,
+            GuessOutcome::Correct => writeln!(out, "{}", messages::format(Message::PlayerWins, &[("name", &name)]))?,
+            GuessOutcome::GameOver => {}
+        }
+        if hot_seat.game().is_out_of_time() {
+            writeln!(out, "{}", messages::text(Message::TimeUp))?;
+            break SessionEnd::TimeUp;
+        }
+        let left: Option<u32> = hot_seat.attempts_left(current);
+        if let (Some(left), GuessOutcome::TooSmall | GuessOutcome::TooBig) = (left, outcome) {
+            writeln!(out, "{}", messages::plural(Message::PlayerAttemptsLeft, u64::from(left), &[("name", &name)]))?;
+        }
+    };
+
+    write_scoreboard(hot_seat, out)?;
+    out.flush()?;
+
+    Ok(end)
+}
+
+/// Print the final scoreboard of a hot-seat game: every turn, then every player.
+fn write_scoreboard<W>(hot_seat: &HotSeat, out: &mut W) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    writeln!(out)?;
+    match hot_seat.winner() {
+        Some(winner) => writeln!(out, "{}", messages::format(Message::Winner, &[("name", &winner.name)]))?,
+        None => writeln!(out, "{}", messages::format(Message::NobodyWon, &[("secret", &hot_seat.game().secret())]))?,
+    }
+
+    writeln!(out)?;
+    writeln!(out, "{}", messages::text(Message::Turns))?;
+    for (number, turn) in hot_seat.turns().iter().enumerate() {
+        let feedback: &str = match turn.outcome {
+            GuessOutcome::TooSmall => messages::text(Message::TooSmall),
+            GuessOutcome::TooBig => messages::text(Message::TooBig),
+            _ => messages::text(Message::Correct),
+        };
+        writeln!(
+            out,
+            "{:>4}. {:<16} {:>7}  {feedback}",
+            number + 1,
+            hot_seat.players()[turn.player].name,
+            turn.guess
+        )?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "{}", messages::text(Message::Players))?;
+    for player in hot_seat.players() {
+        let attempts: String = messages::plural(Message::PlayerAttempts, u64::from(player.attempts), &[]);
+        match hot_seat.winner() == Some(player) {
+            true => writeln!(out, "{:<16} {attempts}  {}", player.name, messages::text(Message::WinnerTag))?,
+            false => writeln!(out, "{:<16} {attempts}", player.name)?,
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,326 @@
+/***************************************************************/
+/* Settings: The range of the secret and the allowed attempts. */
+/***************************************************************/
+
+// Import libraries/modules
+use crate::domain::{DomainError, Number};
+use crate::lies;
+use crate::messages;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The number of misses after which hints are offered, by default.
+pub const DEFAULT_HINTS_AFTER: u32 = 3;
+
+/// A preset range of numbers, from easiest to hardest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    /// Numbers between 1 and 10.
+    Easy,
+    /// Numbers between 1 and 100, as in the original game.
+    #[default]
+    Normal,
+    /// Numbers between 1 and 1,000.
+    Hard,
+    /// Numbers between 1 and 1,000,000.
+    Insane,
+}
+
+impl Difficulty {
+    /// Every difficulty, from easiest to hardest.
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Insane,
+    ];
+
+    /// The inclusive `(min, max)` range of the difficulty.
+    pub fn range(self) -> (u32, u32) {
+        match self {
+            Difficulty::Easy => (1, 10),
+            Difficulty::Normal => (1, 100),
+            Difficulty::Hard => (1, 1_000),
+            Difficulty::Insane => (1, 1_000_000),
+        }
+    }
+
+    /// The lowercase name of the difficulty, as typed on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Insane => "insane",
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = SettingsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| SettingsError::UnknownDifficulty(s.to_string()))
+    }
+}
+
+/// The kind of feedback given after a wrong guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Feedback {
+    /// "Too small!" or "Too big!", as in the original game.
+    #[default]
+    Classic,
+    /// How close the guess is ("freezing" to "burning"), and whether it is
+    /// warmer or colder than the previous one.
+    HotCold,
+}
+
+impl Feedback {
+    /// Every kind of feedback.
+    pub const ALL: [Feedback; 2] = [Feedback::Classic, Feedback::HotCold];
+
+    /// The stable `snake_case` name of the feedback, as written in saves and transcripts.
+    pub fn name(self) -> &'static str {
+        match self {
+            Feedback::Classic => "classic",
+            Feedback::HotCold => "hot_cold",
+        }
+    }
+
+    /// The feedback with the given [`name`](Feedback::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Feedback::ALL.into_iter().find(|feedback| feedback.name() == name)
+    }
+}
+
+/// The reasons why a set of settings can be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingsError {
+    /// The minimum is bigger than the maximum.
+    InvertedRange { min: u32, max: u32 },
+    /// The range holds a single number, so there is nothing to guess.
+    EmptyRange { min: u32, max: u32 },
+    /// The difficulty name is not one of the presets.
+    UnknownDifficulty(String),
+    /// A game must allow at least one attempt.
+    NoAttempts,
+    /// The strategy name is not one of the built-in strategies.
+    UnknownStrategy(String),
+    /// A hot-seat game needs between 2 and 8 players.
+    PlayerCount(usize),
+    /// A player name is empty or used twice.
+    InvalidPlayer(String),
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::settings_error(self))
+    }
+}
+
+impl Error for SettingsError {}
+
+/// The validated settings of a game.
+///
+/// This is the single source of truth for the range: the secret number,
+/// the prompt, and the range check all read from it. The classic game
+/// draws a `u32`; other [`Number`] types are picked with `--type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settings<T = u32> {
+    min: T,
+    max: T,
+    tolerance: T,
+    max_attempts: Option<u32>,
+    hints_after: Option<u32>,
+    feedback: Feedback,
+    time_limit: Option<Duration>,
+    lies: u32,
+}
+
+impl Settings {
+    /// Create settings for the inclusive range `min..=max`.
+    ///
+    /// The range must hold at least two numbers. The number of attempts
+    /// defaults to the [`attempt_bound`](Settings::attempt_bound) of the range.
+    pub fn new(min: u32, max: u32) -> Result<Self, SettingsError> {
+        if min > max {
+            return Err(SettingsError::InvertedRange { min, max });
+        }
+        if min == max {
+            return Err(SettingsError::EmptyRange { min, max });
+        }
+        Ok(Self::new_unchecked(min, max, 0))
+    }
+
+    /// Create the settings of a difficulty preset.
+    pub fn from_difficulty(difficulty: Difficulty) -> Self {
+        let (min, max): (u32, u32) = difficulty.range();
+        Self::new_unchecked(min, max, 0)
+    }
+
+    /// The number of candidates in the range.
+    pub fn size(&self) -> u64 {
+        u64::from(self.max - self.min) + 1
+    }
+
+    /// The difficulty preset matching this range, if any.
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.range() == (self.min, self.max))
+    }
+}
+
+impl<T: Number> Settings<T> {
+    /// Create settings for the inclusive range `min..=max` of any [`Number`]
+    /// type, where a guess wins within `tolerance` of the secret number.
+    ///
+    /// The number of attempts defaults to the [`attempt_bound`](Settings::attempt_bound) of the range.
+    pub fn new_typed(min: T, max: T, tolerance: T) -> Result<Self, DomainError> {
+        if !T::is_valid_range(min, max) {
+            return Err(DomainError::InvalidRange {
+                min: min.to_string(),
+                max: max.to_string(),
+            });
+        }
+        if !tolerance.is_valid_tolerance() {
+            return Err(DomainError::InvalidTolerance(tolerance.to_string()));
+        }
+        Ok(Self::new_unchecked(min, max, tolerance))
+    }
+
+    /// Create settings for a range and tolerance that are known to be valid.
+    fn new_unchecked(min: T, max: T, tolerance: T) -> Self {
+        Self {
+            min,
+            max,
+            tolerance,
+            max_attempts: Some(T::attempt_bound(min, max, tolerance)),
+            hints_after: Some(DEFAULT_HINTS_AFTER),
+            feedback: Feedback::Classic,
+            time_limit: None,
+            lies: 0,
+        }
+    }
+
+    /// Change the maximum number of attempts, or remove the limit with `None`.
+    pub fn with_max_attempts(mut self, max_attempts: Option<u32>) -> Result<Self, SettingsError> {
+        if max_attempts == Some(0) {
+            return Err(SettingsError::NoAttempts);
+        }
+        self.max_attempts = max_attempts;
+        Ok(self)
+    }
+
+    /// The smallest number that can be picked as the secret number.
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// The largest number that can be picked as the secret number.
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// How close a guess must be to the secret number to win.
+    pub fn tolerance(&self) -> T {
+        self.tolerance
+    }
+
+    /// Whether only the secret number itself wins.
+    pub fn is_exact(&self) -> bool {
+        self.tolerance == T::ZERO
+    }
+
+    /// Change the number of misses after which hints are offered, or turn them off with `None`.
+    pub fn with_hints_after(mut self, hints_after: Option<u32>) -> Self {
+        self.hints_after = hints_after;
+        self
+    }
+
+    /// Change the kind of feedback.
+    ///
+    /// Hot/cold feedback tells less than one bit per guess, so switching to
+    /// it doubles the attempt limit, unless the limit was changed already.
+    pub fn with_feedback(mut self, feedback: Feedback) -> Self {
+        let bound: u32 = self.attempt_bound();
+        if feedback == Feedback::HotCold && self.max_attempts == Some(bound) {
+            self.max_attempts = Some(bound * 2);
+        }
+        self.feedback = feedback;
+        self
+    }
+
+    /// Let the game lie in up to `lies` of its "Too small!" and "Too big!".
+    ///
+    /// Finding the number through lies takes more guesses, so the attempt
+    /// limit grows to Berlekamp's bound, unless it was changed already. A
+    /// game without lies keeps the bound of a perfect bisection.
+    pub fn with_lies(mut self, lies: u32) -> Self {
+        if lies > 0 && self.max_attempts == Some(self.attempt_bound()) {
+            let count: u128 = T::count(self.min, self.max, self.tolerance);
+            self.max_attempts = Some(lies::attempt_bound(count, lies));
+        }
+        self.lies = lies;
+        self
+    }
+
+    /// The most answers the game may lie in.
+    pub fn lies(&self) -> u32 {
+        self.lies
+    }
+
+    /// Give the player a fixed wall-clock budget to find the number, or none.
+    pub fn with_time_limit(mut self, time_limit: Option<Duration>) -> Self {
+        self.time_limit = time_limit;
+        self
+    }
+
+    /// The wall-clock budget of the game, or `None` if time is unlimited.
+    pub fn time_limit(&self) -> Option<Duration> {
+        self.time_limit
+    }
+
+    /// The kind of feedback given after a wrong guess.
+    pub fn feedback(&self) -> Feedback {
+        self.feedback
+    }
+
+    /// The number of misses after which hints are offered, or `None` if they are off.
+    pub fn hints_after(&self) -> Option<u32> {
+        self.hints_after
+    }
+
+    /// The maximum number of attempts, or `None` if they are unlimited.
+    pub fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    /// The number of guesses a perfect bisection needs, in the worst case,
+    /// to find any secret number of the range.
+    pub fn attempt_bound(&self) -> u32 {
+        T::attempt_bound(self.min, self.max, self.tolerance)
+    }
+
+    /// Whether a guess falls within the accepted range.
+    pub fn contains(&self, guess: T) -> bool {
+        self.min <= guess && guess <= self.max
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::from_difficulty(Difficulty::default())
+    }
+}
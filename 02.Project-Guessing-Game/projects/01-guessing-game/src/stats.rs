@@ -0,0 +1,172 @@
+/******************************************************************/
+/* Stats: Lifetime statistics of a player, from the game history. */
+/******************************************************************/
+
+// Import libraries/modules
+use crate::date::Date;
+use crate::histogram;
+use crate::history::GameRecord;
+use crate::messages::{self, Message};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// A run of consecutive games with the same result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Streak {
+    /// Whether the games of the run were won.
+    pub won: bool,
+    /// The number of games in the run.
+    pub length: usize,
+}
+
+/// Aggregate statistics over many games.
+///
+/// Nothing is stored besides the history itself: the statistics are computed
+/// from it on demand, so they follow any change in how they are computed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats<'a> {
+    /// The number of games played.
+    pub games: usize,
+    /// The number of games won.
+    pub wins: usize,
+    /// The mean number of attempts per game.
+    pub mean_attempts: f64,
+    /// The game with the highest score, fewest attempts, and fastest time.
+    pub best: Option<&'a GameRecord>,
+    /// The game with the lowest score, most attempts, and slowest time.
+    pub worst: Option<&'a GameRecord>,
+    /// The run that the latest game belongs to.
+    pub current_streak: Streak,
+    /// The longest run of won games.
+    pub longest_wins: usize,
+    /// The longest run of lost games.
+    pub longest_losses: usize,
+    /// The number of games won with each number of attempts.
+    pub histogram: BTreeMap<u32, usize>,
+}
+
+impl Stats<'_> {
+    /// The share of games won, from 0 to 1.
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+}
+
+/// Compute the statistics of `records`, in any order.
+pub fn stats<'a, I>(records: I) -> Stats<'a>
+where
+    I: IntoIterator<Item = &'a GameRecord>,
+{
+    // Streaks follow the order in which games were played
+    let mut records: Vec<&GameRecord> = records.into_iter().collect();
+    records.sort_by_key(|record| record.timestamp);
+
+    let mut current_streak: Streak = Streak::default();
+    let mut longest_wins: usize = 0;
+    let mut longest_losses: usize = 0;
+    let mut histogram: BTreeMap<u32, usize> = BTreeMap::new();
+    let mut total_attempts: u64 = 0;
+    for record in &records {
+        if current_streak.length > 0 && current_streak.won == record.won {
+            current_streak.length += 1;
+        } else {
+            current_streak = Streak {
+                won: record.won,
+                length: 1,
+            };
+        }
+        if record.won {
+            longest_wins = longest_wins.max(current_streak.length);
+            *histogram.entry(record.attempts).or_default() += 1;
+        } else {
+            longest_losses = longest_losses.max(current_streak.length);
+        }
+        total_attempts += u64::from(record.attempts);
+    }
+
+    let games: usize = records.len();
+    Stats {
+        games,
+        wins: histogram.values().sum(),
+        mean_attempts: if games == 0 { 0.0 } else { total_attempts as f64 / games as f64 },
+        best: records
+            .iter()
+            .copied()
+            .min_by_key(|record| (Reverse(record.score), record.attempts, record.duration)),
+        worst: records
+            .iter()
+            .copied()
+            .max_by_key(|record| (Reverse(record.score), record.attempts, record.duration)),
+        current_streak,
+        longest_wins,
+        longest_losses,
+        histogram,
+    }
+}
+
+/// Render the statistics as plain text, with a histogram of the attempts.
+///
+/// The labels are padded to the longest one, so the values line up in every
+/// language.
+pub fn render(title: &str, stats: &Stats<'_>) -> String {
+    let mut text: String = String::new();
+    let _ = writeln!(text, "== {title} ==");
+    if stats.games == 0 {
+        let _ = writeln!(text, "{}", messages::text(Message::NoGames));
+        return text;
+    }
+
+    // Every row is a label and a value
+    let wins = |count: u64| messages::plural(Message::WinCount, count, &[]);
+    let losses = |count: u64| messages::plural(Message::LossCount, count, &[]);
+    let streak: u64 = stats.current_streak.length as u64;
+    let mut rows: Vec<(Message, String)> = vec![
+        (Message::GamesPlayed, stats.games.to_string()),
+        (Message::Wins, format!("{} ({:.1}%)", stats.wins, stats.win_rate() * 100.0)),
+        (Message::MeanAttempts, format!("{:.2}", stats.mean_attempts)),
+    ];
+    if let Some(best) = stats.best {
+        rows.push((Message::BestGame, describe(best)));
+    }
+    if let Some(worst) = stats.worst {
+        rows.push((Message::WorstGame, describe(worst)));
+    }
+    rows.push((Message::CurrentStreak, if stats.current_streak.won { wins(streak) } else { losses(streak) }));
+    rows.push((
+        Message::LongestStreaks,
+        format!("{}, {}", wins(stats.longest_wins as u64), losses(stats.longest_losses as u64)),
+    ));
+    let width: usize = rows.iter().map(|(label, _)| messages::text(*label).chars().count()).max().unwrap_or(0);
+    for (label, value) in rows {
+        let _ = writeln!(text, "{:<width$} {value}", messages::text(label));
+    }
+
+    if !stats.histogram.is_empty() {
+        let _ = write!(text, "\n{}\n", messages::text(Message::AttemptsToWin));
+        text.push_str(&histogram::render(&stats.histogram));
+    }
+
+    text
+}
+
+/// Sum a game up on one line.
+fn describe(record: &GameRecord) -> String {
+    let attempts: String = messages::plural(Message::AttemptCount, u64::from(record.attempts), &[]);
+    let message: Message = if record.won { Message::GameRecord } else { Message::GameRecordLost };
+    messages::format(
+        message,
+        &[
+            ("score", &record.score),
+            ("attempts", &attempts),
+            ("category", &record.category()),
+            ("min", &record.min),
+            ("max", &record.max),
+            ("date", &Date::from_timestamp(record.timestamp)),
+        ],
+    )
+}
@@ -0,0 +1,163 @@
+/*************************************************************/
+/* Strategy: Let the computer play, using the same feedback. */
+/*************************************************************/
+
+// Import libraries/modules
+use crate::game::{seeded_rng, Game, GameStatus, GuessOutcome, SeededRng};
+use crate::settings::SettingsError;
+use rand::RngExt;
+use std::fmt;
+use std::str::FromStr;
+
+/// Mixed into the seed of [`RandomGuess`], so that its choices never mirror
+/// the secret numbers drawn from the same seed.
+const RANDOM_GUESS_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A way of picking the next guess.
+///
+/// The solver keeps track of the candidates that are still consistent with
+/// the feedback, and asks the strategy to pick one of them.
+pub trait Strategy {
+    /// The name of the strategy, as typed on the command line.
+    fn name(&self) -> &'static str;
+
+    /// Pick the next guess among the remaining candidates `low..=high`.
+    fn next_guess(&mut self, low: u32, high: u32) -> u32;
+}
+
+/// Always guess the middle of the remaining candidates.
+///
+/// This halves the candidates at every step, so it never needs more than
+/// the attempt bound of the range.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BinarySearch;
+
+impl Strategy for BinarySearch {
+    fn name(&self) -> &'static str {
+        "binary"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        low + (high - low) / 2
+    }
+}
+
+/// Guess any of the remaining candidates, at random.
+#[derive(Debug, Clone)]
+pub struct RandomGuess {
+    rng: SeededRng,
+}
+
+impl RandomGuess {
+    /// Create a random strategy that always makes the same choices for the same seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: seeded_rng(seed ^ RANDOM_GUESS_SALT),
+        }
+    }
+}
+
+impl Strategy for RandomGuess {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        self.rng.random_range(low..=high)
+    }
+}
+
+/// Try every candidate in turn, from the smallest.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Linear;
+
+impl Strategy for Linear {
+    fn name(&self) -> &'static str {
+        "linear"
+    }
+
+    fn next_guess(&mut self, low: u32, _high: u32) -> u32 {
+        low
+    }
+}
+
+/// The built-in strategies, as picked on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StrategyKind {
+    /// [`BinarySearch`]
+    #[default]
+    Binary,
+    /// [`RandomGuess`]
+    Random,
+    /// [`Linear`]
+    Linear,
+}
+
+impl StrategyKind {
+    /// Every built-in strategy.
+    pub const ALL: [StrategyKind; 3] = [StrategyKind::Binary, StrategyKind::Random, StrategyKind::Linear];
+
+    /// The name of the strategy, as typed on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            StrategyKind::Binary => "binary",
+            StrategyKind::Random => "random",
+            StrategyKind::Linear => "linear",
+        }
+    }
+
+    /// Create the strategy; `seed` drives the choices of the random strategy.
+    pub fn build(self, seed: u64) -> Box<dyn Strategy> {
+        match self {
+            StrategyKind::Binary => Box::new(BinarySearch),
+            StrategyKind::Random => Box::new(RandomGuess::new(seed)),
+            StrategyKind::Linear => Box::new(Linear),
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = SettingsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StrategyKind::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| SettingsError::UnknownStrategy(s.to_string()))
+    }
+}
+
+/// Let a strategy play a game until it is over.
+///
+/// `on_step` is called after every guess with the guess and its outcome.
+pub fn solve<S, F>(game: &mut Game, strategy: &mut S, mut on_step: F) -> GameStatus
+where
+    S: Strategy + ?Sized,
+    F: FnMut(u32, GuessOutcome),
+{
+    // The candidates that are still consistent with the feedback
+    let mut low: u32 = game.settings().min();
+    let mut high: u32 = game.settings().max();
+
+    while !game.is_over() {
+        // Feed the guess through the same path as typed input
+        let guess: u32 = strategy.next_guess(low, high).clamp(low, high);
+        let outcome: GuessOutcome = game.submit(&guess.to_string());
+        on_step(guess, outcome);
+
+        // Narrow the candidates
+        match outcome {
+            GuessOutcome::TooSmall => low = guess + 1,
+            GuessOutcome::TooBig => high = guess - 1,
+            _ => {}
+        }
+    }
+
+    game.status()
+}
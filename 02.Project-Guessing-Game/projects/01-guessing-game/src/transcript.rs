@@ -0,0 +1,334 @@
+/**************************************************************/
+/* Transcript: Record a session as JSON Lines, and replay it. */
+/**************************************************************/
+
+//! A transcript is a JSON Lines file: one JSON object per line, each with a
+//! `type` field.
+//!
+//! ```text
+//! {"type":"start","version":1,"seed":42,"min":1,"max":100,"max_attempts":7,"hints_after":3,"lies":0,
+//!  "feedback":"classic","time_limit_ms":null}
+//! {"type":"line","input":"50","outcome":"too_big","elapsed_ms":1520}
+//! {"type":"line","input":"abc","outcome":"invalid","elapsed_ms":3012}
+//! {"type":"end","result":"won","attempts":4,"elapsed_ms":9120}
+//! ```
+//!
+//! - `start` holds everything needed to recreate the game
+//! - `line` holds every raw line typed by the player, without its line
+//!   ending, with what the session made of it and when
+//! - `end` holds how the session ended; it is missing if the game crashed
+//!
+//! New fields may be added in the same version; readers ignore unknown fields.
+
+// Import libraries/modules
+use crate::game::{seeded_rng, Game, GameStatus};
+use crate::json::{self, JsonError, Value};
+use crate::messages;
+use crate::session::{self, LineOutcome, Observer, SessionEnd};
+use crate::settings::{Feedback, Settings, DEFAULT_HINTS_AFTER};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::io::{BufRead, Write};
+use std::time::Duration;
+
+/// The version written in the `start` record.
+pub const VERSION: u64 = 1;
+
+/// Records a session into a transcript, one line at a time.
+///
+/// Every record is flushed as soon as it is written, so that a transcript
+/// survives a crash of the game.
+#[derive(Debug)]
+pub struct TranscriptWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> TranscriptWriter<W> {
+    /// Start a transcript with the `start` record of a game drawn from `seed`.
+    pub fn new(mut writer: W, seed: u64, settings: &Settings) -> io::Result<Self> {
+        let start: Value = Value::object([
+            ("type", "start".into()),
+            ("version", VERSION.into()),
+            ("seed", seed.into()),
+            ("min", settings.min().into()),
+            ("max", settings.max().into()),
+            ("max_attempts", settings.max_attempts().into()),
+            ("hints_after", settings.hints_after().into()),
+            ("lies", settings.lies().into()),
+            ("feedback", settings.feedback().name().into()),
+            ("time_limit_ms", settings.time_limit().map(|time_limit| time_limit.as_millis() as u64).into()),
+        ]);
+        writeln!(writer, "{start}")?;
+        writer.flush()?;
+        Ok(Self { writer })
+    }
+
+    /// Write one record.
+    fn write(&mut self, record: Value) -> io::Result<()> {
+        writeln!(self.writer, "{record}")?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Observer for TranscriptWriter<W> {
+    fn on_line(&mut self, line: &str, outcome: LineOutcome, game: &Game) -> io::Result<()> {
+        self.write(Value::object([
+            ("type", "line".into()),
+            ("input", strip_line_ending(line).into()),
+            ("outcome", outcome.name().into()),
+            ("elapsed_ms", (game.elapsed().as_millis() as u64).into()),
+        ]))
+    }
+
+    fn on_end(&mut self, end: SessionEnd, game: &Game) -> io::Result<()> {
+        self.write(Value::object([
+            ("type", "end".into()),
+            ("result", end.name().into()),
+            ("attempts", game.attempts().into()),
+            ("elapsed_ms", (game.elapsed().as_millis() as u64).into()),
+        ]))
+    }
+}
+
+/// Remove the `\n` or `\r\n` that ends a line, and nothing else.
+fn strip_line_ending(line: &str) -> &str {
+    let line: &str = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// A recorded outcome that the engine no longer agrees with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The line of the transcript, starting at 1.
+    pub line: usize,
+    /// The raw input, or a description of the record.
+    pub input: String,
+    /// What the transcript says.
+    pub expected: String,
+    /// What the engine says now.
+    pub actual: String,
+}
+
+/// The result of replaying a transcript.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReplayReport {
+    /// The settings of the game, as recreated from the `start` record.
+    pub settings: Settings,
+    /// The number of input lines that were replayed.
+    pub lines: usize,
+    /// Every outcome that differs from the recording.
+    pub mismatches: Vec<Mismatch>,
+    /// Whether the transcript has its `end` record.
+    pub complete: bool,
+}
+
+impl ReplayReport {
+    /// Whether the replay matches the recording.
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// A transcript that cannot be replayed at all.
+#[derive(Debug)]
+pub enum ReplayError {
+    /// The transcript could not be read.
+    Io(io::Error),
+    /// A line is not valid JSON.
+    Json { line: usize, error: JsonError },
+    /// A record is missing fields, or comes in the wrong order.
+    Format { line: usize, message: String },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::replay_error(self))
+    }
+}
+
+impl Error for ReplayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReplayError::Io(err) => Some(err),
+            ReplayError::Json { error, .. } => Some(error),
+            ReplayError::Format { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReplayError {
+    fn from(err: io::Error) -> Self {
+        ReplayError::Io(err)
+    }
+}
+
+/// Re-execute a transcript against the engine and compare every outcome.
+///
+/// The game is recreated from the seed and range of the `start` record, and
+/// every recorded input goes through [`Game::submit`] again. Timings are not
+/// compared, since they cannot be reproduced.
+pub fn replay<R: BufRead>(reader: R) -> Result<ReplayReport, ReplayError> {
+    let mut game: Option<Game> = None;
+    let mut report: ReplayReport = ReplayReport::default();
+    let mut quit: bool = false;
+
+    for (index, text) in reader.lines().enumerate() {
+        let text: String = text?;
+        let line: usize = index + 1;
+        if text.trim().is_empty() {
+            continue;
+        }
+        let record: Value = json::parse(&text).map_err(|error| ReplayError::Json { line, error })?;
+        let format = |message: &str| ReplayError::Format {
+            line,
+            message: message.to_string(),
+        };
+        let kind: &str = record.get("type").and_then(Value::as_str).ok_or_else(|| format("missing 'type'"))?;
+
+        match (kind, game.as_mut()) {
+            ("start", None) => {
+                let started: Game = start_game(&record).map_err(|message| format(&message))?;
+                report.settings = *started.settings();
+                game = Some(started);
+            }
+            ("start", Some(_)) => return Err(format("a second 'start' record")),
+            (_, None) => return Err(format("the transcript does not begin with a 'start' record")),
+            ("line", Some(game)) => {
+                let input: &str = record.get("input").and_then(Value::as_str).ok_or_else(|| format("missing 'input'"))?;
+                let expected: &str = record
+                    .get("outcome")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| format("missing 'outcome'"))?;
+
+                // Go through the same path as a live session
+                let actual: LineOutcome = if session::is_quit_command(input) {
+                    quit = true;
+                    LineOutcome::Quit
+                } else if session::is_hint_command(input) {
+                    session::ask_hint(game).0
+                } else if session::is_save_command(input) {
+                    LineOutcome::Save
+                } else {
+                    LineOutcome::Guess(game.submit(input))
+                };
+                report.lines += 1;
+                if actual.name() != expected {
+                    report.mismatches.push(Mismatch {
+                        line,
+                        input: input.to_string(),
+                        expected: expected.to_string(),
+                        actual: actual.name().to_string(),
+                    });
+                }
+            }
+            ("end", Some(game)) => {
+                report.complete = true;
+                let expected: &str = record
+                    .get("result")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| format("missing 'result'"))?;
+                let (actual, consistent): (&str, bool) = match game.status() {
+                    GameStatus::Won => (SessionEnd::Won.name(), expected == SessionEnd::Won.name()),
+                    GameStatus::Lost => (SessionEnd::Lost.name(), expected == SessionEnd::Lost.name()),
+                    GameStatus::InProgress if quit => (SessionEnd::Quit.name(), expected == SessionEnd::Quit.name()),
+                    // How the input or the clock ended the game cannot be replayed,
+                    // only that it ended early
+                    GameStatus::InProgress => (
+                        "in_progress",
+                        expected == SessionEnd::EndOfInput.name()
+                            || expected == SessionEnd::Interrupted.name()
+                            || expected == SessionEnd::TimeUp.name(),
+                    ),
+                };
+                if !consistent {
+                    report.mismatches.push(Mismatch {
+                        line,
+                        input: "end of session".to_string(),
+                        expected: expected.to_string(),
+                        actual: actual.to_string(),
+                    });
+                }
+                let attempts: Option<u64> = record.get("attempts").and_then(Value::as_u64);
+                if attempts != Some(u64::from(game.attempts())) {
+                    report.mismatches.push(Mismatch {
+                        line,
+                        input: "attempt count".to_string(),
+                        expected: attempts.map_or_else(|| "nothing".to_string(), |attempts| attempts.to_string()),
+                        actual: game.attempts().to_string(),
+                    });
+                }
+            }
+            (other, Some(_)) => return Err(format(&format!("unknown record type '{other}'"))),
+        }
+    }
+
+    if game.is_none() {
+        return Err(ReplayError::Format {
+            line: 0,
+            message: "the transcript is empty".to_string(),
+        });
+    }
+    Ok(report)
+}
+
+/// Recreate the game described by a `start` record.
+fn start_game(record: &Value) -> Result<Game, String> {
+    let version: u64 = record.get("version").and_then(Value::as_u64).ok_or("missing 'version'")?;
+    if version != VERSION {
+        return Err(format!("unsupported transcript version {version}"));
+    }
+    let seed: u64 = record.get("seed").and_then(Value::as_u64).ok_or("missing 'seed'")?;
+    let bound = |key: &str| -> Result<u32, String> {
+        record
+            .get(key)
+            .and_then(Value::as_u64)
+            .and_then(|value| u32::try_from(value).ok())
+            .ok_or_else(|| format!("missing or invalid '{key}'"))
+    };
+    let max_attempts: Option<u32> = match record.get("max_attempts") {
+        None | Some(Value::Null) => None,
+        Some(value) => Some(
+            value
+                .as_u64()
+                .and_then(|value| u32::try_from(value).ok())
+                .ok_or("invalid 'max_attempts'")?,
+        ),
+    };
+
+    // Transcripts from before hints existed use the default
+    let hints_after: Option<u32> = match record.get("hints_after") {
+        None => Some(DEFAULT_HINTS_AFTER),
+        Some(Value::Null) => None,
+        Some(value) => Some(value.as_u64().and_then(|value| u32::try_from(value).ok()).ok_or("invalid 'hints_after'")?),
+    };
+
+    // Transcripts from before lies existed never lie
+    let lies: u32 = match record.get("lies") {
+        None => 0,
+        Some(value) => value.as_u64().and_then(|value| u32::try_from(value).ok()).ok_or("invalid 'lies'")?,
+    };
+
+    // Transcripts from before hot/cold and time attack existed have neither
+    let feedback: Feedback = match record.get("feedback") {
+        None => Feedback::Classic,
+        Some(value) => value.as_str().and_then(Feedback::from_name).ok_or("invalid 'feedback'")?,
+    };
+    let time_limit: Option<Duration> = match record.get("time_limit_ms") {
+        None | Some(Value::Null) => None,
+        Some(value) => Some(Duration::from_millis(value.as_u64().ok_or("invalid 'time_limit_ms'")?)),
+    };
+
+    // Rebuild the settings in the order of the command line
+    let settings: Settings = Settings::new(bound("min")?, bound("max")?)
+        .and_then(|settings| {
+            settings
+                .with_feedback(feedback)
+                .with_lies(lies)
+                .with_time_limit(time_limit)
+                .with_max_attempts(max_attempts)
+        })
+        .map_err(|err| err.to_string())?
+        .with_hints_after(hints_after);
+    Ok(Game::from_rng(settings, &mut seeded_rng(seed)))
+}
@@ -0,0 +1,262 @@
+/****************************************************************/
+/* TUI: A full-screen front-end on top of the same game engine. */
+/****************************************************************/
+
+//! The full-screen mode draws the whole game after every line typed by the
+//! player, with plain ANSI escape sequences:
+//!
+//! ```text
+//!  Guessing Game                        Attempt 3 of 7 · Win now: 1470
+//!  ────────────────────────────────────────────────────────────────
+//!  1 ···········████████·························· 100
+//!    Candidates: 26 to 37, 12 left
+//!
+//!  1.    50  Too big!
+//!  2.    25  Too small!
+//!
+//!  Too small! You have 5 attempts left.
+//!  Type a number, 'hint', 'save', or 'quit', then press Enter.
+//!  > _
+//! ```
+//!
+//! Lines are still read in the terminal's own line mode, so editing a guess
+//! works as usual, and every command of the line-based mode works the same.
+
+// Import libraries/modules
+use crate::expr::ExprError;
+use crate::game::{Game, GuessOutcome};
+use crate::input::{Input, InputSource};
+use crate::messages::{self, Message};
+use crate::proximity;
+use crate::score;
+use crate::session::{self, LineOutcome, Observer, SessionEnd};
+use crate::settings::Feedback;
+use std::fmt::Write as _;
+use std::io;
+use std::io::Write;
+use std::time::Duration;
+
+/// The width of the screen, in columns.
+const WIDTH: usize = 66;
+
+/// The width of the candidate bar, in cells.
+const BAR_WIDTH: usize = 48;
+
+/// The most recent guesses shown in the history.
+const HISTORY_ROWS: usize = 10;
+
+/// How often the clock of a timed game is redrawn.
+const CLOCK_TICK: Duration = Duration::from_secs(1);
+
+/// Switch to the alternate screen, so that the shell comes back untouched.
+const ENTER_SCREEN: &str = "\x1b[?1049h";
+
+/// Go back to the main screen.
+const LEAVE_SCREEN: &str = "\x1b[?1049l";
+
+/// Clear the screen and move to its top-left corner.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Play a game full-screen until it is over or the player leaves, reporting
+/// every line and the ending to `observer`, as [`session::play_observed`].
+///
+/// The screen is restored before the summary is printed, so that it stays
+/// in the scrollback of the terminal.
+pub fn play_tui<I, W, O>(game: &mut Game, input: &mut I, out: &mut W, observer: &mut O) -> io::Result<SessionEnd>
+where
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+    O: Observer + ?Sized,
+{
+    write!(out, "{ENTER_SCREEN}")?;
+    let end: io::Result<SessionEnd> = run(game, input, out, observer);
+
+    // Leave the full screen even when the terminal failed
+    write!(out, "{LEAVE_SCREEN}")?;
+    let end: SessionEnd = end?;
+    observer.on_end(end, game)?;
+    session::write_summary(game, end, out)?;
+    out.flush()?;
+
+    Ok(end)
+}
+
+/// The loop of [`play_tui`], drawing the screen before every line.
+fn run<I, W, O>(game: &mut Game, input: &mut I, out: &mut W, observer: &mut O) -> io::Result<SessionEnd>
+where
+    I: InputSource + ?Sized,
+    W: Write + ?Sized,
+    O: Observer + ?Sized,
+{
+    let mut message: String = match game.settings().lies() {
+        0 => messages::text(Message::FindSecret).to_string(),
+        lies => messages::format(Message::Lies, &[("lies", &lies)]),
+    };
+    loop {
+        write!(out, "{CLEAR}{}", render(game, &message))?;
+        out.flush()?;
+
+        // Wait for a line, redrawing the clock of a timed game as it runs
+        let line: String = loop {
+            let input: Option<Input> = match game.time_left() {
+                Some(left) => input.read_input_timeout(left.min(CLOCK_TICK))?,
+                None => Some(input.read_input()?),
+            };
+            match input {
+                Some(Input::Line(line)) => break line,
+                Some(Input::Eof) => return Ok(SessionEnd::EndOfInput),
+                Some(Input::Interrupted) => return Ok(SessionEnd::Interrupted),
+                None if game.check_clock() => return Ok(SessionEnd::TimeUp),
+                // Redraw the header only, keeping what the player is typing
+                None => write!(out, "\x1b7\x1b[1;1H{}\x1b8", header(game))?,
+            }
+            out.flush()?;
+        };
+
+        // Handle the commands of the line-based mode
+        if session::is_quit_command(&line) {
+            observer.on_line(&line, LineOutcome::Quit, game)?;
+            return Ok(SessionEnd::Quit);
+        }
+        if session::is_hint_command(&line) {
+            let (outcome, hint): (LineOutcome, String) = session::ask_hint(game);
+            observer.on_line(&line, outcome, game)?;
+            message = hint;
+            continue;
+        }
+        if session::is_save_command(&line) {
+            message = match observer.on_save(game) {
+                Ok(Some(path)) => messages::format(Message::SavedTo, &[("path", &path.display())]),
+                Ok(None) => messages::text(Message::CannotSave).to_string(),
+                Err(err) => messages::format(Message::SaveFailed, &[("error", &err)]),
+            };
+            observer.on_line(&line, LineOutcome::Save, game)?;
+            continue;
+        }
+        if game.check_clock() {
+            return Ok(SessionEnd::TimeUp);
+        }
+
+        // Let the engine judge the guess, evaluated before it moves 'last'
+        let value: Result<i64, ExprError> = game.evaluate(&line);
+        let outcome: GuessOutcome = game.submit(&line);
+        observer.on_line(&line, LineOutcome::Guess(outcome), game)?;
+        message = match (outcome, value) {
+            (GuessOutcome::Invalid, Err(error)) => {
+                messages::format(Message::NotANumber, &[("guess", &line.trim()), ("error", &error)])
+            }
+            (GuessOutcome::OutOfRange, _) => messages::text(Message::OutOfRange).to_string(),
+            (GuessOutcome::Correct, _) => return Ok(SessionEnd::Won),
+            _ => feedback(game),
+        };
+        if game.is_out_of_time() {
+            return Ok(SessionEnd::TimeUp);
+        }
+        if game.is_over() {
+            return Ok(SessionEnd::Lost);
+        }
+
+        // Only guesses that were judged use up an attempt
+        if let (GuessOutcome::TooSmall | GuessOutcome::TooBig, Some(left)) = (outcome, game.attempts_left()) {
+            let _ = write!(message, " {}", messages::plural(Message::AttemptsLeft, u64::from(left), &[]));
+        }
+    }
+}
+
+/// Draw the whole screen: the header, the candidate bar, the history, the
+/// last message, and the prompt.
+pub fn render(game: &Game, message: &str) -> String {
+    let mut screen: String = String::new();
+    let _ = writeln!(screen, "{}", header(game));
+    let _ = writeln!(screen, " {}", "─".repeat(WIDTH - 2));
+    render_candidates(game, &mut screen);
+    let _ = writeln!(screen);
+    render_history(game, &mut screen);
+    let _ = writeln!(screen);
+    let _ = writeln!(screen, " {message}");
+    let _ = writeln!(screen, " {}", messages::text(Message::Commands));
+    let _ = write!(screen, " > ");
+    screen
+}
+
+/// The title, followed by the attempt counter, the score, and the clock.
+fn header(game: &Game) -> String {
+    let attempt: u32 = game.attempts() + 1;
+    let mut status: String = match game.settings().max_attempts() {
+        Some(max) => messages::format(Message::AttemptOf, &[("attempt", &attempt), ("max", &max)]),
+        None => messages::format(Message::Attempt, &[("attempt", &attempt)]),
+    };
+
+    // The score if the next guess wins, which drops with every guess and second
+    let score: u32 = score::score(attempt, game.settings().size(), game.elapsed(), game.hints().len() as u32);
+    let _ = write!(status, " · {}", messages::format(Message::WinNow, &[("score", &score)]));
+    if let Some(left) = game.time_left() {
+        let seconds: String = format!("{:.0}", left.as_secs_f64().ceil());
+        let _ = write!(status, " · {}", messages::format(Message::SecondsLeft, &[("seconds", &seconds)]));
+    }
+    let title: &str = messages::text(Message::Title);
+    let padding: usize = WIDTH.saturating_sub(1 + title.chars().count() + status.chars().count());
+    format!(" {title}{}{status}\x1b[K", " ".repeat(padding))
+}
+
+/// The range as a bar, with the candidates left highlighted.
+///
+/// In hot/cold mode the directions are secret, so the bar would give them
+/// away, and with lies it would narrow on answers that may be false.
+fn render_candidates(game: &Game, screen: &mut String) {
+    let (min, max): (u32, u32) = (game.settings().min(), game.settings().max());
+    let hidden: Option<Message> = match game.settings().feedback() {
+        Feedback::HotCold => Some(Message::CandidatesHidden),
+        Feedback::Classic if game.settings().lies() > 0 => Some(Message::CandidatesLies),
+        Feedback::Classic => None,
+    };
+
+    // Only lies can leave no candidate, so the bar is hidden then too
+    let (None, Some((low, high))) = (hidden, game.candidates()) else {
+        let _ = writeln!(screen, " {min} {} {max}", "·".repeat(BAR_WIDTH));
+        let _ = writeln!(screen, "   {}", messages::text(hidden.unwrap_or(Message::CandidatesLies)));
+        return;
+    };
+
+    // A cell is lit when any of its numbers is still a candidate
+    let size: u64 = game.settings().size();
+    let cell = |number: u32| -> usize { (u64::from(number - min) * BAR_WIDTH as u64 / size) as usize };
+    let bar: String = (0..BAR_WIDTH)
+        .map(|index| if (cell(low)..=cell(high)).contains(&index) { '█' } else { '·' })
+        .collect();
+    let _ = writeln!(screen, " {min} {bar} {max}");
+    let count: u64 = u64::from(high - low) + 1;
+    let _ = writeln!(screen, "   {}", messages::plural(Message::Candidates, count, &[("low", &low), ("high", &high)]));
+}
+
+/// The most recent guesses, with the feedback they got.
+fn render_history(game: &Game, screen: &mut String) {
+    let guesses = game.guesses();
+    if guesses.is_empty() {
+        let _ = writeln!(screen, " {}", messages::text(Message::NoGuess));
+        return;
+    }
+    let first: usize = guesses.len().saturating_sub(HISTORY_ROWS);
+    for (index, guess) in guesses.iter().enumerate().skip(first) {
+        let answer: String = match game.settings().feedback() {
+            Feedback::HotCold => {
+                let previous: Option<u32> = index.checked_sub(1).map(|previous| guesses[previous].value);
+                let proximity = proximity::proximity(game.settings().size(), game.secret(), guess.value, previous);
+                match proximity.trend {
+                    Some(trend) => format!("{} {}", messages::heat(proximity.heat), messages::trend(trend)),
+                    None => messages::heat(proximity.heat).to_string(),
+                }
+            }
+            Feedback::Classic => messages::outcome(guess.outcome).to_string(),
+        };
+        let _ = writeln!(screen, " {:>2}. {:>10}  {answer}", index + 1, guess.value);
+    }
+}
+
+/// The feedback of the last guess, in the words of the line-based mode.
+fn feedback(game: &Game) -> String {
+    let mut text: Vec<u8> = Vec::new();
+    let outcome: GuessOutcome = game.guesses().last().map_or(GuessOutcome::Invalid, |guess| guess.outcome);
+    let _ = session::write_feedback(game, outcome, &mut text);
+    String::from_utf8_lossy(&text).split_whitespace().collect::<Vec<&str>>().join(" ")
+}
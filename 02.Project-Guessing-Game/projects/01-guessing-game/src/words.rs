@@ -0,0 +1,322 @@
+/*****************************************************************/
+/* Words: Guess a secret word, scored letter by letter (Wordle). */
+/*****************************************************************/
+
+// Import libraries/modules
+use crate::game::GameStatus;
+use crate::messages;
+use crate::score;
+use rand::{Rng, RngExt};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The embedded word list: common five-letter English words.
+pub const EMBEDDED_WORDS: &str = include_str!("words.txt");
+
+/// The number of letters of a word, unless `--letters` is given.
+pub const DEFAULT_LETTERS: usize = 5;
+
+/// The number of attempts, unless `--attempts` is given.
+pub const DEFAULT_ATTEMPTS: u32 = 6;
+
+/// Get every word of a text, as slices of it.
+///
+/// A word is a run of letters, in any script, as in `first_word_slice()` of
+/// `02-slice`, but splitting on every non-letter instead of spaces only.
+/// Combining marks belong to the word, so that `"cafe\u{301}"` (NFD) stays
+/// whole. Lines starting with `#` are comments.
+pub fn extract_words(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(|line| line.split(|c: char| !c.is_alphabetic() && !is_combining_mark(c)))
+        .filter(|word| word.chars().next().is_some_and(char::is_alphabetic))
+}
+
+/// Bring a word to the form it is compared in: lowercase, in Unicode NFC,
+/// without the surrounding whitespace.
+///
+/// Lowercasing may decompose a letter, e.g. `"İ"` becomes `"i\u{307}"`, so
+/// the word is composed afterwards.
+pub fn normalize(word: &str) -> String {
+    word.trim().to_lowercase().nfc().collect()
+}
+
+/// The letters of a word: its extended grapheme clusters, not its `char`s
+/// nor its bytes.
+///
+/// `"été".len()` is 5 bytes, and `"e\u{301}te\u{301}"` is 5 `char`s, but both
+/// have 3 letters.
+pub fn letters(word: &str) -> Vec<&str> {
+    word.graphemes(true).collect()
+}
+
+/// The number of letters of a word, as split by [`letters`].
+pub fn letter_count(word: &str) -> usize {
+    word.graphemes(true).count()
+}
+
+/// The words that can be guessed, and drawn as the secret word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordList {
+    /// The distinct words, normalized, in the order of the text.
+    words: Vec<String>,
+    /// The same words, to check guesses quickly.
+    known: HashSet<String>,
+}
+
+impl WordList {
+    /// Keep the words of `letters` letters of a text.
+    pub fn from_text(text: &str, letters: usize) -> Result<Self, WordError> {
+        let mut words: Vec<String> = Vec::new();
+        let mut known: HashSet<String> = HashSet::new();
+        for word in extract_words(text).map(normalize) {
+            // Counted once normalized, as lowercasing may change the length
+            if letter_count(&word) == letters && known.insert(word.clone()) {
+                words.push(word);
+            }
+        }
+        if words.is_empty() {
+            return Err(WordError::NoWords(letters));
+        }
+        Ok(Self { words, known })
+    }
+
+    /// The embedded word list.
+    pub fn embedded() -> Self {
+        Self::from_text(EMBEDDED_WORDS, DEFAULT_LETTERS).expect("the embedded word list is not empty")
+    }
+
+    /// The words, in the order of the text.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Whether a normalized word is in the list.
+    pub fn contains(&self, word: &str) -> bool {
+        self.known.contains(word)
+    }
+
+    /// Draw a word at random.
+    pub fn pick<R: Rng + ?Sized>(&self, rng: &mut R) -> &str {
+        &self.words[rng.random_range(0..self.words.len())]
+    }
+}
+
+/// How a letter of a guess matches the secret word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    /// The letter is at this very place in the secret word.
+    Correct,
+    /// The letter is elsewhere in the secret word.
+    Present,
+    /// The letter is not in the secret word, or not that many times.
+    Absent,
+}
+
+/// Mark every letter of `guess` against `secret`, which have as many letters
+/// and are both normalized.
+///
+/// A letter repeated in the guess is only marked present as many times as
+/// the secret word holds it beyond its correct places, as in Wordle.
+pub fn mark(secret: &str, guess: &str) -> Vec<Mark> {
+    let secret: Vec<&str> = letters(secret);
+    let guess: Vec<&str> = letters(guess);
+
+    // Correct letters first, keeping the other letters of the secret word aside
+    let mut marks: Vec<Mark> = vec![Mark::Absent; guess.len()];
+    let mut unmatched: Vec<&str> = Vec::new();
+    for (index, (&wanted, &given)) in secret.iter().zip(&guess).enumerate() {
+        if wanted == given {
+            marks[index] = Mark::Correct;
+        } else {
+            unmatched.push(wanted);
+        }
+    }
+
+    // Then present letters, each using up one unmatched letter
+    for (index, given) in guess.iter().enumerate() {
+        if marks[index] == Mark::Correct {
+            continue;
+        }
+        if let Some(position) = unmatched.iter().position(|wanted| wanted == given) {
+            unmatched.swap_remove(position);
+            marks[index] = Mark::Present;
+        }
+    }
+
+    marks
+}
+
+/// Render a marked guess, with coloured tiles or plain-text markers.
+///
+/// Without colour, correct letters are in brackets `[A]`, present letters in
+/// parentheses `(B)`, and absent letters stand alone ` C `.
+pub fn render(guess: &str, marks: &[Mark], colour: bool) -> String {
+    letters(guess)
+        .into_iter()
+        .zip(marks)
+        .map(|(letter, mark)| {
+            let letter: String = letter.to_uppercase().nfc().collect();
+            match (colour, mark) {
+                (true, Mark::Correct) => format!("\x1b[1;30;42m {letter} \x1b[0m"),
+                (true, Mark::Present) => format!("\x1b[1;30;43m {letter} \x1b[0m"),
+                (true, Mark::Absent) => format!("\x1b[1;37;100m {letter} \x1b[0m"),
+                (false, Mark::Correct) => format!("[{letter}]"),
+                (false, Mark::Present) => format!("({letter})"),
+                (false, Mark::Absent) => format!(" {letter} "),
+            }
+        })
+        .collect()
+}
+
+/// The reasons why a word list or a guessed word can be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordError {
+    /// The word list holds no word of that many letters.
+    NoWords(usize),
+    /// The guess does not have as many letters as the secret word.
+    Length { expected: usize, found: usize },
+    /// The guess is not in the word list.
+    Unknown(String),
+}
+
+impl fmt::Display for WordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&messages::word_error(self))
+    }
+}
+
+impl Error for WordError {}
+
+/// The outcome of one guessed word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordOutcome {
+    /// The guess was marked, and counts as an attempt.
+    Marked(Vec<Mark>),
+    /// The guess is not a valid word, and does not count.
+    Invalid(WordError),
+    /// The game is already over.
+    GameOver,
+}
+
+/// A game of Wordle: find the secret word of the list.
+#[derive(Debug, Clone)]
+pub struct WordGame {
+    list: WordList,
+    secret: String,
+    max_attempts: Option<u32>,
+    guesses: Vec<(String, Vec<Mark>)>,
+    status: GameStatus,
+    started: Instant,
+    finished: Option<Instant>,
+}
+
+impl WordGame {
+    /// Start a game with a secret word drawn from `list` with `rng`.
+    pub fn from_rng<R: Rng + ?Sized>(list: WordList, max_attempts: Option<u32>, rng: &mut R) -> Self {
+        let secret: String = list.pick(rng).to_string();
+        Self::with_secret(list, &secret, max_attempts)
+    }
+
+    /// Start a game with a known secret word, which must be in the list.
+    pub fn with_secret(list: WordList, secret: &str, max_attempts: Option<u32>) -> Self {
+        let secret: String = normalize(secret);
+        assert!(list.contains(&secret), "the secret word must be in the word list");
+        Self {
+            list,
+            secret,
+            max_attempts,
+            guesses: Vec::new(),
+            status: GameStatus::InProgress,
+            started: Instant::now(),
+            finished: None,
+        }
+    }
+
+    /// Submit the raw text typed by the player and return its outcome.
+    pub fn submit(&mut self, guess: &str) -> WordOutcome {
+        if self.is_over() {
+            return WordOutcome::GameOver;
+        }
+        let guess: String = normalize(guess);
+        let (expected, found): (usize, usize) = (self.letters(), letter_count(&guess));
+        if found != expected {
+            return WordOutcome::Invalid(WordError::Length { expected, found });
+        }
+        if !self.list.contains(&guess) {
+            return WordOutcome::Invalid(WordError::Unknown(guess));
+        }
+
+        // Settle the game on a win, or when no attempt is left
+        let marks: Vec<Mark> = mark(&self.secret, &guess);
+        let won: bool = guess == self.secret;
+        self.guesses.push((guess, marks.clone()));
+        if won {
+            self.finish(GameStatus::Won);
+        } else if self.attempts_left() == Some(0) {
+            self.finish(GameStatus::Lost);
+        }
+
+        WordOutcome::Marked(marks)
+    }
+
+    /// Record the end of the game.
+    fn finish(&mut self, status: GameStatus) {
+        self.status = status;
+        self.finished = Some(Instant::now());
+    }
+
+    /// The number of letters of the secret word.
+    pub fn letters(&self) -> usize {
+        letter_count(&self.secret)
+    }
+
+    /// The secret word the player is trying to find.
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// Every valid guess so far, with its marks, oldest first.
+    pub fn guesses(&self) -> &[(String, Vec<Mark>)] {
+        &self.guesses
+    }
+
+    /// The number of valid guesses so far.
+    pub fn attempts(&self) -> u32 {
+        self.guesses.len() as u32
+    }
+
+    /// The number of attempts left, or `None` if they are unlimited.
+    pub fn attempts_left(&self) -> Option<u32> {
+        self.max_attempts
+            .map(|max_attempts| max_attempts.saturating_sub(self.attempts()))
+    }
+
+    /// Whether the word was found.
+    pub fn is_won(&self) -> bool {
+        self.status == GameStatus::Won
+    }
+
+    /// Whether the game is won or lost.
+    pub fn is_over(&self) -> bool {
+        self.status != GameStatus::InProgress
+    }
+
+    /// The time spent playing, up to the end of the game.
+    pub fn elapsed(&self) -> Duration {
+        self.finished.unwrap_or_else(Instant::now) - self.started
+    }
+
+    /// The score of the game, as for numbers, with every word as a candidate.
+    pub fn score(&self) -> u32 {
+        match self.status {
+            GameStatus::Won => score::score(self.attempts(), self.list.words().len() as u64, self.elapsed(), 0),
+            GameStatus::InProgress | GameStatus::Lost => 0,
+        }
+    }
+}
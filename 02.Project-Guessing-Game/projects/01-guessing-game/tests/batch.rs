@@ -0,0 +1,51 @@
+/**************************************************************/
+/* Tests: Batch games answer every line with one JSON object. */
+/**************************************************************/
+
+// Import libraries/modules
+use guessing_game::batch;
+use guessing_game::json::{self, Value};
+use guessing_game::{Game, SessionEnd, Settings};
+
+/// Play `lines` in batch on the secret number 42, and return the end and every answer.
+fn play(lines: &str) -> (SessionEnd, Vec<String>) {
+    let mut game: Game = Game::with_secret(Settings::new(1, 100).unwrap(), 42);
+    let mut out: Vec<u8> = Vec::new();
+    let end: SessionEnd = batch::play_batch(&mut game, lines.as_bytes(), &mut out).unwrap();
+    (end, String::from_utf8(out).unwrap().lines().map(String::from).collect())
+}
+
+#[test]
+fn every_line_gets_an_answer_and_the_game_a_summary() {
+    let (end, answers) = play("50\n1 +\nhint\n100 - 58\n");
+    assert_eq!(end, SessionEnd::Won);
+    assert_eq!(answers.len(), 5);
+    assert_eq!(answers[0], r#"{"guess":50,"outcome":"too_big"}"#);
+    assert_eq!(
+        answers[1],
+        r#"{"input":"1 +","outcome":"invalid","error":"column 4: expected a number, found the end"}"#
+    );
+    assert_eq!(answers[3], r#"{"guess":42,"outcome":"correct"}"#);
+
+    // Every line is valid JSON, for scripts to parse
+    let hint: Value = json::parse(&answers[2]).unwrap();
+    assert_eq!(hint.get("input").and_then(Value::as_str), Some("hint"));
+    assert!(hint.get("message").and_then(Value::as_str).is_some());
+    let summary: Value = json::parse(&answers[4]).unwrap();
+    assert_eq!(summary.get("result").and_then(Value::as_str), Some("won"));
+    assert_eq!(summary.get("attempts").and_then(Value::as_u64), Some(2));
+    assert_eq!(summary.get("secret").and_then(Value::as_u64), Some(42));
+    assert!(summary.get("score").and_then(Value::as_u64).unwrap() > 0);
+}
+
+#[test]
+fn the_summary_tells_how_the_game_ended() {
+    let (end, answers) = play("10\nquit\n20\n");
+    assert_eq!(end, SessionEnd::Quit);
+    assert_eq!(answers.len(), 2);
+    assert!(answers[1].starts_with(r#"{"result":"quit","attempts":1,"secret":42"#), "{}", answers[1]);
+
+    let (end, answers) = play("10\n");
+    assert_eq!(end, SessionEnd::EndOfInput);
+    assert!(answers[1].contains(r#""result":"end_of_input""#));
+}
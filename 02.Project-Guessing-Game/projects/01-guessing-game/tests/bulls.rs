@@ -0,0 +1,84 @@
+/**************************************************************/
+/* Tests: The bulls and cows solver finds every code in time. */
+/**************************************************************/
+
+// Import libraries/modules
+use guessing_game::bulls::{self, Code, CodeGame, CodeOutcome, CodeSolver, Marks};
+use guessing_game::{seeded_rng, GameStatus};
+use std::collections::BTreeSet;
+
+/// The most guesses the solver needs for any code consistent with its
+/// candidates, found by following every possible answer.
+fn worst_case(solver: &CodeSolver) -> u32 {
+    let guess: Code = solver.next_guess().expect("there is always a candidate");
+    let answers: BTreeSet<(u32, u32)> = solver
+        .candidates()
+        .iter()
+        .map(|candidate| candidate.marks(&guess))
+        .map(|marks| (marks.bulls, marks.cows))
+        .collect();
+
+    let mut worst: u32 = 1;
+    for (bulls, cows) in answers {
+        if bulls as usize == guess.len() {
+            continue;
+        }
+        let mut next: CodeSolver = solver.clone();
+        next.record(&guess, Marks { bulls, cows });
+        worst = worst.max(1 + worst_case(&next));
+    }
+    worst
+}
+
+#[test]
+fn solver_finds_every_code_within_the_optimal_bound() {
+    assert_eq!(worst_case(&CodeSolver::new(4)), 7);
+    assert_eq!(bulls::attempt_bound(4), 7);
+}
+
+#[test]
+fn solver_finds_every_short_code_within_the_attempt_bound() {
+    assert_eq!(worst_case(&CodeSolver::new(2)), 6);
+    assert_eq!(worst_case(&CodeSolver::new(3)), 6);
+    assert_eq!(bulls::attempt_bound(2), 6);
+    assert_eq!(bulls::attempt_bound(3), 6);
+}
+
+/// Following every answer for 5 digits takes over a minute even in release
+/// mode, so it only runs with `cargo test --release -- --ignored`.
+#[test]
+#[ignore]
+fn solver_finds_every_long_code_within_the_attempt_bound() {
+    assert_eq!(worst_case(&CodeSolver::new(5)), 9);
+    assert_eq!(bulls::attempt_bound(5), 9);
+}
+
+#[test]
+fn solver_finds_sampled_long_codes_within_the_attempt_bound() {
+    // A sample of seeded secrets stands in for the exhaustive run above
+    for seed in 0..8 {
+        let mut game: CodeGame = CodeGame::from_rng(5, Some(bulls::attempt_bound(5)), &mut seeded_rng(seed));
+        assert_eq!(bulls::solve(&mut game, |_, _| {}), GameStatus::Won, "seed {seed}");
+    }
+}
+
+#[test]
+fn marks_count_bulls_and_cows() {
+    let secret: Code = Code::parse("0372", 4).unwrap();
+    assert_eq!(secret.marks(&Code::parse("0372", 4).unwrap()), Marks { bulls: 4, cows: 0 });
+    assert_eq!(secret.marks(&Code::parse("2730", 4).unwrap()), Marks { bulls: 0, cows: 4 });
+    assert_eq!(secret.marks(&Code::parse("0918", 4).unwrap()), Marks { bulls: 1, cows: 0 });
+    assert_eq!(secret.marks(&Code::parse("7456", 4).unwrap()), Marks { bulls: 0, cows: 1 });
+}
+
+#[test]
+fn invalid_codes_do_not_count() {
+    let mut game: CodeGame = CodeGame::with_secret(Code::parse("1234", 4).unwrap(), Some(7));
+    assert!(matches!(game.submit("123"), CodeOutcome::Invalid(_)));
+    assert!(matches!(game.submit("1123"), CodeOutcome::Invalid(_)));
+    assert!(matches!(game.submit("12a4"), CodeOutcome::Invalid(_)));
+    assert_eq!(game.attempts(), 0);
+
+    assert_eq!(game.submit("1234"), CodeOutcome::Marked(Marks { bulls: 4, cows: 0 }));
+    assert!(game.is_won());
+}
@@ -0,0 +1,128 @@
+/*****************************************************************/
+/* Tests: Options that a mode would ignore are rejected instead. */
+/*****************************************************************/
+
+// Import libraries/modules
+use guessing_game::cli::{self, CliError, Options};
+
+/// Parse a command line written as one string, split on spaces.
+fn parse(line: &str) -> Result<Options, CliError> {
+    cli::parse_args(line.split_whitespace().map(String::from))
+}
+
+#[test]
+fn reverse_rejects_the_options_of_the_player_game() {
+    assert_eq!(parse("reverse --lies 1").unwrap_err(), CliError::Conflict("reverse", "--lies"));
+    assert_eq!(parse("reverse --hot-cold").unwrap_err(), CliError::Conflict("reverse", "--hot-cold"));
+    assert_eq!(parse("reverse --time 5").unwrap_err(), CliError::Conflict("reverse", "--time"));
+    assert_eq!(parse("reverse --tui").unwrap_err(), CliError::Conflict("reverse", "--tui"));
+    assert!(parse("reverse --min 1 --max 50 --strategy random --seed 3").is_ok());
+}
+
+#[test]
+fn hot_seat_rejects_the_options_of_a_single_player() {
+    assert_eq!(parse("--players a,b --record game.jsonl").unwrap_err(), CliError::Conflict("--players", "--record"));
+    assert_eq!(parse("--players a,b --auto").unwrap_err(), CliError::Conflict("--players", "--auto"));
+    assert_eq!(parse("--players a,b --batch").unwrap_err(), CliError::Conflict("--players", "--batch"));
+    assert_eq!(parse("--players a,b --tui").unwrap_err(), CliError::Conflict("--players", "--tui"));
+    assert!(parse("--players a,b --lies 1 --time 30").is_ok());
+}
+
+#[test]
+fn batch_rejects_what_its_json_cannot_answer() {
+    assert_eq!(parse("--batch --hot-cold").unwrap_err(), CliError::Conflict("--batch", "--hot-cold"));
+    assert_eq!(parse("--batch --time 5").unwrap_err(), CliError::Conflict("--batch", "--time"));
+    assert_eq!(parse("--batch --record game.jsonl").unwrap_err(), CliError::Conflict("--batch", "--record"));
+    assert_eq!(parse("--batch --auto").unwrap_err(), CliError::Conflict("--batch", "--auto"));
+    assert!(parse("--batch --lies 1 --seed 7").is_ok());
+}
+
+#[test]
+fn bulls_rejects_the_options_of_numbers() {
+    assert_eq!(parse("bulls --lies 3 --time 5 --hot-cold").unwrap_err(), CliError::Conflict("bulls", "--lies"));
+    assert_eq!(parse("bulls --time 5").unwrap_err(), CliError::Conflict("bulls", "--time"));
+    assert_eq!(parse("bulls --difficulty hard").unwrap_err(), CliError::Conflict("bulls", "--difficulty"));
+    assert_eq!(parse("bulls --record game.jsonl").unwrap_err(), CliError::Conflict("bulls", "--record"));
+    assert_eq!(parse("bulls --players a,b").unwrap_err(), CliError::Conflict("bulls", "--players"));
+    assert!(parse("bulls --digits 5 --attempts 9 --seed 1 --auto").is_ok());
+}
+
+#[test]
+fn words_rejects_the_options_of_numbers() {
+    assert_eq!(parse("words --hot-cold").unwrap_err(), CliError::Conflict("words", "--hot-cold"));
+    assert_eq!(parse("words --max 50").unwrap_err(), CliError::Conflict("words", "--max"));
+    assert_eq!(parse("words --auto").unwrap_err(), CliError::Conflict("words", "--auto"));
+    assert_eq!(parse("words --tui").unwrap_err(), CliError::Conflict("words", "--tui"));
+    assert!(parse("words --letters 6 --attempts 8 --seed 1").is_ok());
+}
+
+#[test]
+fn auto_rejects_the_options_of_a_person() {
+    assert_eq!(parse("--auto --hot-cold").unwrap_err(), CliError::Conflict("--auto", "--hot-cold"));
+    assert_eq!(parse("--auto --time 5").unwrap_err(), CliError::Conflict("--auto", "--time"));
+    assert_eq!(parse("--auto --record game.jsonl").unwrap_err(), CliError::Conflict("--auto", "--record"));
+    assert_eq!(parse("--auto --tui").unwrap_err(), CliError::Conflict("--auto", "--tui"));
+    assert!(parse("--auto --lies 2 --strategy linear --seed 1").is_ok());
+}
+
+#[test]
+fn lies_reject_hot_cold_feedback() {
+    assert_eq!(parse("--lies 1 --hot-cold").unwrap_err(), CliError::Conflict("--lies", "--hot-cold"));
+    assert_eq!(parse("--hot-cold --lies 2").unwrap_err(), CliError::Conflict("--lies", "--hot-cold"));
+}
+
+#[test]
+fn number_types_reject_the_features_of_u32_only() {
+    assert_eq!(parse("--type i64 --hot-cold").unwrap_err(), CliError::Conflict("--type", "--hot-cold"));
+    assert_eq!(parse("--type u8 --hints-after 2").unwrap_err(), CliError::Conflict("--type", "--hints-after"));
+    assert_eq!(parse("--type f64 --tui").unwrap_err(), CliError::Conflict("--type", "--tui"));
+    assert_eq!(parse("--type i8 --auto --lies 1").unwrap_err(), CliError::Conflict("--auto", "--lies"));
+    assert!(parse("--type i64 --min=-1000 --max 1000 --lies 1 --time 30").is_ok());
+}
+
+#[test]
+fn attempts_default_to_the_bound_of_the_range() {
+    let attempts = |line: &str| parse(line).unwrap().settings.max_attempts();
+    assert_eq!(attempts(""), Some(7));
+    assert_eq!(attempts("--difficulty normal"), Some(7));
+    assert_eq!(attempts("--difficulty easy"), Some(4));
+    assert_eq!(attempts("--difficulty hard"), Some(10));
+    assert_eq!(attempts("--difficulty normal --lies 0"), Some(7));
+    assert_eq!(attempts("--difficulty normal --lies 1"), Some(12));
+}
+
+#[test]
+fn bench_needs_at_least_one_game() {
+    let invalid = |value: &str| CliError::InvalidValue { option: "--games".to_string(), value: value.to_string() };
+    assert_eq!(parse("bench --games 0").unwrap_err(), invalid("0"));
+    assert_eq!(parse("bench --games=-3").unwrap_err(), invalid("-3"));
+    assert_eq!(parse("bench --games 5").unwrap().games, 5);
+}
+
+#[test]
+fn serve_and_bench_reject_what_they_would_ignore() {
+    assert_eq!(parse("serve --auto").unwrap_err(), CliError::Conflict("serve", "--auto"));
+    assert_eq!(parse("serve --batch").unwrap_err(), CliError::Conflict("serve", "--batch"));
+    assert_eq!(parse("serve --players a,b").unwrap_err(), CliError::Conflict("serve", "--players"));
+    assert_eq!(parse("serve --record game.jsonl").unwrap_err(), CliError::Conflict("serve", "--record"));
+    assert_eq!(parse("serve --tui").unwrap_err(), CliError::Conflict("serve", "--tui"));
+    assert_eq!(parse("serve --resume").unwrap_err(), CliError::Conflict("serve", "--resume"));
+    assert!(parse("serve --difficulty hard --lies 1 --time 60 --seed 3 --addr 0.0.0.0:7878").is_ok());
+
+    assert_eq!(parse("bench --lies 1").unwrap_err(), CliError::Conflict("bench", "--lies"));
+    assert_eq!(parse("bench --auto").unwrap_err(), CliError::Conflict("bench", "--auto"));
+    assert!(parse("bench --difficulty hard --strategy random --games 10 --seed 3").is_ok());
+}
+
+#[test]
+fn commands_without_a_game_reject_its_options() {
+    assert_eq!(parse("connect --auto").unwrap_err(), CliError::Conflict("connect", "--auto"));
+    assert_eq!(parse("connect --seed 3").unwrap_err(), CliError::Conflict("connect", "--seed"));
+    assert_eq!(parse("scores --auto").unwrap_err(), CliError::Conflict("scores", "--auto"));
+    assert_eq!(parse("stats --difficulty hard").unwrap_err(), CliError::Conflict("stats", "--difficulty"));
+    assert_eq!(parse("replay game.jsonl --tui").unwrap_err(), CliError::Conflict("replay", "--tui"));
+    assert!(parse("connect --addr 127.0.0.1:9000").is_ok());
+    assert!(parse("scores --top 3").is_ok());
+    assert!(parse("stats --name ann").is_ok());
+    assert!(parse("replay game.jsonl --lang fr").is_ok());
+}
@@ -0,0 +1,76 @@
+/***************************************************************/
+/* Tests: Every day has its own number, played once for score. */
+/***************************************************************/
+
+// Import libraries/modules
+use guessing_game::daily::{self, DailyLog, DailyResult};
+use guessing_game::date::Date;
+use guessing_game::{Game, GuessOutcome, Settings};
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// A day of the year, e.g. `day(3, 14)`.
+fn day(month: u32, day: u32) -> Date {
+    Date { year: 2026, month, day }
+}
+
+#[test]
+fn a_day_has_the_same_number_everywhere() {
+    let date: Date = day(10, 17);
+    assert_eq!(daily::game(date).secret(), daily::game(date).secret());
+    assert_eq!(daily::game(date).settings(), &Settings::default());
+
+    // And the days of a year do not all share a few numbers
+    let start: i64 = day(1, 1).to_days();
+    let secrets: HashSet<u32> = (start..start + 365)
+        .map(|days| daily::game(Date::from_days(days)).secret())
+        .collect();
+    assert!(secrets.len() > 50, "only {} numbers in a year", secrets.len());
+}
+
+#[test]
+fn the_result_to_share_is_the_attempts_and_the_directions() {
+    let mut game: Game = daily::game(day(10, 17));
+    let secret: u32 = game.secret();
+    game.submit("0");
+    game.submit(&secret.to_string());
+    let won: DailyResult = DailyResult::from_game(day(10, 17), &game);
+    assert!(won.is_won());
+    assert_eq!(won.share(), "Guessing Game daily 2026-10-17: 1/7\n🎯");
+
+    let lost: DailyResult = DailyResult {
+        date: day(10, 18),
+        max_attempts: 7,
+        outcomes: vec![GuessOutcome::TooSmall, GuessOutcome::TooBig],
+    };
+    assert!(!lost.is_won());
+    assert_eq!(lost.share(), "Guessing Game daily 2026-10-18: X/7\n🔼🔽");
+    assert_eq!(DailyResult::from_line(&lost.to_line()), Some(lost));
+}
+
+#[test]
+fn the_log_keeps_the_last_result_of_each_day() {
+    let path: PathBuf = env::temp_dir().join(format!("guessing-game-daily-{}.tsv", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let log: DailyLog = DailyLog::at(&path);
+    assert_eq!(log.find(day(10, 17)).unwrap(), None);
+
+    let started: DailyResult = DailyResult {
+        date: day(10, 17),
+        max_attempts: 7,
+        outcomes: vec![GuessOutcome::TooBig],
+    };
+    let finished: DailyResult = DailyResult {
+        outcomes: vec![GuessOutcome::TooBig, GuessOutcome::Correct],
+        ..started.clone()
+    };
+    log.append(&started).unwrap();
+    fs::write(&path, format!("{}\nv1\tcorrupted", fs::read_to_string(&path).unwrap())).unwrap();
+    log.append(&finished).unwrap();
+
+    assert_eq!(log.find(day(10, 17)).unwrap(), Some(finished));
+    assert_eq!(log.find(day(10, 18)).unwrap(), None);
+    fs::remove_file(&path).unwrap();
+}
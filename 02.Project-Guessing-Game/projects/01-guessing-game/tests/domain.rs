@@ -0,0 +1,102 @@
+/****************************************************************/
+/* Tests: The bisection stays within the bound of every domain. */
+/****************************************************************/
+
+// Import libraries/modules
+use guessing_game::domain::{self, DomainError, Number};
+use guessing_game::{seeded_rng, Game, GameStatus, GuessOutcome, Settings};
+
+/// Let the bisection find `secret`, and return the attempts it took.
+fn solve_for<T: Number>(settings: Settings<T>, secret: T) -> u32 {
+    let mut game: Game<T> = Game::with_secret(settings.with_max_attempts(None).unwrap(), secret);
+    assert_eq!(domain::solve(&mut game, |_, _| {}), GameStatus::Won, "{secret} was not found");
+    game.attempts()
+}
+
+/// Check every secret number of a small domain against its bound.
+fn check_every_secret<T: Number>(settings: Settings<T>, secrets: impl Iterator<Item = T>) {
+    let worst: u32 = secrets.map(|secret| solve_for(settings, secret)).max().unwrap();
+    assert_eq!(worst, settings.attempt_bound(), "the bound of {} is not tight", T::NAME);
+}
+
+#[test]
+fn small_integer_types_meet_their_bound_exactly() {
+    check_every_secret(Settings::new_typed(i8::MIN, i8::MAX, 0).unwrap(), i8::MIN..=i8::MAX);
+    check_every_secret(Settings::new_typed(u8::MIN, u8::MAX, 0).unwrap(), u8::MIN..=u8::MAX);
+    check_every_secret(Settings::new_typed(-50i16, 50, 0).unwrap(), -50..=50);
+    check_every_secret(Settings::new_typed(0u16, 999, 2).unwrap(), 0..=999);
+}
+
+#[test]
+fn the_widest_ranges_take_one_guess_per_bit() {
+    let i128s: Settings<i128> = Settings::new_typed(i128::MIN, i128::MAX, 0).unwrap();
+    let u128s: Settings<u128> = Settings::new_typed(u128::MIN, u128::MAX, 0).unwrap();
+    let u64s: Settings<u64> = Settings::new_typed(u64::MIN, u64::MAX, 0).unwrap();
+    assert_eq!((i128s.attempt_bound(), u128s.attempt_bound(), u64s.attempt_bound()), (129, 129, 65));
+
+    for secret in [i128::MIN, -1, 0, i128::MAX] {
+        assert!(solve_for(i128s, secret) <= i128s.attempt_bound());
+    }
+    for seed in 0..200 {
+        let mut rng = seeded_rng(seed);
+        let secret: i128 = i128::random(i128::MIN, i128::MAX, &mut rng);
+        assert!(solve_for(i128s, secret) <= i128s.attempt_bound());
+        let secret: u128 = u128::random(u128::MIN, u128::MAX, &mut rng);
+        assert!(solve_for(u128s, secret) <= u128s.attempt_bound());
+    }
+}
+
+#[test]
+fn floats_are_found_within_the_tolerance() {
+    let settings: Settings<f64> = Settings::new_typed(-1.0, 1.0, 0.001).unwrap();
+    assert_eq!(settings.attempt_bound(), 11);
+    for seed in 0..500 {
+        let mut game: Game<f64> = Game::from_rng(settings, &mut seeded_rng(seed));
+        assert_eq!(domain::solve(&mut game, |_, _| {}), GameStatus::Won);
+        let guess: f64 = game.last_guess().unwrap();
+        assert!((guess - game.secret()).abs() <= 0.001);
+    }
+
+    // A tolerance below the precision of f32 still ends
+    let tiny: Settings<f32> = Settings::new_typed(0.0, 1.0, 1e-12).unwrap();
+    for seed in 0..50 {
+        let mut game: Game<f32> = Game::from_rng(tiny, &mut seeded_rng(seed));
+        assert_eq!(domain::solve(&mut game, |_, _| {}), GameStatus::Won);
+    }
+}
+
+#[test]
+fn guesses_are_parsed_in_the_type_of_the_domain() {
+    let mut game: Game<i8> = Game::with_secret(Settings::new_typed(-100, 100, 0).unwrap(), -42);
+    assert_eq!(game.submit("128"), GuessOutcome::OutOfRange);
+    assert_eq!(game.submit("1e3"), GuessOutcome::Invalid);
+    assert_eq!(game.submit("-101"), GuessOutcome::OutOfRange);
+    assert_eq!(game.submit("-50"), GuessOutcome::TooSmall);
+    assert_eq!(game.submit(" -42\n"), GuessOutcome::Correct);
+
+    let mut game: Game<f64> = Game::with_secret(Settings::new_typed(0.0, 10.0, 0.5).unwrap(), 3.3);
+    assert_eq!(game.submit("NaN"), GuessOutcome::Invalid);
+    assert_eq!(game.submit("2.7"), GuessOutcome::TooSmall);
+    assert_eq!(game.submit("3.75"), GuessOutcome::Correct);
+}
+
+#[test]
+fn invalid_settings_are_rejected() {
+    assert!(matches!(Settings::new_typed(5u8, 5, 0), Err(DomainError::InvalidRange { .. })));
+    assert!(matches!(Settings::new_typed(0.0, 1.0, 0.0), Err(DomainError::InvalidTolerance(_))));
+    assert!(matches!(Settings::new_typed(f64::MIN, f64::MAX, 1.0), Err(DomainError::InvalidRange { .. })));
+    assert!(matches!(Settings::new_typed(-5i32, 5, -1), Err(DomainError::InvalidTolerance(_))));
+    assert!(domain::parse_number::<u8>("256").is_err());
+}
+
+#[test]
+fn lies_and_attempts_apply_to_every_type() {
+    let settings: Settings<i64> = Settings::new_typed(-1000, 1000, 0).unwrap();
+    assert_eq!(settings.attempt_bound(), 11);
+    assert_eq!(settings.with_lies(1).max_attempts(), Some(16));
+
+    let mut game: Game<i64> = Game::with_secret(settings.with_max_attempts(Some(2)).unwrap(), -7);
+    assert_eq!(game.submit("-10 * 100"), GuessOutcome::TooSmall);
+    assert_eq!(game.submit("0"), GuessOutcome::TooBig);
+    assert_eq!(game.status(), GameStatus::Lost);
+}
@@ -0,0 +1,49 @@
+/*******************************************************************/
+/* Tests: Guesses can be arithmetic, and errors point at a column. */
+/*******************************************************************/
+
+// Import libraries/modules
+use guessing_game::expr::{self, ExprError, ExprErrorKind};
+use guessing_game::{Game, GuessOutcome, Settings};
+
+/// The error of `input`, as its column and kind.
+fn error(input: &str) -> (usize, ExprErrorKind) {
+    let ExprError { column, kind } = expr::evaluate(input, Some(10)).unwrap_err();
+    (column, kind)
+}
+
+#[test]
+fn expressions_follow_the_usual_precedence() {
+    assert_eq!(expr::evaluate("2 + 3 * 4", None), Ok(14));
+    assert_eq!(expr::evaluate("(2 + 3) * 4", None), Ok(20));
+    assert_eq!(expr::evaluate("-7 / 2", None), Ok(-3));
+    assert_eq!(expr::evaluate("-7 % 3", None), Ok(-1));
+    assert_eq!(expr::evaluate("last + -(last / 2)", Some(50)), Ok(25));
+}
+
+#[test]
+fn errors_point_at_the_offending_column() {
+    assert_eq!(error("1 + * 2"), (5, ExprErrorKind::ExpectedOperand(Some('*'))));
+    assert_eq!(error("1 +"), (4, ExprErrorKind::ExpectedOperand(None)));
+    assert_eq!(error("12 13"), (4, ExprErrorKind::ExpectedOperator('1')));
+    assert_eq!(error("(1 + 2"), (7, ExprErrorKind::UnclosedParen));
+    assert_eq!(error("2 * lats"), (5, ExprErrorKind::UnknownName("lats".to_string())));
+    assert_eq!(error("50 / (last - 10)"), (4, ExprErrorKind::DivisionByZero));
+    assert_eq!(error("9223372036854775807 + 1"), (21, ExprErrorKind::Overflow));
+    assert_eq!(error(&"(".repeat(100)).1, ExprErrorKind::TooDeep);
+    assert_eq!(error("1 + ½"), (5, ExprErrorKind::ExpectedOperand(Some('½'))));
+    assert_eq!(expr::evaluate("last", None).unwrap_err().kind, ExprErrorKind::NoLastGuess);
+}
+
+#[test]
+fn a_game_takes_the_value_of_an_expression() {
+    let mut game: Game = Game::with_secret(Settings::new(1, 100).unwrap(), 42);
+    assert_eq!(game.submit("100 / 2"), GuessOutcome::TooBig);
+    assert_eq!(game.submit("last - 8"), GuessOutcome::Correct);
+    assert_eq!(game.guesses()[1].value, 42);
+
+    let mut game: Game = Game::with_secret(Settings::new(1, 100).unwrap(), 42);
+    assert_eq!(game.submit("1 / 0"), GuessOutcome::Invalid);
+    assert_eq!(game.submit("10 * 11"), GuessOutcome::OutOfRange);
+    assert_eq!(game.attempts(), 0);
+}
@@ -0,0 +1,43 @@
+/*************************************************************/
+/* Tests: A guess is judged the same way by every front-end. */
+/*************************************************************/
+
+// Import libraries/modules
+use guessing_game::{Game, GameStatus, GuessOutcome, Settings};
+
+/// A normal game on the secret number 42.
+fn game() -> Game {
+    Game::with_secret(Settings::new(1, 100).unwrap(), 42)
+}
+
+#[test]
+fn guesses_compare_to_the_secret_number() {
+    let mut game: Game = game();
+    assert_eq!(game.submit("50"), GuessOutcome::TooBig);
+    assert_eq!(game.submit(" 25\n"), GuessOutcome::TooSmall);
+    assert_eq!(game.status(), GameStatus::InProgress);
+    assert_eq!(game.submit("42"), GuessOutcome::Correct);
+    assert!(game.is_won());
+    assert_eq!(game.attempts(), 3);
+}
+
+#[test]
+fn invalid_guesses_do_not_count_as_attempts() {
+    let mut game: Game = game();
+    assert_eq!(game.submit("forty-two"), GuessOutcome::Invalid);
+    assert_eq!(game.submit(""), GuessOutcome::Invalid);
+    assert_eq!(game.submit("0"), GuessOutcome::OutOfRange);
+    assert_eq!(game.submit("101"), GuessOutcome::OutOfRange);
+    assert_eq!(game.submit("-5"), GuessOutcome::OutOfRange);
+    assert_eq!(game.attempts(), 0);
+    assert!(game.guesses().is_empty());
+}
+
+#[test]
+fn a_finished_game_ignores_further_guesses() {
+    let mut game: Game = game();
+    game.submit("42");
+    assert_eq!(game.submit("50"), GuessOutcome::GameOver);
+    assert_eq!(game.attempts(), 1);
+    assert_eq!(game.status(), GameStatus::Won);
+}
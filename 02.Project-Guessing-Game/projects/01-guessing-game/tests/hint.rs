@@ -0,0 +1,50 @@
+/*********************************************************************/
+/* Tests: Hints are true, earned, and never worth more than a guess. */
+/*********************************************************************/
+
+// Import libraries/modules
+use guessing_game::hint::{self, HintError};
+use guessing_game::{Game, Hint, Settings};
+
+/// The numbers of `1..=100` that satisfy every hint.
+fn candidates(hints: &[Hint]) -> usize {
+    (1..=100).filter(|number| hints.iter().all(|hint| hint.holds_for(*number))).count()
+}
+
+#[test]
+fn every_hint_is_true_and_leaves_half_of_the_candidates() {
+    let settings: Settings = Settings::new(1, 100).unwrap().with_hints_after(Some(0));
+    for secret in 1..=100 {
+        let mut game: Game = Game::with_secret(settings, secret);
+        while let Ok(hint) = game.hint() {
+            assert!(hint.holds_for(secret), "{hint:?} is false for {secret}");
+            let given: &[Hint] = game.hints();
+            let (before, after): (usize, usize) = (candidates(&given[..given.len() - 1]), candidates(given));
+            assert!(after < before && after * 2 >= before, "{hint:?} gives {secret} away");
+        }
+        assert_eq!(game.hint(), Err(HintError::NoneLeft));
+    }
+}
+
+#[test]
+fn hints_are_earned_by_missing() {
+    let mut game: Game = Game::with_secret(Settings::new(1, 100).unwrap().with_hints_after(Some(2)), 42);
+    assert_eq!(game.hint(), Err(HintError::TooEarly { misses_needed: 2 }));
+    game.submit("50");
+    game.submit("25");
+    assert!(game.hint().is_ok());
+    game.submit("42");
+    assert_eq!(game.hint(), Err(HintError::GameOver));
+
+    let settings: Settings = Settings::new(1, 100).unwrap();
+    assert_eq!(Game::with_secret(settings.with_hints_after(None), 42).hint(), Err(HintError::Disabled));
+    assert_eq!(Game::with_secret(settings.with_lies(1), 42).hint(), Err(HintError::Disabled));
+}
+
+#[test]
+fn primes_are_found_by_trial_division() {
+    let primes: Vec<u32> = (0..30).filter(|number| hint::is_prime(*number)).collect();
+    assert_eq!(primes, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert!(hint::is_prime(4_294_967_291));
+    assert!(!hint::is_prime(u32::MAX));
+}
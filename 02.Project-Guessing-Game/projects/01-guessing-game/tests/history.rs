@@ -0,0 +1,72 @@
+/********************************************************************/
+/* Tests: The history survives corrupted lines, and ranks the best. */
+/********************************************************************/
+
+// Import libraries/modules
+use guessing_game::history::{GameRecord, History, Loaded};
+use guessing_game::leaderboard::{self, Board};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A game of `player` on the normal range.
+fn record(player: &str, attempts: u32, score: u32, won: bool) -> GameRecord {
+    GameRecord {
+        player: player.to_string(),
+        min: 1,
+        max: 100,
+        attempts,
+        won,
+        score,
+        duration: Duration::from_millis(12_345),
+        timestamp: 1_790_000_000,
+    }
+}
+
+#[test]
+fn records_round_trip_through_their_line() {
+    let record: GameRecord = record("ann\tthe\nfirst", 4, 2450, true);
+    let decoded: GameRecord = GameRecord::from_line(&record.to_line()).unwrap();
+    assert_eq!(decoded.player, "ann the first");
+    assert_eq!(GameRecord { player: record.player.clone(), ..decoded }, record);
+}
+
+#[test]
+fn corrupted_lines_are_skipped_and_counted() {
+    let path: PathBuf = env::temp_dir().join(format!("guessing-game-history-{}.tsv", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let history: History = History::at(&path);
+    history.append(&record("ann", 4, 2450, true)).unwrap();
+    let good: String = fs::read_to_string(&path).unwrap();
+    let bad: [&str; 5] = [
+        "v1\tbob\t1\t100",
+        "v9\tbob\t1\t100\t4\t1\t2450\t12345\t1790000000",
+        "v1\tbob\t100\t1\t4\t1\t2450\t12345\t1790000000",
+        "v1\tbob\t1\t100\t4\tyes\t2450\t12345\t1790000000",
+        "\u{0}\u{1}garbage",
+    ];
+    fs::write(&path, format!("{}\n{good}", bad.join("\n"))).unwrap();
+    history.append(&record("bob", 7, 0, false)).unwrap();
+
+    let loaded: Loaded = history.load().unwrap();
+    assert_eq!(loaded.corrupted, bad.len());
+    let players: Vec<&str> = loaded.records.iter().map(|record| record.player.as_str()).collect();
+    assert_eq!(players, ["ann", "bob"]);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn boards_rank_won_games_by_score_then_attempts() {
+    let records: Vec<GameRecord> = vec![
+        record("ann", 5, 2000, true),
+        record("bob", 4, 2000, true),
+        record("cat", 7, 0, false),
+        record("dan", 3, 3000, true),
+    ];
+    let boards: Vec<Board<'_>> = leaderboard::leaderboard(&records, 2);
+    assert_eq!(boards.len(), 1);
+    assert_eq!(boards[0].category, "normal");
+    let players: Vec<&str> = boards[0].entries.iter().map(|record| record.player.as_str()).collect();
+    assert_eq!(players, ["dan", "bob"]);
+}
@@ -0,0 +1,35 @@
+/********************************************************************/
+/* Tests: JSON round-trips its values, and refuses hostile nesting. */
+/********************************************************************/
+
+// Import libraries/modules
+use guessing_game::json::{self, Value};
+use guessing_game::transcript;
+
+/// `depth` arrays nested in one another, around `null`.
+fn nested(depth: usize) -> String {
+    format!("{}null{}", "[".repeat(depth), "]".repeat(depth))
+}
+
+#[test]
+fn values_round_trip_through_their_text() {
+    let value: Value = Value::object([
+        ("seed", Value::from(u64::MAX)),
+        ("name", Value::from("ann \"the\" first\n\u{1F600}")),
+        ("won", Value::from(true)),
+        ("limit", Value::from(None::<u32>)),
+    ]);
+    assert_eq!(json::parse(&value.to_string()), Ok(value));
+    assert_eq!(json::parse("\"\\ud83d\\ude00\"").unwrap().as_str(), Some("\u{1F600}"));
+}
+
+#[test]
+fn nesting_is_capped() {
+    assert!(json::parse(&nested(64)).is_ok());
+    assert!(json::parse(&nested(65)).unwrap_err().message.contains("nested deeper than 64 levels"));
+
+    // Far past the cap, the parser stops before the stack does
+    let hostile: String = nested(1_000_000);
+    assert!(json::parse(&hostile).is_err());
+    assert!(transcript::replay(hostile.as_bytes()).is_err());
+}
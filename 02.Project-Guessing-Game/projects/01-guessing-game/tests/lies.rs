@@ -0,0 +1,69 @@
+/**********************************************************/
+/* Tests: The lies of a game are drawn from its own seed. */
+/**********************************************************/
+
+// Import libraries/modules
+use guessing_game::lies;
+use guessing_game::save;
+use guessing_game::{seeded_rng, Game, GameStatus, GuessOutcome, HotSeat, Settings};
+
+/// Guesses that never hit the secret number 500, so that every answer may lie.
+const MISSES: [u32; 8] = [100, 900, 200, 800, 300, 700, 400, 600];
+
+/// Submit `guesses` and return the outcome of each one.
+fn answers(game: &mut Game, guesses: &[u32]) -> Vec<GuessOutcome> {
+    guesses.iter().map(|guess| game.submit(&guess.to_string())).collect()
+}
+
+#[test]
+fn the_same_seed_tells_the_same_lies() {
+    let settings: Settings = Settings::new(1, 1000).unwrap().with_lies(3);
+    let mut first: Game = Game::with_secret(settings, 500).with_lie_seed(7);
+    let mut second: Game = Game::with_secret(settings, 500).with_lie_seed(7);
+    assert_eq!(answers(&mut first, &MISSES), answers(&mut second, &MISSES));
+    assert!(first.lies_told() > 0);
+}
+
+#[test]
+fn hot_seat_games_keep_the_seed_of_their_lies() {
+    let settings: Settings = Settings::new(1, 100).unwrap().with_lies(2);
+    let names: Vec<String> = vec!["ann".to_string(), "bob".to_string()];
+    let mut first: HotSeat = HotSeat::new(Game::from_rng(settings, &mut seeded_rng(3)), names.clone()).unwrap();
+    let mut second: HotSeat = HotSeat::new(Game::from_rng(settings, &mut seeded_rng(3)), names).unwrap();
+    for guess in 1..=20 {
+        assert_eq!(first.submit(&guess.to_string()), second.submit(&guess.to_string()));
+    }
+}
+
+#[test]
+fn resumed_games_go_on_with_the_same_lies() {
+    let settings: Settings = Settings::new(1, 1000).unwrap().with_lies(3);
+    let mut played: Game = Game::with_secret(settings, 500).with_lie_seed(11);
+    let mut saved: Game = Game::with_secret(settings, 500).with_lie_seed(11);
+    answers(&mut played, &MISSES[..4]);
+    answers(&mut saved, &MISSES[..4]);
+
+    // The rest of the answers do not depend on the break
+    let mut resumed: Game = save::from_json(&save::to_json(&saved)).unwrap();
+    assert_eq!(answers(&mut played, &MISSES[4..]), answers(&mut resumed, &MISSES[4..]));
+}
+
+#[test]
+fn the_solver_wins_within_the_berlekamp_bound() {
+    for (max, lies) in [(100, 1), (100, 2), (1000, 1)] {
+        let settings: Settings = Settings::new(1, max).unwrap().with_lies(lies);
+        let bound: u32 = lies::attempt_bound(u128::from(max), lies);
+        assert_eq!(settings.max_attempts(), Some(bound));
+        let mut lies_told: u32 = 0;
+        for secret in 1..=max {
+            for seed in 0..4 {
+                let mut game: Game = Game::with_secret(settings, secret).with_lie_seed(seed);
+                let status: GameStatus = lies::solve(&mut game, |_, _| {});
+                assert_eq!(status, GameStatus::Won, "{secret} with {lies} lies, seed {seed}");
+                assert!(game.attempts() <= bound);
+                lies_told += game.lies_told();
+            }
+        }
+        assert!(lies_told > 0);
+    }
+}
@@ -0,0 +1,123 @@
+/**************************************************************/
+/* Tests: Every catalog has every message, in the same shape. */
+/**************************************************************/
+
+// Import libraries/modules
+use guessing_game::bulls::Code;
+use guessing_game::cli::{self, CliError};
+use guessing_game::expr;
+use guessing_game::messages::{Language, Message};
+use guessing_game::reverse::{Answer, Contradiction};
+use guessing_game::{Settings, SettingsError};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+/// The names of the `{placeholders}` of a text, across all of its plural forms.
+fn placeholders(text: &str) -> BTreeSet<&str> {
+    text.split('{').skip(1).filter_map(|rest| rest.split_once('}')).map(|(name, _)| name).collect()
+}
+
+#[test]
+fn every_key_exists_in_every_catalog() {
+    for language in Language::ALL {
+        let keys: Vec<&str> = language.catalog().iter().map(|(key, _)| *key).collect();
+        for message in Message::ALL {
+            let count: usize = keys.iter().filter(|key| **key == message.key()).count();
+            assert_eq!(count, 1, "'{}' is in the {language} catalog {count} time(s)", message.key());
+        }
+        for key in &keys {
+            assert!(
+                Message::ALL.iter().any(|message| message.key() == *key),
+                "'{key}' of the {language} catalog is not a message"
+            );
+        }
+    }
+}
+
+#[test]
+fn translations_keep_the_placeholders_and_plural_forms() {
+    for message in Message::ALL.iter().copied() {
+        let english: &str = Language::English.text(message);
+        let is_plural: bool = english.contains('|');
+        for language in Language::ALL {
+            let text: &str = language.text(message);
+            assert!(!text.trim().is_empty(), "'{}' is empty in {language}", message.key());
+            assert_eq!(
+                placeholders(text),
+                placeholders(english),
+                "'{}' has other placeholders in {language}",
+                message.key()
+            );
+            let forms: usize = if is_plural { language.plural_forms() } else { 1 };
+            assert_eq!(text.split('|').count(), forms, "'{}' has the wrong forms in {language}", message.key());
+        }
+    }
+}
+
+#[test]
+fn counts_pick_the_plural_form_of_each_language() {
+    let left = |language: Language, count: u64| language.plural(Message::AttemptsLeft, count, &[]);
+    assert_eq!(left(Language::English, 1), "You have 1 attempt left.");
+    assert_eq!(left(Language::English, 0), "You have 0 attempts left.");
+    assert_eq!(left(Language::French, 0), "Il vous reste 0 essai.");
+    assert_eq!(left(Language::French, 2), "Il vous reste 2 essais.");
+    assert_eq!(left(Language::Malagasy, 2), "Mbola manana andrana 2 ianao.");
+
+    let guess: u32 = 42;
+    let text: String = Language::French.format(Message::PlayerGuessed, &[("name", &"Ada"), ("guess", &guess)]);
+    assert_eq!(text, "Ada a proposé : 42");
+}
+
+#[test]
+fn locales_select_their_language() {
+    assert_eq!(Language::from_locale("fr_FR.UTF-8"), Some(Language::French));
+    assert_eq!(Language::from_locale("mg_MG"), Some(Language::Malagasy));
+    assert_eq!(Language::from_locale("en-GB"), Some(Language::English));
+    assert_eq!(Language::from_locale("C"), None);
+    assert_eq!("MG".parse::<Language>(), Ok(Language::Malagasy));
+    assert!("de".parse::<Language>().is_err());
+}
+
+#[test]
+fn errors_are_worded_by_the_catalogs() {
+    let error: String = expr::evaluate("5 +", None).unwrap_err().to_string();
+    assert_eq!(error, "column 4: expected a number, found the end");
+    let error: String = Code::parse("1123", 4).unwrap_err().to_string();
+    assert_eq!(error, "The digit 1 is repeated, but every digit is distinct.");
+
+    let contradiction: Contradiction = Contradiction {
+        number: 2,
+        answer: Answer { guess: 30, ordering: Ordering::Greater },
+        earlier: Some((1, Answer { guess: 50, ordering: Ordering::Less })),
+        settings: Settings::new(1, 100).unwrap(),
+    };
+    assert_eq!(
+        contradiction.to_string(),
+        "answer #2 (your number is lower than 30) contradicts answer #1 (your number is higher than 50)"
+    );
+}
+
+#[test]
+fn the_command_line_is_worded_by_the_catalogs() {
+    assert_eq!(CliError::Conflict("--lies", "--hot-cold").to_string(), "'--lies' cannot be used with '--hot-cold'");
+    assert_eq!(SettingsError::PlayerCount(1).to_string(), "a hot-seat game needs between 2 and 8 players, not 1");
+    assert!(cli::usage().starts_with("Usage: guessing-game [COMMAND] [OPTIONS]"));
+
+    // Every translation of the help names every command and option
+    let words = |language: Language| -> Vec<&str> {
+        let indented = language.text(Message::Usage).lines().filter(|line| line.starts_with(' '));
+        indented.filter_map(|line| line.split_whitespace().next()).collect()
+    };
+    for language in Language::ALL {
+        assert_eq!(words(language), words(Language::English), "{language}");
+    }
+}
+
+#[test]
+fn the_language_is_found_before_the_command_line_is_parsed() {
+    let args = |line: &str| line.split_whitespace().map(String::from).collect::<Vec<String>>();
+    assert_eq!(cli::language_of(&args("--bogus --lang fr")), Some(Language::French));
+    assert_eq!(cli::language_of(&args("--max x --lang=mg")), Some(Language::Malagasy));
+    assert_eq!(cli::language_of(&args("--lang de")), None);
+    assert_eq!(cli::language_of(&args("--max 5")), None);
+}
@@ -0,0 +1,91 @@
+/***********************************************************/
+/* Tests: Hot-seat players share the secret and the clock. */
+/***********************************************************/
+
+// Import libraries/modules
+use guessing_game::multiplayer;
+use guessing_game::session;
+use guessing_game::{Game, GuessOutcome, HotSeat, LineInput, SessionEnd, Settings, SettingsError};
+use std::thread;
+use std::time::Duration;
+
+/// Start a hot-seat game for `ann` and `bob` on the secret number 42.
+fn hot_seat(settings: Settings) -> HotSeat {
+    let game: Game = Game::with_secret(settings, 42);
+    HotSeat::new(game, vec!["ann".to_string(), "bob".to_string()]).unwrap()
+}
+
+#[test]
+fn shared_time_up_ends_the_game_for_everyone() {
+    let settings: Settings = Settings::new(1, 100)
+        .unwrap()
+        .with_time_limit(Some(Duration::from_millis(1)));
+    let mut hot_seat: HotSeat = hot_seat(settings);
+    thread::sleep(Duration::from_millis(10));
+
+    // A guess typed after the deadline neither counts nor passes the turn
+    let mut out: Vec<u8> = Vec::new();
+    let mut input = LineInput::new("50\n60\n70\n".as_bytes());
+    let end: SessionEnd = session::play_hot_seat(&mut hot_seat, &mut input, &mut out).unwrap();
+    assert_eq!(end, SessionEnd::TimeUp);
+    assert!(hot_seat.is_over());
+    assert!(hot_seat.game().is_out_of_time());
+    assert!(hot_seat.turns().is_empty());
+    assert!(String::from_utf8(out).unwrap().contains("Time's up!"));
+}
+
+#[test]
+fn players_take_turns_until_one_finds_the_secret() {
+    let mut hot_seat: HotSeat = hot_seat(Settings::new(1, 100).unwrap());
+    assert_eq!(hot_seat.submit("50"), GuessOutcome::TooBig);
+    assert_eq!(hot_seat.current_player().name, "bob");
+
+    // An invalid guess does not pass the turn
+    assert_eq!(hot_seat.submit("abc"), GuessOutcome::Invalid);
+    assert_eq!(hot_seat.current_player().name, "bob");
+    assert_eq!(hot_seat.submit("42"), GuessOutcome::Correct);
+    assert_eq!(hot_seat.winner().map(|player| player.name.as_str()), Some("bob"));
+    assert_eq!(hot_seat.submit("42"), GuessOutcome::GameOver);
+    let players: Vec<usize> = hot_seat.turns().iter().map(|turn| turn.player).collect();
+    assert_eq!(players, [0, 1]);
+}
+
+#[test]
+fn every_player_gets_the_attempt_limit() {
+    let settings: Settings = Settings::new(1, 100).unwrap().with_max_attempts(Some(2)).unwrap();
+    let mut hot_seat: HotSeat = hot_seat(settings);
+    for guess in ["1", "2", "3"] {
+        assert_eq!(hot_seat.submit(guess), GuessOutcome::TooSmall);
+    }
+    assert_eq!((hot_seat.attempts_left(0), hot_seat.attempts_left(1)), (Some(0), Some(1)));
+    assert_eq!(hot_seat.current_player().name, "bob");
+    assert_eq!(hot_seat.submit("4"), GuessOutcome::TooSmall);
+    assert!(hot_seat.is_over());
+    assert_eq!(hot_seat.winner(), None);
+}
+
+#[test]
+fn player_names_are_checked() {
+    let names = |names: &[&str]| multiplayer::validate_players(names.iter().map(|name| name.to_string()).collect());
+    assert_eq!(names(&["ann"]), Err(SettingsError::PlayerCount(1)));
+    assert_eq!(names(&["ann", " ann "]), Err(SettingsError::InvalidPlayer("ann".to_string())));
+    assert_eq!(names(&["ann", ""]), Err(SettingsError::InvalidPlayer(String::new())));
+    assert_eq!(names(&[" ann", "bob "]), Ok(vec!["ann".to_string(), "bob".to_string()]));
+}
+
+#[test]
+fn hints_and_saves_are_refused_without_passing_the_turn() {
+    let mut hot_seat: HotSeat = hot_seat(Settings::new(1, 100).unwrap());
+    let mut out: Vec<u8> = Vec::new();
+    let mut input = LineInput::new("50\nhint\nsave\n42\n".as_bytes());
+    let end: SessionEnd = session::play_hot_seat(&mut hot_seat, &mut input, &mut out).unwrap();
+    assert_eq!(end, SessionEnd::Won);
+    assert_eq!(hot_seat.winner().map(|player| player.name.as_str()), Some("bob"));
+    assert_eq!(hot_seat.turns().len(), 2);
+    assert!(hot_seat.game().hints().is_empty());
+
+    let out: String = String::from_utf8(out).unwrap();
+    let refusal: &str = "Hot-seat games have no hints and cannot be saved. Type a number or 'quit'.";
+    assert_eq!(out.matches(refusal).count(), 2, "{out}");
+    assert!(!out.contains("That was not a valid number!"), "{out}");
+}
@@ -0,0 +1,45 @@
+/**************************************************************/
+/* Tests: A client plays the terminal game over a TCP socket. */
+/**************************************************************/
+
+// Import libraries/modules
+use guessing_game::net;
+use guessing_game::Settings;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+/// Start a server with seed 5, whose first secret number is 30, on a free port.
+fn start_server() -> SocketAddr {
+    let addr: SocketAddr = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+    thread::spawn(move || net::serve(addr, Settings::new(1, 100).unwrap(), Some(5)));
+    addr
+}
+
+/// Connect to `addr`, waiting for the server to listen.
+fn connect(addr: SocketAddr) -> TcpStream {
+    for _ in 0..100 {
+        if let Ok(stream) = TcpStream::connect(addr) {
+            return stream;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    panic!("the server never listened on {addr}");
+}
+
+#[test]
+fn a_connection_plays_one_game_to_the_end() {
+    let mut stream: TcpStream = connect(start_server());
+    stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    stream.write_all(b"50\n25\n30\n").unwrap();
+
+    // The server sends the summary, then hangs up
+    let mut transcript: String = String::new();
+    stream.read_to_string(&mut transcript).unwrap();
+    assert!(transcript.starts_with("Take a guess"), "{transcript}");
+    assert!(transcript.contains("Too big!"));
+    assert!(transcript.contains("Too small!"));
+    assert!(transcript.contains("You win!!!"));
+    assert!(transcript.contains("You made 3 attempts"), "{transcript}");
+}
@@ -0,0 +1,85 @@
+/***************************************************************/
+/* Tests: Hot/cold thresholds scale with the configured range. */
+/***************************************************************/
+
+// Import libraries/modules
+use guessing_game::proximity::{self, Heat, Thresholds, Trend};
+use guessing_game::{Feedback, Game, Settings};
+
+#[test]
+fn thresholds_scale_with_the_range() {
+    let normal: Thresholds = Thresholds::for_range(100);
+    assert_eq!((normal.burning, normal.hot, normal.warm, normal.cold), (1, 5, 15, 35));
+
+    let insane: Thresholds = Thresholds::for_range(1_000_000);
+    assert_eq!(
+        (insane.burning, insane.hot, insane.warm, insane.cold),
+        (10_000, 50_000, 150_000, 350_000)
+    );
+}
+
+#[test]
+fn small_ranges_keep_every_heat() {
+    let easy: Thresholds = Thresholds::for_range(10);
+    assert!(easy.burning < easy.hot && easy.hot < easy.warm && easy.warm < easy.cold);
+    assert_eq!(easy.heat(1), Heat::Burning);
+    assert_eq!(easy.heat(9), Heat::Freezing);
+}
+
+#[test]
+fn heat_grows_as_the_distance_shrinks() {
+    let thresholds: Thresholds = Thresholds::for_range(1_000);
+    let heats: Vec<Heat> = [500, 300, 100, 40, 5].into_iter().map(|d| thresholds.heat(d)).collect();
+    assert_eq!(heats, [Heat::Freezing, Heat::Cold, Heat::Warm, Heat::Hot, Heat::Burning]);
+    assert!(heats.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn thresholds_are_inclusive() {
+    let thresholds: Thresholds = Thresholds::for_range(100);
+    assert_eq!(thresholds.heat(5), Heat::Hot);
+    assert_eq!(thresholds.heat(6), Heat::Warm);
+    assert_eq!(thresholds.heat(35), Heat::Cold);
+    assert_eq!(thresholds.heat(36), Heat::Freezing);
+}
+
+#[test]
+fn trend_compares_with_the_previous_guess() {
+    assert_eq!(proximity::trend(30, 10), Trend::Warmer);
+    assert_eq!(proximity::trend(10, 30), Trend::Colder);
+    // On either side of the secret number, at the same distance
+    assert_eq!(proximity::proximity(100, 50, 60, Some(40)).trend, Some(Trend::Same));
+    assert_eq!(proximity::proximity(100, 50, 60, None).trend, None);
+}
+
+#[test]
+fn game_reports_the_proximity_of_the_last_guess() {
+    let settings: Settings = Settings::new(1, 100).unwrap().with_feedback(Feedback::HotCold);
+    let mut game: Game = Game::with_secret(settings, 42);
+    assert_eq!(game.proximity(), None);
+
+    game.submit("90");
+    assert_eq!(game.proximity().unwrap().heat, Heat::Freezing);
+    assert_eq!(game.proximity().unwrap().trend, None);
+
+    game.submit("45");
+    assert_eq!(game.proximity().unwrap().heat, Heat::Hot);
+    assert_eq!(game.proximity().unwrap().trend, Some(Trend::Warmer));
+
+    // Invalid input leaves the last guess untouched
+    game.submit("abc");
+    assert_eq!(game.proximity().unwrap().heat, Heat::Hot);
+}
+
+#[test]
+fn hot_cold_doubles_the_default_attempt_limit_only() {
+    let default: Settings = Settings::new(1, 100).unwrap().with_feedback(Feedback::HotCold);
+    assert_eq!(default.max_attempts(), Some(14));
+
+    let custom: Settings = Settings::new(1, 100)
+        .unwrap()
+        .with_max_attempts(Some(3))
+        .unwrap()
+        .with_feedback(Feedback::HotCold);
+    assert_eq!(custom.max_attempts(), Some(3));
+}
@@ -0,0 +1,58 @@
+/****************************************************************/
+/* Tests: The computer finds the player's number, or a cheater. */
+/****************************************************************/
+
+// Import libraries/modules
+use guessing_game::reverse::{Answer, Contradiction, ReverseStatus};
+use guessing_game::session;
+use guessing_game::{LineInput, ReverseGame, SessionEnd, Settings};
+use std::cmp::Ordering;
+
+/// A reverse game between 1 and 4, where the computer first guesses 2.
+fn game() -> ReverseGame {
+    let mut game: ReverseGame = ReverseGame::new(Settings::new(1, 4).unwrap());
+    assert_eq!(game.guess(), 2);
+    game
+}
+
+#[test]
+fn answers_narrow_the_guesses_down_to_the_number() {
+    let mut game: ReverseGame = game();
+    assert_eq!(game.answer(Ordering::Less), Ok(ReverseStatus::InProgress));
+    assert_eq!(game.candidates(), (3, 4));
+    assert_eq!(game.guess(), 3);
+    assert_eq!(game.answer(Ordering::Equal), Ok(ReverseStatus::Found(3)));
+    assert_eq!(game.attempts(), 2);
+}
+
+#[test]
+fn a_contradiction_names_the_answer_it_breaks() {
+    let mut game: ReverseGame = game();
+    game.answer(Ordering::Less).unwrap();
+    let contradiction: Contradiction = game.answer(Ordering::Greater).unwrap_err();
+    assert_eq!(contradiction.number, 2);
+    assert_eq!(contradiction.answer, Answer { guess: 3, ordering: Ordering::Greater });
+    assert_eq!(contradiction.earlier, Some((1, Answer { guess: 2, ordering: Ordering::Less })));
+
+    // The contradictory answer is not kept, so it can be taken back
+    assert_eq!(game.answers().len(), 1);
+    assert_eq!(game.answer(Ordering::Equal), Ok(ReverseStatus::Found(3)));
+}
+
+#[test]
+fn a_contradiction_of_the_range_has_no_earlier_answer() {
+    let mut game: ReverseGame = game();
+    game.answer(Ordering::Greater).unwrap();
+    assert_eq!(game.guess(), 1);
+    let contradiction: Contradiction = game.answer(Ordering::Greater).unwrap_err();
+    assert_eq!(contradiction.earlier, None);
+}
+
+#[test]
+fn a_cheater_ends_the_session() {
+    let mut out: Vec<u8> = Vec::new();
+    let mut input = LineInput::new("maybe\nh\nl\n".as_bytes());
+    let end: SessionEnd = session::play_reverse(&mut game(), &mut input, &mut out).unwrap();
+    assert_eq!(end, SessionEnd::Contradiction);
+    assert!(String::from_utf8(out).unwrap().contains("Cheater!"));
+}
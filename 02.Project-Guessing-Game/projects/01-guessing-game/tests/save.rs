@@ -0,0 +1,86 @@
+/**************************************************************/
+/* Tests: A saved game resumes exactly where it was left off. */
+/**************************************************************/
+
+// Import libraries/modules
+use guessing_game::json::{self, Value};
+use guessing_game::save::{self, SaveFile};
+use guessing_game::{seeded_rng, Game, GameStatus, GuessOutcome, Settings};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// A game of 1..=100 with two misses and a hint.
+fn game_in_progress() -> Game {
+    let settings: Settings = Settings::new(1, 100).unwrap().with_hints_after(Some(1));
+    let mut game: Game = Game::from_rng(settings, &mut seeded_rng(7));
+    game.submit("1");
+    game.submit("100");
+    game.hint().unwrap();
+    game
+}
+
+#[test]
+fn a_saved_game_resumes_with_the_same_state() {
+    let game: Game = game_in_progress();
+    let text: String = save::to_json(&game).to_string();
+    let resumed: Game = save::from_json(&json::parse(&text).unwrap()).unwrap();
+    assert_eq!(resumed.settings(), game.settings());
+    assert_eq!(resumed.secret(), game.secret());
+    assert_eq!(resumed.guesses(), game.guesses());
+    assert_eq!(resumed.hints(), game.hints());
+    assert!(resumed.elapsed() >= game.guesses()[1].elapsed);
+
+    // The game goes on from there
+    let mut resumed: Game = resumed;
+    assert_eq!(resumed.submit(&resumed.secret().to_string()), GuessOutcome::Correct);
+    assert_eq!(resumed.attempts(), 3);
+}
+
+#[test]
+fn the_secret_is_not_written_in_clear() {
+    let settings: Settings = Settings::new(1, 1_000).unwrap();
+    let mut game: Game = Game::with_secret(settings, 617);
+    game.submit("500");
+
+    let value: Value = save::to_json(&game);
+    let secret: &str = value.get("secret").and_then(Value::as_str).unwrap();
+    assert!(!secret.contains("617"));
+
+    // The times played are in the file too, and may well contain the digits
+    let text: String = value.to_string();
+    assert!(!text.contains(":617,") && !text.contains(":617}"), "{text}");
+}
+
+#[test]
+fn finished_or_tampered_games_are_rejected() {
+    let settings: Settings = Settings::new(1, 10).unwrap();
+    let mut game: Game = Game::with_secret(settings, 4);
+    game.submit("2");
+    let text: String = save::to_json(&game).to_string();
+
+    // A winning guess, a guess out of range, and a secret out of range
+    for tampered in [
+        text.replace("\"too_small\"", "\"correct\""),
+        text.replace("\"value\":2", "\"value\":11"),
+        text.replace("\"max\":10", "\"max\":3"),
+    ] {
+        assert!(save::from_json(&json::parse(&tampered).unwrap()).is_err(), "{tampered}");
+    }
+}
+
+#[test]
+fn a_save_file_round_trips_and_can_be_removed() {
+    let path: PathBuf = env::temp_dir().join(format!("guessing-game-save-{}.json", std::process::id()));
+    let file: SaveFile = SaveFile::at(&path);
+    let game: Game = game_in_progress();
+
+    file.save(&game).unwrap();
+    let resumed: Game = file.load().unwrap().expect("a game was saved");
+    assert_eq!(resumed.status(), GameStatus::InProgress);
+    assert_eq!(resumed.guesses(), game.guesses());
+
+    file.remove().unwrap();
+    assert!(file.load().unwrap().is_none());
+    assert!(fs::metadata(&path).is_err());
+}
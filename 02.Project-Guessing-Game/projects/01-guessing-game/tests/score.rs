@@ -0,0 +1,49 @@
+/*********************************************************************/
+/* Tests: Attempts run out, and fewer and faster guesses score more. */
+/*********************************************************************/
+
+// Import libraries/modules
+use guessing_game::score;
+use guessing_game::{Game, GameStatus, GuessOutcome, Settings};
+use std::time::Duration;
+
+#[test]
+fn the_last_attempt_ends_the_game() {
+    let settings: Settings = Settings::new(1, 100).unwrap().with_max_attempts(Some(3)).unwrap();
+    let mut game: Game = Game::with_secret(settings, 42);
+    assert_eq!(game.submit("10"), GuessOutcome::TooSmall);
+    assert_eq!(game.attempts_left(), Some(2));
+    assert_eq!(game.submit("90"), GuessOutcome::TooBig);
+    assert_eq!(game.submit("20"), GuessOutcome::TooSmall);
+    assert_eq!(game.status(), GameStatus::Lost);
+    assert_eq!(game.attempts_left(), Some(0));
+    assert_eq!(game.submit("42"), GuessOutcome::GameOver);
+    assert_eq!(game.score(), 0);
+}
+
+#[test]
+fn attempts_default_to_the_bound_of_the_range() {
+    assert_eq!(Settings::new(1, 100).unwrap().max_attempts(), Some(7));
+    assert_eq!(Settings::new(1, 1_000_000).unwrap().max_attempts(), Some(20));
+    assert_eq!(score::attempt_bound(1), 1);
+    assert_eq!(score::attempt_bound(u64::MAX), 64);
+    assert!(Settings::new(1, 100).unwrap().with_max_attempts(Some(0)).is_err());
+
+    // Unlimited attempts never lose
+    let settings: Settings = Settings::new(1, 100).unwrap().with_max_attempts(None).unwrap();
+    let mut game: Game = Game::with_secret(settings, 100);
+    for guess in 1..100 {
+        assert_eq!(game.submit(&guess.to_string()), GuessOutcome::TooSmall);
+    }
+    assert_eq!(game.attempts_left(), None);
+    assert_eq!(game.submit("100"), GuessOutcome::Correct);
+}
+
+#[test]
+fn scores_reward_few_fast_guesses_without_hints() {
+    assert_eq!(score::score(7, 100, Duration::ZERO, 0), 1400);
+    assert_eq!(score::score(1, 100, Duration::ZERO, 0), 9800);
+    assert_eq!(score::score(7, 100, Duration::from_secs(35), 0), 1050);
+    assert_eq!(score::score(7, 100, Duration::ZERO, 1), 1190);
+    assert_eq!(score::score(0, 100, Duration::ZERO, 0), 0);
+}
@@ -0,0 +1,33 @@
+/*********************************************************/
+/* Tests: The same seed plays the exact same game again. */
+/*********************************************************/
+
+// Import libraries/modules
+use guessing_game::bench::{self, BenchReport};
+use guessing_game::{seeded_rng, Game, Settings, StrategyKind};
+
+/// The secret numbers drawn by the seeds `0..count`.
+fn secrets(settings: Settings, count: u64) -> Vec<u32> {
+    (0..count).map(|seed| Game::from_rng(settings, &mut seeded_rng(seed)).secret()).collect()
+}
+
+#[test]
+fn a_seed_always_draws_the_same_secret() {
+    let settings: Settings = Settings::new(1, 1_000_000).unwrap();
+    let drawn: Vec<u32> = secrets(settings, 20);
+    assert_eq!(drawn, secrets(settings, 20));
+
+    // Different seeds are not stuck on one number
+    let mut distinct: Vec<u32> = drawn.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    assert!(distinct.len() > 15, "{drawn:?}");
+}
+
+#[test]
+fn seeded_strategies_play_the_same_games() {
+    let settings: Settings = Settings::new(1, 1000).unwrap();
+    let first: BenchReport = bench::bench(StrategyKind::Random, settings, 50, 7);
+    let again: BenchReport = bench::bench(StrategyKind::Random, settings, 50, 7);
+    assert_eq!(first, again);
+}
@@ -0,0 +1,52 @@
+/*************************************************************/
+/* Tests: Every way of leaving a game has its own exit code. */
+/*************************************************************/
+
+// Import libraries/modules
+use guessing_game::session;
+use guessing_game::{Game, Input, InputSource, LineInput, SessionEnd, Settings};
+use std::io;
+use std::process::ExitCode;
+
+/// Input that only ever presses Ctrl-C.
+struct CtrlC;
+
+impl InputSource for CtrlC {
+    fn read_input(&mut self) -> io::Result<Input> {
+        Ok(Input::Interrupted)
+    }
+}
+
+/// Play `lines` on a normal game with the secret number 42.
+fn play(lines: &str) -> (SessionEnd, String) {
+    let mut game: Game = Game::with_secret(Settings::new(1, 100).unwrap(), 42);
+    let mut out: Vec<u8> = Vec::new();
+    let end: SessionEnd = session::play(&mut game, &mut LineInput::new(lines.as_bytes()), &mut out).unwrap();
+    (end, String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn closed_input_ends_the_game_with_its_own_code() {
+    let (end, out) = play("50\n");
+    assert_eq!(end, SessionEnd::EndOfInput);
+    assert_eq!(end.exit_code(), ExitCode::from(4));
+    assert!(out.contains("The secret number was 42."), "{out}");
+}
+
+#[test]
+fn quitting_is_not_losing() {
+    for command in ["quit\n", "exit\n", "  QUIT  \n"] {
+        let (end, _) = play(command);
+        assert_eq!(end, SessionEnd::Quit, "{command:?}");
+    }
+    assert_eq!(SessionEnd::Quit.exit_code(), ExitCode::from(3));
+    assert_eq!(play("42\n").0.exit_code(), ExitCode::SUCCESS);
+}
+
+#[test]
+fn ctrl_c_ends_the_game_like_a_shell_would() {
+    let mut game: Game = Game::with_secret(Settings::new(1, 100).unwrap(), 42);
+    let end: SessionEnd = session::play(&mut game, &mut CtrlC, &mut Vec::new()).unwrap();
+    assert_eq!(end, SessionEnd::Interrupted);
+    assert_eq!(end.exit_code(), ExitCode::from(130));
+}
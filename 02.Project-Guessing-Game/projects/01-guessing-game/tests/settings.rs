@@ -0,0 +1,25 @@
+/*********************************************************************/
+/* Tests: A range holds at least two numbers, and presets are fixed. */
+/*********************************************************************/
+
+// Import libraries/modules
+use guessing_game::{Difficulty, Settings, SettingsError};
+
+#[test]
+fn ranges_without_two_numbers_are_rejected() {
+    assert_eq!(Settings::new(100, 1), Err(SettingsError::InvertedRange { min: 100, max: 1 }));
+    assert_eq!(Settings::new(7, 7), Err(SettingsError::EmptyRange { min: 7, max: 7 }));
+    assert_eq!(Settings::new(0, u32::MAX).unwrap().size(), 1 << 32);
+}
+
+#[test]
+fn every_difficulty_is_its_own_preset() {
+    for difficulty in Difficulty::ALL {
+        let settings: Settings = Settings::from_difficulty(difficulty);
+        assert_eq!((settings.min(), settings.max()), difficulty.range());
+        assert_eq!(settings.difficulty(), Some(difficulty));
+        assert_eq!(difficulty.name().parse::<Difficulty>(), Ok(difficulty));
+    }
+    assert_eq!(Settings::new(1, 50).unwrap().difficulty(), None);
+    assert!("extreme".parse::<Difficulty>().is_err());
+}
@@ -0,0 +1,58 @@
+/***************************************************************/
+/* Tests: Streaks follow the order in which games were played. */
+/***************************************************************/
+
+// Import libraries/modules
+use guessing_game::history::GameRecord;
+use guessing_game::stats::{self, Stats, Streak};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// A game on the normal range, ended at `timestamp`.
+fn record(timestamp: u64, attempts: u32, won: bool) -> GameRecord {
+    GameRecord {
+        player: "ann".to_string(),
+        min: 1,
+        max: 100,
+        attempts,
+        won,
+        score: if won { 1000 / attempts } else { 0 },
+        duration: Duration::from_secs(30),
+        timestamp,
+    }
+}
+
+#[test]
+fn streaks_are_counted_in_the_order_of_play() {
+    // Stored out of order: won, won, won, lost, won, lost, lost
+    let records: Vec<GameRecord> = vec![
+        record(7, 7, false),
+        record(1, 4, true),
+        record(5, 4, true),
+        record(2, 5, true),
+        record(6, 7, false),
+        record(3, 4, true),
+        record(4, 7, false),
+    ];
+    let stats: Stats = stats::stats(&records);
+    assert_eq!(stats.games, 7);
+    assert_eq!(stats.wins, 4);
+    assert_eq!(stats.current_streak, Streak { won: false, length: 2 });
+    assert_eq!((stats.longest_wins, stats.longest_losses), (3, 2));
+    assert_eq!(stats.histogram, BTreeMap::from([(4, 3), (5, 1)]));
+    assert_eq!(stats.best.map(|best| best.timestamp), Some(1));
+}
+
+#[test]
+fn the_render_lines_up_its_values() {
+    let records: Vec<GameRecord> = vec![record(1, 4, true), record(2, 7, false)];
+    let text: String = stats::render("ann", &stats::stats(&records));
+    assert!(text.starts_with("== ann ==\n"));
+    assert!(text.contains("\nGames played:    2\n"));
+    assert!(text.contains("\nCurrent streak:  1 loss\n"));
+    assert!(text.contains("\nLongest streaks: 1 win, 1 loss\n"));
+    assert!(text.contains("\nAttempts to win:\n"));
+
+    let empty: Vec<GameRecord> = Vec::new();
+    assert!(!stats::render("ann", &stats::stats(&empty)).contains("Current streak:"));
+}
@@ -0,0 +1,42 @@
+/**************************************************************/
+/* Tests: The computer finds every number, within its limits. */
+/**************************************************************/
+
+// Import libraries/modules
+use guessing_game::strategy::{self, BinarySearch, Linear};
+use guessing_game::{Game, GameStatus, GuessOutcome, Settings, StrategyKind};
+
+#[test]
+fn binary_search_finds_every_number_within_the_bound() {
+    let settings: Settings = Settings::new(1, 1000).unwrap();
+    let worst: u32 = (1..=1000)
+        .map(|secret| {
+            let mut game: Game = Game::with_secret(settings, secret);
+            assert_eq!(strategy::solve(&mut game, &mut BinarySearch, |_, _| {}), GameStatus::Won);
+            game.attempts()
+        })
+        .max()
+        .unwrap();
+    assert_eq!(worst, settings.attempt_bound());
+}
+
+#[test]
+fn every_strategy_wins_without_an_attempt_limit() {
+    let settings: Settings = Settings::new(1, 100).unwrap().with_max_attempts(None).unwrap();
+    for kind in StrategyKind::ALL {
+        for secret in [1, 37, 100] {
+            let mut game: Game = Game::with_secret(settings, secret);
+            let status: GameStatus = strategy::solve(&mut game, kind.build(secret.into()).as_mut(), |_, _| {});
+            assert_eq!(status, GameStatus::Won, "{kind} did not find {secret}");
+        }
+    }
+}
+
+#[test]
+fn the_solver_reports_every_step() {
+    let mut game: Game = Game::with_secret(Settings::new(1, 10).unwrap(), 4);
+    let mut steps: Vec<(u32, GuessOutcome)> = Vec::new();
+    strategy::solve(&mut game, &mut Linear, |guess, outcome| steps.push((guess, outcome)));
+    let (small, correct): (GuessOutcome, GuessOutcome) = (GuessOutcome::TooSmall, GuessOutcome::Correct);
+    assert_eq!(steps, [(1, small), (2, small), (3, small), (4, correct)]);
+}
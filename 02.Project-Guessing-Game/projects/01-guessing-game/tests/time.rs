@@ -0,0 +1,47 @@
+/******************************************************/
+/* Tests: A game whose time runs out is lost on time. */
+/******************************************************/
+
+// Import libraries/modules
+use guessing_game::session;
+use guessing_game::{Game, GameStatus, GuessOutcome, LineInput, SessionEnd, Settings};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+/// Start a game on the secret number 42 with `time_limit`, and wait until it passes.
+fn late_game(time_limit: Duration) -> Game {
+    let settings: Settings = Settings::new(1, 100).unwrap().with_time_limit(Some(time_limit));
+    let game: Game = Game::with_secret(settings, 42);
+    thread::sleep(time_limit + Duration::from_millis(10));
+    game
+}
+
+#[test]
+fn a_late_guess_does_not_count() {
+    let mut game: Game = late_game(Duration::from_millis(1));
+    assert_eq!(game.time_left(), Some(Duration::ZERO));
+    assert_eq!(game.submit("42"), GuessOutcome::GameOver);
+    assert_eq!(game.status(), GameStatus::Lost);
+    assert!(game.is_out_of_time());
+    assert_eq!(game.attempts(), 0);
+}
+
+#[test]
+fn time_up_ends_the_session_with_its_own_code() {
+    let mut game: Game = late_game(Duration::from_millis(1));
+    let mut out: Vec<u8> = Vec::new();
+    let end: SessionEnd = session::play(&mut game, &mut LineInput::new("42\n".as_bytes()), &mut out).unwrap();
+    assert_eq!(end, SessionEnd::TimeUp);
+    assert_eq!(end.exit_code(), ExitCode::from(6));
+    assert!(String::from_utf8(out).unwrap().contains("Time's up!"));
+}
+
+#[test]
+fn untimed_games_never_run_out() {
+    let mut game: Game = Game::with_secret(Settings::new(1, 100).unwrap(), 42);
+    assert_eq!(game.time_left(), None);
+    assert!(!game.check_clock());
+    assert_eq!(game.submit("42"), GuessOutcome::Correct);
+    assert!(!game.is_out_of_time());
+}
@@ -0,0 +1,44 @@
+/****************************************************************/
+/* Tests: A recorded session replays against the same settings. */
+/****************************************************************/
+
+// Import libraries/modules
+use guessing_game::session;
+use guessing_game::transcript::{self, ReplayReport, TranscriptWriter};
+use guessing_game::{seeded_rng, Feedback, Game, LineInput, Settings};
+use std::time::Duration;
+
+/// Play `lines` on a game of `settings` drawn from `seed`, and return the transcript.
+fn record(settings: Settings, seed: u64, lines: &str) -> String {
+    let mut transcript: Vec<u8> = Vec::new();
+    let mut recorder = TranscriptWriter::new(&mut transcript, seed, &settings).unwrap();
+    let mut game: Game = Game::from_rng(settings, &mut seeded_rng(seed));
+    let mut input = LineInput::new(lines.as_bytes());
+    session::play_observed(&mut game, &mut input, &mut Vec::new(), &mut recorder).unwrap();
+    String::from_utf8(transcript).unwrap()
+}
+
+#[test]
+fn hot_cold_and_timed_games_replay_with_their_settings() {
+    let settings: Settings = Settings::new(1, 100)
+        .unwrap()
+        .with_feedback(Feedback::HotCold)
+        .with_time_limit(Some(Duration::from_secs(30)));
+    let transcript: String = record(settings, 42, "50\n25\nquit\n");
+    assert!(transcript.contains("\"feedback\":\"hot_cold\""));
+    assert!(transcript.contains("\"time_limit_ms\":30000"));
+
+    let report: ReplayReport = transcript::replay(transcript.as_bytes()).unwrap();
+    assert!(report.is_ok(), "{:?}", report.mismatches);
+    assert_eq!(report.settings, settings);
+    assert_eq!(report.lines, 3);
+}
+
+#[test]
+fn older_transcripts_replay_as_classic_untimed_games() {
+    let transcript: &str = "{\"type\":\"start\",\"version\":1,\"seed\":42,\"min\":1,\"max\":100,\"max_attempts\":7}\n";
+    let report: ReplayReport = transcript::replay(transcript.as_bytes()).unwrap();
+    assert_eq!(report.settings.feedback(), Feedback::Classic);
+    assert_eq!(report.settings.time_limit(), None);
+    assert!(!report.complete);
+}
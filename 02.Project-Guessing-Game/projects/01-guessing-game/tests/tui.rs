@@ -0,0 +1,46 @@
+/*********************************************************************/
+/* Tests: The full-screen mode draws the bar, the rows, and secrets. */
+/*********************************************************************/
+
+// Import libraries/modules
+use guessing_game::tui;
+use guessing_game::{seeded_rng, Feedback, Game, Settings};
+
+/// The seed that draws 30 as the secret number between 1 and 100.
+const SEED: u64 = 5;
+
+/// Draw the screen after guessing 50 and 25 in a game of `settings`.
+fn screen_after_two_guesses(settings: Settings) -> Vec<String> {
+    let mut game: Game = Game::from_rng(settings, &mut seeded_rng(SEED));
+    assert_eq!(game.secret(), 30);
+    game.submit("50");
+    game.submit("25");
+    tui::render(&game, "Too small!").lines().map(String::from).collect()
+}
+
+#[test]
+fn the_bar_lights_the_candidates_left() {
+    let screen: Vec<String> = screen_after_two_guesses(Settings::new(1, 100).unwrap());
+    assert!(screen[0].starts_with(" Guessing Game"));
+    assert!(screen[0].contains("Attempt 3 of 7"));
+    let bar: String = format!(" 1 {}{}{} 100", "·".repeat(12), "█".repeat(12), "·".repeat(24));
+    assert_eq!(screen[2], bar);
+    assert_eq!(screen[3], "   Candidates: 26 to 49, 24 left");
+    assert_eq!(screen[5], "  1.         50  Too big!");
+    assert_eq!(screen[6], "  2.         25  Too small!");
+    assert_eq!(screen[8], " Too small!");
+}
+
+#[test]
+fn hot_cold_and_lies_keep_the_bar_dark() {
+    let dark: String = format!(" 1 {} 100", "·".repeat(48));
+
+    let settings: Settings = Settings::new(1, 100).unwrap();
+    let hot_cold: Vec<String> = screen_after_two_guesses(settings.with_feedback(Feedback::HotCold));
+    assert_eq!(hot_cold[2], dark);
+    assert_eq!(hot_cold[3], "   Candidates: hidden, only the heat is known");
+
+    let lies: Vec<String> = screen_after_two_guesses(settings.with_lies(1));
+    assert_eq!(lies[2], dark);
+    assert_eq!(lies[3], "   Candidates: hidden, since some answers may be lies");
+}
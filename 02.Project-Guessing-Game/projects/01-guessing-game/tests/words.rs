@@ -0,0 +1,49 @@
+/*****************************************************************/
+/* Tests: Words are marked and counted letter by letter, in NFC. */
+/*****************************************************************/
+
+// Import libraries/modules
+use guessing_game::words::{self, Mark, WordGame, WordList, WordOutcome};
+
+#[test]
+fn repeated_letters_are_marked_as_in_wordle() {
+    // "abbey" holds two b's: one is found in place, one elsewhere, and the third is surplus
+    let marks: Vec<Mark> = words::mark("abbey", "bobby");
+    assert_eq!(marks, [Mark::Present, Mark::Absent, Mark::Correct, Mark::Absent, Mark::Correct]);
+
+    // A single e cannot light up the other two
+    let marks: Vec<Mark> = words::mark("stole", "geese");
+    assert_eq!(marks, [Mark::Absent, Mark::Absent, Mark::Absent, Mark::Present, Mark::Correct]);
+}
+
+#[test]
+fn decomposed_words_are_kept_whole_and_composed() {
+    // "café" written in NFD, with its accent as a combining mark
+    let list: WordList = WordList::from_text("cafe\u{301}, CAFE; face!\n# fake", 4).unwrap();
+    assert_eq!(list.words(), ["caf\u{e9}", "cafe", "face"]);
+    assert_eq!(words::letter_count("e\u{301}te\u{301}"), 3);
+    assert_eq!(words::normalize(" E\u{301}T\u{c9} "), "\u{e9}t\u{e9}");
+
+    // A guess typed either way is the same word
+    let mut game: WordGame = WordGame::with_secret(list, "caf\u{e9}", Some(6));
+    let accentless: WordOutcome = game.submit("cafe");
+    assert_eq!(accentless, WordOutcome::Marked(vec![Mark::Correct, Mark::Correct, Mark::Correct, Mark::Absent]));
+    assert_eq!(game.submit("CAFE\u{301}"), WordOutcome::Marked(vec![Mark::Correct; 4]));
+    assert!(game.is_won());
+}
+
+#[test]
+fn tiles_are_coloured_or_marked_in_plain_text() {
+    let marks: [Mark; 4] = [Mark::Correct, Mark::Present, Mark::Absent, Mark::Correct];
+    assert_eq!(words::render("cafe\u{301}", &marks, false), "[C](A) F [\u{c9}]");
+
+    let coloured: String = words::render("caf\u{e9}", &marks, true);
+    let expected: String = [
+        "\x1b[1;30;42m C \x1b[0m",
+        "\x1b[1;30;43m A \x1b[0m",
+        "\x1b[1;37;100m F \x1b[0m",
+        "\x1b[1;30;42m \u{c9} \x1b[0m",
+    ]
+    .concat();
+    assert_eq!(coloured, expected);
+}